@@ -1,8 +1,65 @@
 use crate::utils;
+use serde::Serialize;
+use std::path::{Component, Path};
 use tauri;
 
+/// Result of comparing the working directory with the directory Pailer should run from.
+#[derive(Serialize, Debug, Clone)]
+pub struct CwdMismatch {
+    pub mismatch: bool,
+    pub cwd: String,
+    pub expected: String,
+}
+
 /// Checks if the application is installed via Scoop package manager
 #[tauri::command]
 pub fn is_scoop_installation() -> bool {
     utils::is_scoop_installation()
 }
+
+/// Checks whether Pailer is running from a directory Scoop will overwrite.
+///
+/// For a Scoop install only `apps\<app>\current` survives updates: versioned directories are
+/// replaced or removed by `scoop cleanup`, and a working directory inside one keeps it locked.
+/// `expected` is that `current` directory for Scoop installs and the executable's own
+/// directory otherwise.
+#[tauri::command]
+pub fn is_cwd_mismatch() -> Result<CwdMismatch, String> {
+    let cwd =
+        std::env::current_dir().map_err(|e| format!("Failed to read current directory: {}", e))?;
+    let exe_path =
+        std::env::current_exe().map_err(|e| format!("Failed to resolve executable path: {}", e))?;
+
+    let expected = match utils::scoop_app_dir_containing(&exe_path) {
+        Some(app_dir) => app_dir.join("current"),
+        None => exe_path
+            .parent()
+            .map(Path::to_path_buf)
+            .ok_or_else(|| "Executable path has no parent directory".to_string())?,
+    };
+
+    Ok(CwdMismatch {
+        mismatch: !paths_match(&cwd, &expected),
+        cwd: cwd.to_string_lossy().to_string(),
+        expected: expected.to_string_lossy().to_string(),
+    })
+}
+
+/// Compares paths component-wise, ignoring case on Windows.
+fn paths_match(a: &Path, b: &Path) -> bool {
+    let normalize = |path: &Path| -> Vec<String> {
+        path.components()
+            .filter(|component| !matches!(component, Component::CurDir))
+            .map(|component| {
+                let part = component.as_os_str().to_string_lossy();
+                if cfg!(windows) {
+                    part.to_lowercase()
+                } else {
+                    part.to_string()
+                }
+            })
+            .collect()
+    };
+
+    normalize(a) == normalize(b)
+}
@@ -3,38 +3,152 @@ use crate::commands::installed::get_installed_packages_full;
 use crate::commands::powershell;
 use crate::commands::settings;
 use crate::state::AppState;
-use serde::Deserialize;
+use crate::utils;
+use crate::utils::compare_versions;
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 use tauri::{AppHandle, Runtime, State};
 
+const CATEGORY_OLD_VERSIONS: &str = "oldVersions";
+const CATEGORY_OUTDATED_CACHE: &str = "outdatedCache";
+const CATEGORY_FAILED_DOWNLOADS: &str = "failedDownloads";
+
+/// File suffixes left behind in the cache by interrupted downloads.
+const PARTIAL_DOWNLOAD_SUFFIXES: &[&str] = &[".download", ".aria2"];
+
+fn default_true() -> bool {
+    true
+}
+
 /// Settings for automatic cleanup operations.
+///
+/// The legacy `cleanupOldVersions`/`cleanupCache` names are still accepted.
 #[derive(Debug, Deserialize)]
 pub struct CleanupSettings {
     #[serde(rename = "autoCleanupEnabled")]
     pub auto_cleanup_enabled: bool,
-    #[serde(rename = "cleanupOldVersions")]
-    pub cleanup_old_versions: bool,
-    #[serde(rename = "cleanupCache")]
-    pub cleanup_cache: bool,
+    #[serde(
+        rename = "oldVersions",
+        alias = "cleanupOldVersions",
+        default = "default_true"
+    )]
+    pub old_versions: bool,
+    #[serde(
+        rename = "outdatedCache",
+        alias = "cleanupCache",
+        default = "default_true"
+    )]
+    pub outdated_cache: bool,
+    #[serde(rename = "failedDownloads", default)]
+    pub failed_downloads: bool,
     #[serde(rename = "preserveVersionCount")]
     pub preserve_version_count: usize,
 }
 
+/// A path that auto cleanup removes, or would remove during a dry run.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CleanupCandidate {
+    pub package: String,
+    /// One of `"oldVersions"`, `"outdatedCache"` or `"failedDownloads"`.
+    pub kind: String,
+    pub path: String,
+    pub size_bytes: u64,
+}
+
+/// Number of items and bytes handled for one cleanup category.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CleanupCategorySummary {
+    pub category: String,
+    pub items: usize,
+    pub bytes: u64,
+}
+
+/// Result of an auto cleanup run.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoCleanupReport {
+    pub dry_run: bool,
+    /// One entry per enabled category.
+    pub categories: Vec<CleanupCategorySummary>,
+    /// Individual paths, only populated for dry runs.
+    pub candidates: Vec<CleanupCandidate>,
+}
+
+/// Bytes each cleanup category could free right now.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReclaimableSpace {
+    pub outdated_cache: u64,
+    pub old_versions: u64,
+    pub failed_downloads: u64,
+    pub total: u64,
+}
+
+fn summarize_category(category: &str, candidates: &[CleanupCandidate]) -> CleanupCategorySummary {
+    let matching = candidates.iter().filter(|c| c.kind == category);
+    CleanupCategorySummary {
+        category: category.to_string(),
+        items: matching.clone().count(),
+        bytes: matching.map(|c| c.size_bytes).sum(),
+    }
+}
+
 /// Runs the auto cleanup operation silently in the background based on user settings.
 ///
 /// This function is designed to be called after package operations (install, update, uninstall)
 /// to automatically clean up old versions and/or cache without user intervention.
+///
+/// Only the categories enabled in `settings` are touched, and the returned report
+/// summarizes the items and bytes handled per category.
+///
+/// With `dry_run` set, nothing is deleted. The same candidates are collected and
+/// returned with their sizes, even while auto cleanup is disabled, so the settings
+/// page can preview what enabling it would free.
+///
+/// A real run waits for its turn in the operation queue first, since a download
+/// in progress looks just like an interrupted one.
 #[tauri::command]
 pub async fn run_auto_cleanup<R: Runtime>(
     app: AppHandle<R>,
     state: State<'_, AppState>,
     settings: CleanupSettings,
-) -> Result<(), String> {
-    if !settings.auto_cleanup_enabled {
+    dry_run: bool,
+) -> Result<AutoCleanupReport, String> {
+    let _turn = if dry_run || !settings.auto_cleanup_enabled {
+        None
+    } else {
+        let operation_id = format!(
+            "auto-cleanup-{}",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_millis())
+                .unwrap_or(0)
+        );
+        Some(
+            crate::commands::operation_queue::wait_for_turn(&app, &operation_id, "Auto cleanup")
+                .await?,
+        )
+    };
+
+    cleanup_with_settings(app, state, settings, dry_run).await
+}
+
+/// Runs the cleanup behind `run_auto_cleanup` without queueing.
+///
+/// Unless `dry_run` is set, the caller must already hold an operation queue turn.
+pub(crate) async fn cleanup_with_settings<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+    settings: CleanupSettings,
+    dry_run: bool,
+) -> Result<AutoCleanupReport, String> {
+    if !settings.auto_cleanup_enabled && !dry_run {
         log::debug!("Auto cleanup is disabled, skipping");
-        return Ok(());
+        return Ok(AutoCleanupReport::default());
     }
 
     log::info!("Running auto cleanup with settings: {:?}", settings);
@@ -75,7 +189,44 @@ pub async fn run_auto_cleanup<R: Runtime>(
     // Run cleanup operations based on user settings
     let scoop_path = state.scoop_path();
 
-    if settings.cleanup_old_versions && !regular_packages.is_empty() {
+    let mut enabled_categories = Vec::new();
+    let mut candidates = Vec::new();
+    if settings.old_versions {
+        enabled_categories.push(CATEGORY_OLD_VERSIONS);
+        candidates.extend(collect_old_version_candidates(
+            &scoop_path,
+            &regular_packages,
+            settings.preserve_version_count,
+        ));
+    }
+    if settings.outdated_cache {
+        enabled_categories.push(CATEGORY_OUTDATED_CACHE);
+        candidates.extend(collect_cache_candidates(&scoop_path, &regular_packages));
+    }
+    if settings.failed_downloads {
+        enabled_categories.push(CATEGORY_FAILED_DOWNLOADS);
+        candidates.extend(collect_failed_download_candidates(&scoop_path));
+    }
+
+    let categories = enabled_categories
+        .into_iter()
+        .map(|category| summarize_category(category, &candidates))
+        .collect();
+
+    if dry_run {
+        log::info!(
+            "Auto cleanup dry run found {} candidates ({} bytes)",
+            candidates.len(),
+            candidates.iter().map(|c| c.size_bytes).sum::<u64>()
+        );
+        return Ok(AutoCleanupReport {
+            dry_run,
+            categories,
+            candidates,
+        });
+    }
+
+    if settings.old_versions && !regular_packages.is_empty() {
         log::info!(
             "Running auto cleanup of old versions (preserving {} versions)",
             settings.preserve_version_count
@@ -88,13 +239,266 @@ pub async fn run_auto_cleanup<R: Runtime>(
         .await?;
     }
 
-    if settings.cleanup_cache && !regular_packages.is_empty() {
+    if settings.outdated_cache && !regular_packages.is_empty() {
         log::info!("Running auto cleanup of outdated cache");
         cleanup_cache_for_packages(&regular_packages).await?;
     }
 
+    if settings.failed_downloads {
+        log::info!("Running auto cleanup of failed downloads");
+        remove_failed_downloads(&candidates)?;
+    }
+
     log::info!("Auto cleanup completed successfully");
-    Ok(())
+    Ok(AutoCleanupReport {
+        dry_run,
+        categories,
+        candidates: Vec::new(),
+    })
+}
+
+/// Sums what every cleanup category would free, regardless of which ones are
+/// enabled, without deleting anything. The categories are measured in parallel.
+#[tauri::command]
+pub async fn estimate_reclaimable_space<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+) -> Result<ReclaimableSpace, String> {
+    let keep_count = read_cleanup_settings(&app)
+        .map(|settings| settings.preserve_version_count)
+        .unwrap_or(3);
+    let regular_packages: Vec<String> = get_installed_packages_full(app.clone(), state.clone())
+        .await?
+        .into_iter()
+        .filter(|pkg| {
+            matches!(
+                pkg.installation_type,
+                crate::models::InstallationType::Standard
+            )
+        })
+        .map(|pkg| pkg.name)
+        .collect();
+    let scoop_path = state.scoop_path();
+
+    tokio::task::spawn_blocking(move || {
+        let total_size = |candidates: Vec<CleanupCandidate>| -> u64 {
+            candidates.iter().map(|c| c.size_bytes).sum()
+        };
+        let (old_versions, (outdated_cache, failed_downloads)) = rayon::join(
+            || {
+                total_size(collect_old_version_candidates(
+                    &scoop_path,
+                    &regular_packages,
+                    keep_count,
+                ))
+            },
+            || {
+                rayon::join(
+                    || total_size(collect_cache_candidates(&scoop_path, &regular_packages)),
+                    || total_size(collect_failed_download_candidates(&scoop_path)),
+                )
+            },
+        );
+
+        ReclaimableSpace {
+            outdated_cache,
+            old_versions,
+            failed_downloads,
+            total: outdated_cache + old_versions + failed_downloads,
+        }
+    })
+    .await
+    .map_err(|e| e.to_string())
+}
+
+/// Lists the old version directories `cleanup_old_versions_for_packages` would remove.
+fn collect_old_version_candidates(
+    scoop_path: &Path,
+    packages: &[String],
+    keep_count: usize,
+) -> Vec<CleanupCandidate> {
+    let apps_path = scoop_path.join("apps");
+
+    packages
+        .iter()
+        .flat_map(|package_name| {
+            let package_path = apps_path.join(package_name);
+            let versions = if package_path.is_dir() {
+                get_versions_to_remove(&package_path, keep_count).unwrap_or_default()
+            } else {
+                Vec::new()
+            };
+
+            versions.into_iter().map(move |version| {
+                let version_dir = package_path.join(version);
+                CleanupCandidate {
+                    package: package_name.clone(),
+                    kind: CATEGORY_OLD_VERSIONS.to_string(),
+                    size_bytes: utils::directory_size(&version_dir),
+                    path: version_dir.to_string_lossy().to_string(),
+                }
+            })
+        })
+        .collect()
+}
+
+fn is_partial_download(file_name: &str) -> bool {
+    PARTIAL_DOWNLOAD_SUFFIXES
+        .iter()
+        .any(|suffix| file_name.ends_with(suffix))
+}
+
+/// Returns whether a cache file is junk from an interrupted download: a control
+/// or temporary file, an empty file, or data whose aria2 control file still exists.
+fn is_failed_download(file_name: &str, size_bytes: u64, cache_names: &HashSet<String>) -> bool {
+    is_partial_download(file_name)
+        || size_bytes == 0
+        || cache_names.contains(&format!("{}.aria2", file_name))
+}
+
+fn cache_file_names(files: &[(String, String, PathBuf, u64)]) -> HashSet<String> {
+    files
+        .iter()
+        .map(|(_, file_name, _, _)| file_name.clone())
+        .collect()
+}
+
+/// Lists cache files as `(package, file name, path, size)` tuples.
+fn read_cache_files(scoop_path: &Path) -> Vec<(String, String, PathBuf, u64)> {
+    let (cache_dir, _) = crate::commands::settings::resolve_scoop_cache_dir(scoop_path);
+    let Ok(entries) = fs::read_dir(cache_dir) else {
+        return Vec::new();
+    };
+
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            let (package, _) = file_name.split_once('#')?;
+            let package = package.to_string();
+            let metadata = entry.metadata().ok().filter(|m| m.is_file())?;
+            Some((package, file_name, entry.path(), metadata.len()))
+        })
+        .collect()
+}
+
+/// Lists the completed cache files `scoop cache rm` removes for the given packages.
+///
+/// Partial downloads are reported under the failed downloads category instead.
+fn collect_cache_candidates(scoop_path: &Path, packages: &[String]) -> Vec<CleanupCandidate> {
+    let packages: HashSet<&str> = packages.iter().map(String::as_str).collect();
+    let files = read_cache_files(scoop_path);
+    let cache_names = cache_file_names(&files);
+
+    files
+        .into_iter()
+        .filter(|(package, file_name, _, size_bytes)| {
+            packages.contains(package.as_str())
+                && !is_failed_download(file_name, *size_bytes, &cache_names)
+        })
+        .map(|(package, _, path, size_bytes)| CleanupCandidate {
+            package,
+            kind: CATEGORY_OUTDATED_CACHE.to_string(),
+            path: path.to_string_lossy().to_string(),
+            size_bytes,
+        })
+        .collect()
+}
+
+/// Lists leftovers of interrupted downloads for any package.
+fn collect_failed_download_candidates(scoop_path: &Path) -> Vec<CleanupCandidate> {
+    let files = read_cache_files(scoop_path);
+    let cache_names = cache_file_names(&files);
+
+    files
+        .into_iter()
+        .filter(|(_, file_name, _, size_bytes)| {
+            is_failed_download(file_name, *size_bytes, &cache_names)
+        })
+        .map(|(package, _, path, size_bytes)| CleanupCandidate {
+            package,
+            kind: CATEGORY_FAILED_DOWNLOADS.to_string(),
+            path: path.to_string_lossy().to_string(),
+            size_bytes,
+        })
+        .collect()
+}
+
+/// Removes the failed download candidates and returns the number of bytes freed.
+fn remove_failed_downloads(candidates: &[CleanupCandidate]) -> Result<u64, String> {
+    let mut bytes_freed = 0;
+    let failures: Vec<String> = candidates
+        .iter()
+        .filter(|c| c.kind == CATEGORY_FAILED_DOWNLOADS)
+        .filter_map(|c| match fs::remove_file(&c.path) {
+            Ok(()) => {
+                bytes_freed += c.size_bytes;
+                None
+            }
+            // Already gone, e.g. removed by `scoop cache rm` above
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+            Err(e) => {
+                log::warn!("Failed to remove partial download {}: {}", c.path, e);
+                Some(format!("{} ({})", c.path, e))
+            }
+        })
+        .collect();
+
+    if failures.is_empty() {
+        Ok(bytes_freed)
+    } else {
+        Err(format!(
+            "Failed to remove some partial downloads: {}",
+            failures.join(", ")
+        ))
+    }
+}
+
+/// Lists cache files left behind by interrupted downloads.
+#[tauri::command]
+pub async fn list_failed_downloads(
+    state: State<'_, AppState>,
+) -> Result<Vec<CleanupCandidate>, String> {
+    Ok(collect_failed_download_candidates(&state.scoop_path()))
+}
+
+/// Removes the cache files left behind by interrupted downloads, leaving
+/// completed downloads untouched.
+///
+/// Waits for running Scoop operations first, since a download in progress
+/// looks just like an interrupted one.
+#[tauri::command]
+pub async fn clear_failed_downloads<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+) -> Result<CleanupCategorySummary, String> {
+    let operation_id = format!(
+        "clear-failed-downloads-{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or(0)
+    );
+    let _turn = crate::commands::operation_queue::wait_for_turn(
+        &app,
+        &operation_id,
+        "Clearing failed downloads",
+    )
+    .await?;
+
+    let candidates = collect_failed_download_candidates(&state.scoop_path());
+    let bytes = remove_failed_downloads(&candidates)?;
+
+    log::info!(
+        "Removed {} failed download(s), freeing {} bytes",
+        candidates.len(),
+        bytes
+    );
+    Ok(CleanupCategorySummary {
+        category: CATEGORY_FAILED_DOWNLOADS.to_string(),
+        items: candidates.len(),
+        bytes,
+    })
 }
 
 /// Cleans up old versions of packages while preserving the most recent N versions.
@@ -430,6 +834,7 @@ pub(crate) fn build_cleanup_cache_commands(packages: &[String]) -> Vec<String> {
 /// This reads the cleanup settings from the store and runs the cleanup if enabled.
 ///
 /// This function is designed to be called after operations like install, update, or uninstall.
+/// The caller must have released its operation queue turn, or the cleanup waits forever.
 pub async fn trigger_auto_cleanup<R: Runtime>(app: AppHandle<R>, state: State<'_, AppState>) {
     // Read cleanup settings from the store
     let cleanup_settings = match read_cleanup_settings(&app) {
@@ -449,7 +854,7 @@ pub async fn trigger_auto_cleanup<R: Runtime>(app: AppHandle<R>, state: State<'_
     log::info!("Triggering auto cleanup in background");
 
     // Run cleanup directly - it's already async and won't block
-    if let Err(e) = run_auto_cleanup(app, state, cleanup_settings).await {
+    if let Err(e) = run_auto_cleanup(app, state, cleanup_settings, false).await {
         log::warn!("Auto cleanup failed: {}", e);
     }
 }
@@ -483,14 +888,20 @@ pub(crate) fn read_cleanup_settings<R: Runtime>(
             .ok()
             .and_then(|v| v.as_bool())
             .unwrap_or(false),
-        cleanup_old_versions: get_val("cleanupOldVersions")
+        old_versions: get_val("oldVersions")
+            .or_else(|_| get_val("cleanupOldVersions"))
             .ok()
             .and_then(|v| v.as_bool())
             .unwrap_or(true),
-        cleanup_cache: get_val("cleanupCache")
+        outdated_cache: get_val("outdatedCache")
+            .or_else(|_| get_val("cleanupCache"))
             .ok()
             .and_then(|v| v.as_bool())
             .unwrap_or(true),
+        failed_downloads: get_val("failedDownloads")
+            .ok()
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
         preserve_version_count: get_val("preserveVersionCount")
             .ok()
             .and_then(|v| v.as_u64())
@@ -498,87 +909,6 @@ pub(crate) fn read_cleanup_settings<R: Runtime>(
     })
 }
 
-/// Compares two version strings using semantic version logic.
-/// Returns std::cmp::Ordering::Less if a < b, Greater if a > b, Equal if same.
-fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
-    // Split version from prerelease tags (e.g., "1.2.3-beta.1" -> "1.2.3" and "beta.1")
-    let split_version = |v: &str| -> (Vec<u32>, Option<String>) {
-        let parts: Vec<&str> = v.split('-').collect();
-        let version_parts: Vec<u32> = parts[0].split('.').filter_map(|s| s.parse().ok()).collect();
-        let prerelease = parts.get(1).map(|&s| s.to_string());
-        (version_parts, prerelease)
-    };
-
-    let (a_ver, a_pre) = split_version(a);
-    let (b_ver, b_pre) = split_version(b);
-
-    // Compare main version numbers
-    for i in 0..std::cmp::max(a_ver.len(), b_ver.len()) {
-        let a_val = a_ver.get(i).unwrap_or(&0);
-        let b_val = b_ver.get(i).unwrap_or(&0);
-
-        match a_val.cmp(b_val) {
-            std::cmp::Ordering::Equal => continue,
-            ordering => return ordering,
-        }
-    }
-
-    // Main versions are equal, compare prerelease tags
-    match (a_pre, b_pre) {
-        (None, None) => std::cmp::Ordering::Equal,
-        (None, Some(_)) => std::cmp::Ordering::Greater, // Stable version > prerelease
-        (Some(_), None) => std::cmp::Ordering::Less,    // Prerelease < stable version
-        (Some(a_pre), Some(b_pre)) => {
-            // Parse prerelease identifiers (e.g., "beta.10" -> ("beta", 10))
-            let parse_prerelease = |pre: &str| -> (String, Vec<u32>) {
-                let parts: Vec<&str> = pre.split('.').collect();
-                let identifier = parts[0].to_string();
-                let numbers: Vec<u32> = parts
-                    .iter()
-                    .skip(1)
-                    .filter_map(|s| s.parse().ok())
-                    .collect();
-                (identifier, numbers)
-            };
-
-            let (a_id, a_nums) = parse_prerelease(&a_pre);
-            let (b_id, b_nums) = parse_prerelease(&b_pre);
-
-            // Compare prerelease type (alpha < beta < rc < others)
-            let prerelease_order = |id: &str| -> u8 {
-                if id.starts_with("alpha") {
-                    1
-                } else if id.starts_with("beta") {
-                    2
-                } else if id.starts_with("rc") {
-                    3
-                } else {
-                    4
-                }
-            };
-
-            let a_order = prerelease_order(&a_id);
-            let b_order = prerelease_order(&b_id);
-
-            match a_order.cmp(&b_order) {
-                std::cmp::Ordering::Equal => {
-                    // Same prerelease type, compare numeric parts
-                    for i in 0..std::cmp::max(a_nums.len(), b_nums.len()) {
-                        let a_num = a_nums.get(i).unwrap_or(&0);
-                        let b_num = b_nums.get(i).unwrap_or(&0);
-                        match a_num.cmp(b_num) {
-                            std::cmp::Ordering::Equal => continue,
-                            ordering => return ordering,
-                        }
-                    }
-                    std::cmp::Ordering::Equal
-                }
-                ordering => ordering,
-            }
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -703,6 +1033,38 @@ mod tests {
             assert!(!package_path.join("2.0.0").exists());
             assert!(package_path.join("3.0.0").exists());
         }
+
+        #[test]
+        fn detects_failed_downloads_but_keeps_completed_ones() {
+            let temp_dir = tempdir().unwrap();
+            let cache_path = temp_dir.path().join("cache");
+            fs::create_dir_all(&cache_path).unwrap();
+            fs::write(cache_path.join("git#2.44.0#abc1234.7z"), b"done").unwrap();
+            fs::write(cache_path.join("node#20.0.0#def5678.zip"), b"part").unwrap();
+            fs::write(cache_path.join("node#20.0.0#def5678.zip.aria2"), b"ctl").unwrap();
+            fs::write(cache_path.join("go#1.22.0#fed4321.zip"), b"").unwrap();
+
+            let mut failed: Vec<String> = collect_failed_download_candidates(temp_dir.path())
+                .into_iter()
+                .map(|c| {
+                    PathBuf::from(c.path)
+                        .file_name()
+                        .unwrap()
+                        .to_string_lossy()
+                        .to_string()
+                })
+                .collect();
+            failed.sort();
+
+            assert_eq!(
+                failed,
+                vec![
+                    "go#1.22.0#fed4321.zip",
+                    "node#20.0.0#def5678.zip",
+                    "node#20.0.0#def5678.zip.aria2",
+                ]
+            );
+        }
     }
 
     mod cache_commands {
@@ -23,6 +23,13 @@ pub struct BucketManifestCount {
     pub manifest_count: u32,
 }
 
+/// The origin URL and checked-out branch of a git bucket.
+#[derive(Serialize, Debug, Clone)]
+pub struct BucketRemote {
+    pub url: String,
+    pub branch: String,
+}
+
 /// Checks if a directory is a Git repository by looking for .git directory.
 fn is_git_repo(path: &Path) -> bool {
     path.join(".git").exists()
@@ -171,7 +178,7 @@ fn load_bucket_summary(bucket_path: &Path) -> Result<BucketInfo, String> {
     })
 }
 
-fn scan_buckets(buckets_path: &Path) -> Result<Vec<BucketInfo>, String> {
+pub(crate) fn scan_buckets(buckets_path: &Path) -> Result<Vec<BucketInfo>, String> {
     let bucket_dirs = fs::read_dir(buckets_path)
         .map_err(|e| format!("Failed to read buckets directory: {}", e))?
         .filter_map(Result::ok)
@@ -618,3 +625,49 @@ pub async fn switch_bucket_branch<R: Runtime>(
     );
     Ok(format!("Switched to branch '{}'", branch_name))
 }
+
+/// Runs a git command inside `dir` and returns its trimmed stdout on success.
+async fn run_git_in(dir: &Path, args: &str) -> Option<String> {
+    let output = crate::commands::powershell::create_powershell_command(&format!("git {}", args))
+        .current_dir(dir)
+        .output()
+        .await
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!stdout.is_empty()).then_some(stdout)
+}
+
+/// Reads the origin URL and current branch of the git repository at `bucket_path`.
+pub(crate) async fn read_bucket_remote(bucket_path: &Path) -> Option<BucketRemote> {
+    if !is_git_repo(bucket_path) {
+        return None;
+    }
+
+    let Some(url) = run_git_in(bucket_path, "remote get-url origin").await else {
+        log::warn!("Bucket at '{}' has no origin remote", bucket_path.display());
+        return None;
+    };
+    let branch = run_git_in(bucket_path, "rev-parse --abbrev-ref HEAD")
+        .await
+        .unwrap_or_default();
+
+    Some(BucketRemote { url, branch })
+}
+
+/// Returns the origin URL and current branch of a bucket, or `None` when the
+/// bucket is not a git repository.
+#[tauri::command]
+pub async fn get_bucket_remote(
+    state: State<'_, AppState>,
+    bucket: String,
+) -> Result<Option<BucketRemote>, String> {
+    let bucket_path = state.scoop_path().join("buckets").join(&bucket);
+    if !bucket_path.is_dir() {
+        return Err(format!("Bucket '{}' does not exist", bucket));
+    }
+
+    Ok(read_bucket_remote(&bucket_path).await)
+}
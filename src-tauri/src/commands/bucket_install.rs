@@ -2,12 +2,15 @@ use git2::{Cred, CredentialType, FetchOptions, RemoteCallbacks, Repository};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use tauri::{command, Emitter};
 
+use crate::commands::operation_queue;
 use crate::commands::search::{
     refresh_manifest_cache_for_bucket, refresh_manifest_cache_for_buckets,
     remove_manifest_cache_for_bucket,
 };
+use crate::error::AppError;
 use crate::utils;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,12 +29,21 @@ pub struct BucketInstallResult {
     pub manifest_count: Option<u32>,
 }
 
+pub const EVENT_BUCKET_UPDATE_PROGRESS: &str = "bucket-update-progress";
+pub const EVENT_BUCKET_RECLONE_PROGRESS: &str = "bucket-reclone-progress";
+
+/// Emitted by `update_all_buckets` as soon as each bucket's pull completes.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BucketUpdateProgressEvent {
     pub run_id: String,
+    /// Zero-based position of the bucket in this run.
+    pub index: usize,
+    /// Number of buckets finished so far, including this one.
     pub current: usize,
     pub total: usize,
     pub bucket_name: String,
+    /// `"success"` or `"error"`, mirroring `result.success`.
+    pub status: String,
     pub result: BucketInstallResult,
 }
 
@@ -52,14 +64,34 @@ fn bucket_exists(app: &tauri::AppHandle, bucket_name: &str) -> Result<bool, Stri
     Ok(bucket_path.exists())
 }
 
+/// Distinguishes bucket operations started within the same millisecond.
+static BUCKET_OPERATION_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+// Build the queue id for a bucket operation, e.g. `bucket-update-extras-1700000000000-0`
+fn bucket_operation_id(action: &str, bucket_name: &str) -> String {
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or(0);
+    let sequence = BUCKET_OPERATION_COUNTER.fetch_add(1, Ordering::Relaxed);
+    format!(
+        "bucket-{}-{}-{}-{}",
+        action, bucket_name, timestamp, sequence
+    )
+}
+
 // Get bucket directory path
-fn get_bucket_path(app: &tauri::AppHandle, bucket_name: &str) -> Result<PathBuf, String> {
-    let buckets_dir = get_buckets_dir(app)?;
+fn get_bucket_path(app: &tauri::AppHandle, bucket_name: &str) -> Result<PathBuf, AppError> {
+    let buckets_dir = get_buckets_dir(app).map_err(AppError::NotFound)?;
     Ok(buckets_dir.join(bucket_name))
 }
 
-// Clone repository with progress callback
-fn clone_repository(url: &str, target_path: &Path) -> Result<Repository, String> {
+// Clone repository with progress callback, checking out `branch` when given
+fn clone_repository(
+    url: &str,
+    target_path: &Path,
+    branch: Option<&str>,
+) -> Result<Repository, String> {
     log::info!("Cloning repository {} to {:?}", url, target_path);
 
     // Create parent directory if it doesn't exist
@@ -101,6 +133,9 @@ fn clone_repository(url: &str, target_path: &Path) -> Result<Repository, String>
     // Clone the repository
     let mut builder = git2::build::RepoBuilder::new();
     builder.fetch_options(fetch_options);
+    if let Some(branch) = branch {
+        builder.branch(branch);
+    }
 
     let repo = builder
         .clone(url, target_path)
@@ -180,7 +215,7 @@ async fn install_bucket_internal(
     let bucket_path_clone = bucket_path.clone();
 
     let repo_result = tokio::task::spawn_blocking(move || {
-        clone_repository(&normalized_url_clone, &bucket_path_clone)
+        clone_repository(&normalized_url_clone, &bucket_path_clone, None)
     })
     .await
     .map_err(|e| e.to_string())?;
@@ -230,9 +265,17 @@ async fn install_bucket_internal(
 pub async fn install_bucket(
     app: tauri::AppHandle,
     options: BucketInstallOptions,
-) -> Result<BucketInstallResult, String> {
+) -> Result<BucketInstallResult, AppError> {
     log::info!("Installing bucket: {} from {}", options.name, options.url);
 
+    let operation_id = bucket_operation_id("install", &options.name);
+    let _turn = operation_queue::wait_for_turn(
+        &app,
+        &operation_id,
+        &format!("Installing bucket {}", options.name),
+    )
+    .await?;
+
     match install_bucket_internal(app, options).await {
         Ok(result) => {
             log::info!("Bucket installation result: {:?}", result);
@@ -257,7 +300,7 @@ pub async fn validate_bucket_install(
     app: tauri::AppHandle,
     name: String,
     url: String,
-) -> Result<BucketInstallResult, String> {
+) -> Result<BucketInstallResult, AppError> {
     log::info!("Validating bucket installation: {} from {}", name, url);
 
     // Validate URL
@@ -329,9 +372,17 @@ pub async fn validate_bucket_install(
 pub async fn update_bucket(
     app: tauri::AppHandle,
     bucket_name: String,
-) -> Result<BucketInstallResult, String> {
+) -> Result<BucketInstallResult, AppError> {
     log::info!("Updating bucket: {}", bucket_name);
 
+    let operation_id = bucket_operation_id("update", &bucket_name);
+    let _turn = operation_queue::wait_for_turn(
+        &app,
+        &operation_id,
+        &format!("Updating bucket {}", bucket_name),
+    )
+    .await?;
+
     let bucket_path = get_bucket_path(&app, &bucket_name)?;
 
     if !bucket_path.exists() {
@@ -368,8 +419,8 @@ pub async fn update_bucket(
     let result = tokio::task::spawn_blocking(move || {
         update_bucket_sync(&bucket_name_clone, &bucket_path_clone)
     })
-    .await
-    .map_err(|e| e.to_string())??;
+    .await?
+    .map_err(AppError::Io)?;
 
     if result.success {
         refresh_manifest_cache_for_bucket(&bucket_name, bucket_path, "bucket updated").await;
@@ -378,6 +429,25 @@ pub async fn update_bucket(
     Ok(result)
 }
 
+/// Fetch options with the credential callbacks used for bucket remotes.
+fn origin_fetch_options() -> FetchOptions<'static> {
+    let mut callbacks = RemoteCallbacks::new();
+    callbacks.credentials(|_url, username_from_url, allowed_types| {
+        if allowed_types.contains(CredentialType::USERNAME) {
+            Cred::username("git")
+        } else if allowed_types.contains(CredentialType::SSH_KEY) {
+            let username = username_from_url.unwrap_or("git");
+            Cred::ssh_key_from_agent(username)
+        } else {
+            Cred::default()
+        }
+    });
+
+    let mut fetch_options = FetchOptions::new();
+    fetch_options.remote_callbacks(callbacks);
+    fetch_options
+}
+
 fn update_bucket_sync(
     bucket_name: &str,
     bucket_path: &Path,
@@ -399,23 +469,7 @@ fn update_bucket_sync(
                 }
             };
 
-            // Set up callbacks for fetch
-            let mut callbacks = RemoteCallbacks::new();
-            callbacks.credentials(|_url, username_from_url, allowed_types| {
-                if allowed_types.contains(CredentialType::USERNAME) {
-                    Cred::username("git")
-                } else if allowed_types.contains(CredentialType::SSH_KEY) {
-                    let username = username_from_url.unwrap_or("git");
-                    Cred::ssh_key_from_agent(username)
-                } else if allowed_types.contains(CredentialType::USER_PASS_PLAINTEXT) {
-                    Cred::default()
-                } else {
-                    Cred::default()
-                }
-            });
-
-            let mut fetch_options = FetchOptions::new();
-            fetch_options.remote_callbacks(callbacks);
+            let mut fetch_options = origin_fetch_options();
 
             // Fetch latest changes
             match remote.fetch(&[] as &[&str], Some(&mut fetch_options), None) {
@@ -568,18 +622,116 @@ fn update_bucket_sync(
     }
 }
 
+// Command to check whether a bucket is behind its upstream (git fetch, no pull)
+#[command]
+pub async fn check_bucket_has_updates(
+    app: tauri::AppHandle,
+    bucket: String,
+) -> Result<bool, AppError> {
+    let bucket_path = get_bucket_path(&app, &bucket)?;
+    if !bucket_path.is_dir() {
+        return Err(AppError::NotFound(format!(
+            "Bucket '{}' does not exist",
+            bucket
+        )));
+    }
+
+    if !bucket_path.join(".git").exists() {
+        log::warn!(
+            "Bucket '{}' is not a git repository; reporting no updates",
+            bucket
+        );
+        return Ok(false);
+    }
+
+    tokio::task::spawn_blocking(move || check_bucket_has_updates_sync(&bucket, &bucket_path))
+        .await?
+}
+
+fn check_bucket_has_updates_sync(bucket_name: &str, bucket_path: &Path) -> Result<bool, AppError> {
+    let repo = Repository::open(bucket_path).map_err(|e| {
+        AppError::Io(format!(
+            "Failed to open bucket '{}' as git repository: {}",
+            bucket_name, e
+        ))
+    })?;
+
+    let mut remote = repo.find_remote("origin").map_err(|_| {
+        AppError::NotFound(format!("Bucket '{}' has no origin remote", bucket_name))
+    })?;
+    remote
+        .fetch(&[] as &[&str], Some(&mut origin_fetch_options()), None)
+        .map_err(|e| {
+            AppError::Network(format!("Failed to fetch bucket '{}': {}", bucket_name, e))
+        })?;
+
+    let head = repo.head().map_err(|e| {
+        AppError::Io(format!(
+            "Could not get current branch for bucket '{}': {}",
+            bucket_name, e
+        ))
+    })?;
+    let local_oid = head.target().ok_or_else(|| {
+        AppError::Io(format!(
+            "Bucket '{}' has a detached or unborn HEAD",
+            bucket_name
+        ))
+    })?;
+
+    // Compare against `@{u}`, falling back to origin/<branch> when no upstream is configured
+    let branch_name = head.shorthand().unwrap_or_default().to_string();
+    let upstream = repo
+        .find_branch(&branch_name, git2::BranchType::Local)
+        .and_then(|branch| branch.upstream())
+        .or_else(|_| repo.find_branch(&format!("origin/{}", branch_name), git2::BranchType::Remote))
+        .map_err(|e| {
+            AppError::NotFound(format!(
+                "Bucket '{}' has no upstream branch: {}",
+                bucket_name, e
+            ))
+        })?;
+    let upstream_oid = upstream.get().target().ok_or_else(|| {
+        AppError::NotFound(format!(
+            "Upstream of bucket '{}' has no target",
+            bucket_name
+        ))
+    })?;
+
+    if local_oid == upstream_oid {
+        return Ok(false);
+    }
+
+    // Only report updates when the upstream has commits we don't
+    let (_, behind) = repo
+        .graph_ahead_behind(local_oid, upstream_oid)
+        .map_err(|e| {
+            AppError::Io(format!(
+                "Failed to compare bucket '{}' with upstream: {}",
+                bucket_name, e
+            ))
+        })?;
+    Ok(behind > 0)
+}
+
 /// Command to update all buckets sequentially.
 /// Returns a list of per-bucket results. Non-fatal errors are captured in each result.
 #[command]
 pub async fn update_all_buckets(
     app: tauri::AppHandle,
     run_id: String,
-) -> Result<Vec<BucketInstallResult>, String> {
+) -> Result<Vec<BucketInstallResult>, AppError> {
     log::info!("Updating all buckets (auto-update task)");
 
+    let _turn = operation_queue::wait_for_turn(&app, &run_id, "Updating all buckets").await?;
+
     let buckets_dir = match get_buckets_dir(&app) {
         Ok(p) => p,
-        Err(e) => return Err(format!("Failed to resolve buckets directory: {}", e)),
+        Err(e) => {
+            return Err(AppError::NotFound(format!(
+                "Failed to resolve buckets directory: {}",
+                e
+            )))
+        }
     };
 
     if !buckets_dir.is_dir() {
@@ -595,7 +747,12 @@ pub async fn update_all_buckets(
 
     let entries = match fs::read_dir(&buckets_dir) {
         Ok(e) => e,
-        Err(e) => return Err(format!("Failed to read buckets directory: {}", e)),
+        Err(e) => {
+            return Err(AppError::Io(format!(
+                "Failed to read buckets directory: {}",
+                e
+            )))
+        }
     };
 
     for entry in entries.flatten() {
@@ -639,13 +796,15 @@ pub async fn update_all_buckets(
 
         let progress_event = BucketUpdateProgressEvent {
             run_id: run_id.clone(),
+            index,
             current: index + 1,
             total,
             bucket_name: name,
+            status: if result.success { "success" } else { "error" }.to_string(),
             result: result.clone(),
         };
 
-        if let Err(e) = app.emit("bucket-update-progress", progress_event) {
+        if let Err(e) = app.emit(EVENT_BUCKET_UPDATE_PROGRESS, progress_event) {
             log::warn!("Failed to emit bucket update progress event: {}", e);
         }
 
@@ -671,14 +830,216 @@ pub async fn update_all_buckets(
     Ok(results)
 }
 
+/// Replaces a bucket with a fresh clone of `url` at `branch`.
+///
+/// The clone is made next to the bucket first and the old copy is only deleted
+/// once the new one is in place, so a failed clone or move leaves the existing
+/// bucket untouched.
+fn reclone_bucket_sync(
+    name: &str,
+    path: &Path,
+    url: &str,
+    branch: &str,
+) -> Result<BucketInstallResult, String> {
+    let staging_path = path.with_file_name(format!(".{}.reclone", name));
+    remove_bucket_directory(&staging_path)?;
+
+    let branch = (!branch.is_empty() && branch != "HEAD").then_some(branch);
+    match clone_repository(url, &staging_path, branch) {
+        Ok(repo) => compress_git_refs(&repo, name),
+        Err(e) => {
+            let _ = remove_bucket_directory(&staging_path);
+            return Err(e);
+        }
+    }
+
+    // Swap by renames so a failure never leaves the bucket missing
+    let old_path = path.with_file_name(format!(".{}.old", name));
+    remove_bucket_directory(&old_path)?;
+    if let Err(e) = fs::rename(path, &old_path) {
+        let _ = remove_bucket_directory(&staging_path);
+        return Err(format!("Failed to move old clone aside: {}", e));
+    }
+    if let Err(e) = fs::rename(&staging_path, path) {
+        if let Err(restore) = fs::rename(&old_path, path) {
+            log::error!(
+                "Failed to restore bucket '{}' from {}: {}",
+                name,
+                old_path.display(),
+                restore
+            );
+        }
+        let _ = remove_bucket_directory(&staging_path);
+        return Err(format!("Failed to move fresh clone into place: {}", e));
+    }
+    if let Err(e) = remove_bucket_directory(&old_path) {
+        log::warn!("Failed to remove old clone of bucket '{}': {}", name, e);
+    }
+
+    let manifest_count = utils::count_manifests(path);
+    Ok(BucketInstallResult {
+        success: true,
+        message: format!(
+            "Re-cloned bucket '{}' with {} manifests",
+            name, manifest_count
+        ),
+        bucket_name: name.to_string(),
+        bucket_path: Some(path.to_string_lossy().to_string()),
+        manifest_count: Some(manifest_count),
+    })
+}
+
+/// Re-clones every git bucket from its current origin URL and branch.
+///
+/// This is a recovery path for corrupted bucket clones. The remotes are
+/// captured up front and reused exactly, so buckets added from unusual URLs
+/// come back from the same place. A `bucket-reclone-progress` event is emitted
+/// after each bucket.
+#[command]
+pub async fn reclone_all_buckets(
+    app: tauri::AppHandle,
+    run_id: String,
+) -> Result<Vec<BucketInstallResult>, AppError> {
+    log::info!("Re-cloning all buckets");
+
+    let _turn = operation_queue::wait_for_turn(&app, &run_id, "Re-cloning all buckets").await?;
+
+    let buckets_dir = get_buckets_dir(&app)
+        .map_err(|e| AppError::NotFound(format!("Failed to resolve buckets directory: {}", e)))?;
+    if !buckets_dir.is_dir() {
+        return Ok(vec![]);
+    }
+
+    let entries = fs::read_dir(&buckets_dir)
+        .map_err(|e| AppError::Io(format!("Failed to read buckets directory: {}", e)))?;
+    let mut remotes = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Some(name) = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .map(str::to_string)
+        else {
+            continue;
+        };
+        if !path.is_dir() || name.starts_with('.') {
+            continue;
+        }
+        match crate::commands::bucket::read_bucket_remote(&path).await {
+            Some(remote) => remotes.push((name, path, remote)),
+            None => log::info!("Skipping bucket '{}' without a git remote", name),
+        }
+    }
+    remotes.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let total = remotes.len();
+    let mut results = Vec::with_capacity(total);
+    for (index, (name, path, remote)) in remotes.into_iter().enumerate() {
+        log::info!(
+            "Re-cloning bucket '{}' from {} ({})",
+            name,
+            remote.url,
+            remote.branch
+        );
+        let name_clone = name.clone();
+        let path_clone = path.clone();
+        let result = match tokio::task::spawn_blocking(move || {
+            reclone_bucket_sync(&name_clone, &path_clone, &remote.url, &remote.branch)
+        })
+        .await
+        {
+            Ok(Ok(res)) => res,
+            Ok(Err(e)) => BucketInstallResult {
+                success: false,
+                message: e,
+                bucket_name: name.clone(),
+                bucket_path: Some(path.to_string_lossy().to_string()),
+                manifest_count: None,
+            },
+            Err(e) => BucketInstallResult {
+                success: false,
+                message: format!("Task failed: {}", e),
+                bucket_name: name.clone(),
+                bucket_path: Some(path.to_string_lossy().to_string()),
+                manifest_count: None,
+            },
+        };
+
+        let progress_event = BucketUpdateProgressEvent {
+            run_id: run_id.clone(),
+            index,
+            current: index + 1,
+            total,
+            bucket_name: name,
+            status: if result.success { "success" } else { "error" }.to_string(),
+            result: result.clone(),
+        };
+        if let Err(e) = app.emit(EVENT_BUCKET_RECLONE_PROGRESS, progress_event) {
+            log::warn!("Failed to emit bucket reclone progress event: {}", e);
+        }
+
+        results.push(result);
+    }
+
+    let recloned = results
+        .iter()
+        .filter(|result| result.success)
+        .filter_map(|result| {
+            result
+                .bucket_path
+                .as_ref()
+                .map(|path| (result.bucket_name.as_str(), PathBuf::from(path)))
+        })
+        .collect::<Vec<_>>();
+    if !recloned.is_empty() {
+        refresh_manifest_cache_for_buckets(recloned, "buckets re-cloned").await;
+    }
+
+    Ok(results)
+}
+
+// Command to update only the named buckets, e.g. to retry the failures of a bulk update
+#[command]
+pub async fn update_buckets(
+    app: tauri::AppHandle,
+    names: Vec<String>,
+) -> Result<Vec<BucketInstallResult>, AppError> {
+    log::info!("Updating {} selected bucket(s)", names.len());
+
+    let mut results = Vec::with_capacity(names.len());
+    for name in names {
+        let result = match update_bucket(app.clone(), name.clone()).await {
+            Ok(result) => result,
+            Err(e) => BucketInstallResult {
+                success: false,
+                message: e.to_string(),
+                bucket_name: name,
+                bucket_path: None,
+                manifest_count: None,
+            },
+        };
+        results.push(result);
+    }
+
+    Ok(results)
+}
+
 // Command to remove a bucket
 #[command]
 pub async fn remove_bucket(
     app: tauri::AppHandle,
     bucket_name: String,
-) -> Result<BucketInstallResult, String> {
+) -> Result<BucketInstallResult, AppError> {
     log::info!("Removing bucket: {}", bucket_name);
 
+    let operation_id = bucket_operation_id("remove", &bucket_name);
+    let _turn = operation_queue::wait_for_turn(
+        &app,
+        &operation_id,
+        &format!("Removing bucket {}", bucket_name),
+    )
+    .await?;
+
     let bucket_path = get_bucket_path(&app, &bucket_name)?;
 
     if !bucket_path.exists() {
@@ -5,7 +5,7 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 use tokio::fs;
 use tokio::io::AsyncWriteExt;
 use tokio::sync::Mutex;
@@ -35,6 +35,15 @@ pub struct BucketCacheRefreshResult {
     pub remote_updated_at: Option<String>,
 }
 
+/// Size and freshness of the bucket search cache, shown on the settings page.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BucketCacheStats {
+    pub entries: usize,
+    pub bytes: u64,
+    /// Seconds since the cache was written; `None` when there is no cache.
+    pub age_secs: Option<u64>,
+}
+
 impl Default for BucketFilterOptions {
     fn default() -> Self {
         Self {
@@ -67,6 +76,12 @@ static CACHE_REFRESH_MUTEX: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
 const BUCKET_DIRECTORY_URL: &str =
     "https://github.com/rasa/scoop-directory/raw/refs/heads/master/by-stars.md";
 
+/// Cached bucket data older than this is refetched, unless `buckets.searchCacheTtlHours` is set.
+pub const DEFAULT_SEARCH_CACHE_TTL_HOURS: u64 = 24;
+
+/// Upper bound on cached buckets; the least-starred ones are dropped beyond it.
+const MAX_BUCKET_CACHE_ENTRIES: usize = 20_000;
+
 // Get the cache file path in the app data directory
 fn get_cache_file_path() -> Result<PathBuf, String> {
     // Try to use the correct app data directory
@@ -175,6 +190,7 @@ async fn load_cache_from_disk() -> Result<HashMap<String, SearchableBucket>, Str
             result.map_err(|e| format!("Failed to deserialize bucket from CSV: {}", e))?;
         buckets.insert(bucket.full_name.clone(), bucket);
     }
+    limit_cache_entries(&mut buckets);
 
     log::info!("Loaded {} buckets from cache", buckets.len());
 
@@ -212,10 +228,11 @@ pub async fn fetch_and_parse_bucket_directory(
 
     // Convert to HashMap keyed by full_name (owner/repo) to avoid deduplication of bucket names
     // Store ALL buckets without filtering to preserve cache integrity
-    let bucket_map: HashMap<String, SearchableBucket> = buckets
+    let mut bucket_map: HashMap<String, SearchableBucket> = buckets
         .into_iter()
         .map(|bucket| (bucket.full_name.clone(), bucket))
         .collect();
+    limit_cache_entries(&mut bucket_map);
 
     log::info!(
         "Stored {} buckets to cache (no filtering applied at fetch time)",
@@ -542,14 +559,18 @@ async fn get_remote_last_modified_at() -> Result<Option<DateTime<Utc>>, String>
     Ok(parsed)
 }
 
-pub async fn get_cache_info() -> Result<BucketCacheInfo, String> {
+pub async fn get_cache_info(check_remote: bool) -> Result<BucketCacheInfo, String> {
     let cache_file = get_cache_file_path()?;
     let exists = cache_file.exists();
 
     let local_modified = get_cache_file_modified_at().await?;
     let local_updated_at = local_modified.map(format_system_time);
 
-    let remote_modified = get_remote_last_modified_at().await.unwrap_or(None);
+    let remote_modified = if check_remote {
+        get_remote_last_modified_at().await.unwrap_or(None)
+    } else {
+        None
+    };
     let remote_updated_at = remote_modified.map(|dt| dt.format("%Y-%m-%d %H:%M UTC").to_string());
 
     let has_remote_update =
@@ -620,42 +641,106 @@ pub async fn refresh_cache_if_needed(
     })
 }
 
-// Get cached buckets or fetch if not cached
+// Keep only the most-starred buckets once the cache exceeds its size bound
+fn limit_cache_entries(buckets: &mut HashMap<String, SearchableBucket>) {
+    if buckets.len() <= MAX_BUCKET_CACHE_ENTRIES {
+        return;
+    }
+
+    let mut ranked: Vec<(String, u32)> = buckets
+        .iter()
+        .map(|(key, bucket)| (key.clone(), bucket.stars))
+        .collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+    let dropped = ranked.len() - MAX_BUCKET_CACHE_ENTRIES;
+    for (key, _) in ranked.into_iter().skip(MAX_BUCKET_CACHE_ENTRIES) {
+        buckets.remove(&key);
+    }
+
+    log::info!(
+        "Bucket cache limited to {} entries ({} dropped)",
+        MAX_BUCKET_CACHE_ENTRIES,
+        dropped
+    );
+}
+
+// Age of the disk cache, or None when there is no cache file
+async fn get_cache_age() -> Option<Duration> {
+    let modified = get_cache_file_modified_at().await.ok().flatten()?;
+    Some(
+        SystemTime::now()
+            .duration_since(modified)
+            .unwrap_or_default(),
+    )
+}
+
+// Get cached buckets or fetch if not cached or older than `ttl`
 // NOTE: Memory cache stores ALL buckets unfiltered. Filters are applied only at return time.
 pub async fn get_cached_buckets(
     filters: Option<BucketFilterOptions>,
+    ttl: Duration,
 ) -> Result<HashMap<String, SearchableBucket>, String> {
-    // First check memory cache (always contains complete unfiltered data)
-    {
-        let cache = (*BUCKET_CACHE).read().await;
-        if !cache.is_empty() {
-            log::debug!("Found {} buckets in memory cache", cache.len());
-            // Apply filters only at return time, not when storing
-            return Ok(apply_filters_to_cache(&cache, filters));
+    let is_fresh = get_cache_age().await.is_some_and(|age| age <= ttl);
+
+    if is_fresh {
+        // First check memory cache (always contains complete unfiltered data)
+        {
+            let cache = (*BUCKET_CACHE).read().await;
+            if !cache.is_empty() {
+                log::debug!("Found {} buckets in memory cache", cache.len());
+                // Apply filters only at return time, not when storing
+                return Ok(apply_filters_to_cache(&cache, filters));
+            }
         }
-    }
 
-    // Try to load from disk cache (also contains complete unfiltered data)
-    match load_cache_from_disk().await {
-        Ok(disk_cache) if !disk_cache.is_empty() => {
-            log::info!("Loaded {} buckets from disk cache", disk_cache.len());
+        // Try to load from disk cache (also contains complete unfiltered data)
+        match load_cache_from_disk().await {
+            Ok(disk_cache) if !disk_cache.is_empty() => {
+                log::info!("Loaded {} buckets from disk cache", disk_cache.len());
 
-            // Store COMPLETE unfiltered data to memory cache
-            {
-                let mut cache = BUCKET_CACHE.write().await;
-                *cache = disk_cache.clone();
-            }
+                // Store COMPLETE unfiltered data to memory cache
+                {
+                    let mut cache = BUCKET_CACHE.write().await;
+                    *cache = disk_cache.clone();
+                }
 
-            // Apply filters only at return time
-            return Ok(apply_filters_to_cache(&disk_cache, filters));
+                // Apply filters only at return time
+                return Ok(apply_filters_to_cache(&disk_cache, filters));
+            }
+            Ok(_) => log::info!("Disk cache is empty or doesn't exist"),
+            Err(e) => log::warn!("Failed to load disk cache: {}", e),
         }
-        Ok(_) => log::info!("Disk cache is empty or doesn't exist"),
-        Err(e) => log::warn!("Failed to load disk cache: {}", e),
+    } else {
+        log::info!(
+            "Bucket cache is missing or older than {} hours",
+            ttl.as_secs() / 3600
+        );
     }
 
-    log::info!("No cache found, fetching bucket directory...");
+    log::info!("Fetching bucket directory...");
     // Fetch stores ALL buckets (no filtering at fetch time)
-    let buckets = fetch_and_parse_bucket_directory(None).await?;
+    let buckets = match fetch_and_parse_bucket_directory(None).await {
+        Ok(buckets) => buckets,
+        Err(e) => {
+            // Stale data beats no data when the directory can't be reached
+            let stale = {
+                let cache = (*BUCKET_CACHE).read().await;
+                cache.clone()
+            };
+            let stale = if stale.is_empty() {
+                load_cache_from_disk().await.unwrap_or_default()
+            } else {
+                stale
+            };
+            if stale.is_empty() {
+                return Err(e);
+            }
+
+            log::warn!("Using stale bucket cache after refetch failed: {}", e);
+            stale
+        }
+    };
 
     // Store COMPLETE unfiltered data to memory cache
     {
@@ -702,12 +787,50 @@ fn apply_filters_to_cache(
     filtered
 }
 
+// Report the number of cached buckets, the cache file size and its age
+pub async fn get_cache_stats() -> Result<BucketCacheStats, String> {
+    let cache_file = get_cache_file_path()?;
+    if !cache_file.exists() {
+        return Ok(BucketCacheStats {
+            entries: 0,
+            bytes: 0,
+            age_secs: None,
+        });
+    }
+
+    let bytes = fs::metadata(&cache_file)
+        .await
+        .map(|metadata| metadata.len())
+        .map_err(|e| format!("Failed to read cache file metadata: {}", e))?;
+
+    let memory_entries = (*BUCKET_CACHE).read().await.len();
+    let entries = if memory_entries > 0 {
+        memory_entries
+    } else {
+        load_cache_from_disk().await?.len()
+    };
+
+    Ok(BucketCacheStats {
+        entries,
+        bytes,
+        age_secs: get_cache_age().await.map(|age| age.as_secs()),
+    })
+}
+
 // Check if cache file exists
 pub async fn cache_exists() -> Result<bool, String> {
     let cache_file = get_cache_file_path()?;
     Ok(cache_file.exists())
 }
 
+// Drop the in-memory copy so the next lookup reloads it from disk; returns the entries dropped
+pub async fn clear_memory_cache() -> usize {
+    let mut cache = (*BUCKET_CACHE).write().await;
+    let entries = cache.len();
+    cache.clear();
+    entries
+}
+
 // Clear cache (useful for testing or forced refresh)
 pub async fn clear_cache() {
     // Clear memory cache
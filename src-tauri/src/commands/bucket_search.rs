@@ -1,7 +1,11 @@
-use super::bucket_parser::{self, BucketCacheInfo, BucketCacheRefreshResult, BucketFilterOptions};
+use super::bucket_parser::{
+    self, BucketCacheInfo, BucketCacheRefreshResult, BucketCacheStats, BucketFilterOptions,
+};
+use crate::commands::network::is_offline_mode;
 use crate::state::AppState;
 use serde::{Deserialize, Serialize};
-use tauri::State;
+use std::time::Duration;
+use tauri::{AppHandle, State};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchableBucket {
@@ -17,15 +21,27 @@ pub struct SearchableBucket {
 }
 
 #[tauri::command]
-pub async fn get_bucket_cache_info() -> Result<BucketCacheInfo, String> {
-    bucket_parser::get_cache_info().await
+pub async fn get_bucket_cache_info(app: AppHandle) -> Result<BucketCacheInfo, String> {
+    bucket_parser::get_cache_info(!is_offline_mode(&app)).await
 }
 
 #[tauri::command]
 pub async fn refresh_bucket_cache_if_needed(
+    app: AppHandle,
     disable_chinese_buckets: Option<bool>,
     minimum_stars: Option<u32>,
 ) -> Result<BucketCacheRefreshResult, String> {
+    if is_offline_mode(&app) {
+        log::info!("Offline mode is enabled, skipping bucket cache refresh");
+        return Ok(BucketCacheRefreshResult {
+            updated: false,
+            skipped: true,
+            reason: "offline".to_string(),
+            local_updated_at: None,
+            remote_updated_at: None,
+        });
+    }
+
     let filters = BucketFilterOptions {
         disable_chinese_buckets: disable_chinese_buckets.unwrap_or(false),
         minimum_stars: minimum_stars.unwrap_or(2),
@@ -34,6 +50,16 @@ pub async fn refresh_bucket_cache_if_needed(
     bucket_parser::refresh_cache_if_needed(Some(filters)).await
 }
 
+/// A known bucket tagged with whether it is already added locally.
+#[derive(Debug, Clone, Serialize)]
+pub struct DefaultBucket {
+    #[serde(flatten)]
+    pub bucket: SearchableBucket,
+    pub installed: bool,
+    /// Local bucket directory when installed.
+    pub local_path: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct BucketSearchRequest {
     pub query: Option<String>,
@@ -185,19 +211,75 @@ fn get_verified_buckets() -> Vec<SearchableBucket> {
         .collect()
 }
 
+/// Reads how long cached bucket search data stays fresh from `buckets.searchCacheTtlHours`.
+fn resolve_search_cache_ttl(app: &AppHandle) -> Duration {
+    let hours = crate::commands::settings::get_config_value(
+        app.clone(),
+        "buckets.searchCacheTtlHours".to_string(),
+    )
+    .ok()
+    .flatten()
+    .and_then(|v| v.as_u64())
+    .unwrap_or(bucket_parser::DEFAULT_SEARCH_CACHE_TTL_HOURS);
+
+    Duration::from_secs(hours.saturating_mul(3600))
+}
+
 // Parse the massive bucket list from GitHub using efficient parser
 async fn fetch_expanded_bucket_list(
     filters: Option<BucketFilterOptions>,
+    ttl: Duration,
 ) -> Result<Vec<SearchableBucket>, String> {
     log::info!("Fetching expanded bucket list using efficient parser...");
 
-    let bucket_map = bucket_parser::get_cached_buckets(filters).await?;
+    let bucket_map = bucket_parser::get_cached_buckets(filters, ttl).await?;
     let buckets: Vec<SearchableBucket> = bucket_map.into_values().collect();
 
     log::info!("Retrieved {} buckets from cache/parser", buckets.len());
     Ok(buckets)
 }
 
+/// Lists the locally cloned buckets as search results, for offline mode.
+fn get_local_buckets(scoop_path: &std::path::Path) -> Result<Vec<SearchableBucket>, String> {
+    let buckets_path = scoop_path.join("buckets");
+    if !buckets_path.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let verified_buckets = get_verified_buckets();
+    let local_buckets = super::bucket::scan_buckets(&buckets_path)?;
+
+    Ok(local_buckets
+        .into_iter()
+        .map(|bucket| {
+            let verified = verified_buckets.iter().find(|v| v.name == bucket.name);
+            let url = bucket.git_url.unwrap_or_default();
+            let full_name = verified
+                .map(|v| v.full_name.clone())
+                .or_else(|| {
+                    let repo = url.trim_end_matches('/').trim_end_matches(".git");
+                    let mut parts = repo.rsplit('/');
+                    let name = parts.next()?;
+                    let owner = parts.next()?;
+                    Some(format!("{}/{}", owner, name))
+                })
+                .unwrap_or_else(|| bucket.name.clone());
+
+            SearchableBucket {
+                full_name,
+                description: verified.map(|v| v.description.clone()).unwrap_or_default(),
+                url,
+                stars: verified.map_or(0, |v| v.stars),
+                forks: verified.map_or(0, |v| v.forks),
+                apps: bucket.manifest_count,
+                last_updated: bucket.last_updated.unwrap_or_default(),
+                is_verified: verified.is_some(),
+                name: bucket.name,
+            }
+        })
+        .collect())
+}
+
 fn compare_bucket_identity(a: &SearchableBucket, b: &SearchableBucket) -> std::cmp::Ordering {
     a.name
         .to_lowercase()
@@ -265,10 +347,18 @@ fn sort_buckets(buckets: &mut [SearchableBucket], sort_by: &str) {
 
 #[tauri::command]
 pub async fn search_buckets(
+    app: AppHandle,
     request: BucketSearchRequest,
-    _state: State<'_, AppState>,
+    state: State<'_, AppState>,
 ) -> Result<BucketSearchResponse, String> {
-    let mut buckets = if request.include_expanded {
+    let offline = is_offline_mode(&app);
+    let mut buckets = if offline {
+        log::info!("Offline mode is enabled, searching locally cloned buckets only");
+        let scoop_path = state.scoop_path();
+        tauri::async_runtime::spawn_blocking(move || get_local_buckets(&scoop_path))
+            .await
+            .map_err(|e| format!("Failed to join local bucket scan task: {}", e))??
+    } else if request.include_expanded {
         log::info!("Performing expanded search including all community buckets");
 
         // Create filter options from request
@@ -297,7 +387,8 @@ pub async fn search_buckets(
             verified_buckets.iter().map(|b| b.name.clone()).collect();
 
         // Get expanded buckets from cache/parser with filters
-        let mut expanded_buckets = fetch_expanded_bucket_list(filters).await?;
+        let mut expanded_buckets =
+            fetch_expanded_bucket_list(filters, resolve_search_cache_ttl(&app)).await?;
 
         // Mark verified buckets in the expanded list
         for bucket in &mut expanded_buckets {
@@ -352,7 +443,7 @@ pub async fn search_buckets(
     );
 
     // Calculate expanded list size (rough estimate)
-    let expanded_size_mb = if request.include_expanded {
+    let expanded_size_mb = if request.include_expanded && !offline {
         Some(14.0) // Approximate size as mentioned in the request
     } else {
         None
@@ -367,7 +458,7 @@ pub async fn search_buckets(
     Ok(BucketSearchResponse {
         buckets,
         total_count,
-        is_expanded_search: request.include_expanded,
+        is_expanded_search: request.include_expanded && !offline,
         expanded_list_size_mb: expanded_size_mb,
     })
 }
@@ -382,10 +473,39 @@ pub async fn search_buckets(
 // }
 
 #[tauri::command]
-pub async fn get_default_buckets() -> Result<Vec<SearchableBucket>, String> {
+pub async fn get_default_buckets(state: State<'_, AppState>) -> Result<Vec<DefaultBucket>, String> {
     let mut buckets = get_verified_buckets();
     sort_buckets(&mut buckets, "stars"); // Sort by stars by default
-    Ok(buckets)
+
+    // Local bucket directories keyed by lowercase name for case-insensitive matching
+    let local_buckets: std::collections::HashMap<String, std::path::PathBuf> =
+        std::fs::read_dir(state.scoop_path().join("buckets"))
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .map(|entry| entry.path())
+                    .filter(|path| path.is_dir())
+                    .filter_map(|path| {
+                        let name = path.file_name()?.to_string_lossy().to_lowercase();
+                        Some((name, path))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+    Ok(buckets
+        .into_iter()
+        .map(|bucket| {
+            let local_path = local_buckets
+                .get(&bucket.name.to_lowercase())
+                .map(|path| path.to_string_lossy().to_string());
+            DefaultBucket {
+                bucket,
+                installed: local_path.is_some(),
+                local_path,
+            }
+        })
+        .collect())
 }
 
 #[tauri::command]
@@ -395,6 +515,11 @@ pub async fn clear_bucket_cache() -> Result<(), String> {
     Ok(())
 }
 
+#[tauri::command]
+pub async fn get_bucket_cache_stats() -> Result<BucketCacheStats, String> {
+    bucket_parser::get_cache_stats().await
+}
+
 #[tauri::command]
 pub async fn check_bucket_cache_exists() -> Result<bool, String> {
     match bucket_parser::cache_exists().await {
@@ -0,0 +1,141 @@
+//! Rebuilds every backend cache in a fixed order.
+//!
+//! Meant for support situations where a cache is suspected to be stale; the
+//! caches normally refresh themselves through their own invalidation paths.
+use crate::commands::{bucket_parser, installed, search, updates};
+use crate::state::AppState;
+use crate::utils;
+use serde::Serialize;
+use std::time::Instant;
+use tauri::{AppHandle, Emitter, Runtime, State};
+
+pub const EVENT_CACHE_REBUILD_PROGRESS: &str = "cache-rebuild-progress";
+
+/// The caches rebuilt by [`rebuild_all_caches`], in the order they are rebuilt.
+/// Later stages read from the earlier ones.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CacheRebuildStage {
+    InstalledPackages,
+    PackageVersions,
+    SearchIndex,
+    /// Package sizes, Scoop Apps shortcuts and the bucket directory. These are
+    /// flushed rather than rebuilt and refill on their next use.
+    DerivedCaches,
+    UpdateCheck,
+}
+
+const STAGES: [CacheRebuildStage; 5] = [
+    CacheRebuildStage::InstalledPackages,
+    CacheRebuildStage::PackageVersions,
+    CacheRebuildStage::SearchIndex,
+    CacheRebuildStage::DerivedCaches,
+    CacheRebuildStage::UpdateCheck,
+];
+
+/// Emitted when a stage starts and again when it finishes.
+#[derive(Serialize, Debug, Clone)]
+pub struct CacheRebuildProgressEvent {
+    pub stage: CacheRebuildStage,
+    pub current: usize,
+    pub total: usize,
+    pub done: bool,
+}
+
+/// How a single stage went.
+#[derive(Serialize, Debug, Clone)]
+pub struct CacheRebuildStageResult {
+    pub stage: CacheRebuildStage,
+    pub duration_ms: u64,
+    /// Number of entries the stage loaded, e.g. packages or manifests, or
+    /// flushed for `DerivedCaches`.
+    pub items: usize,
+    pub error: Option<String>,
+}
+
+async fn run_stage<R: Runtime>(
+    app: &AppHandle<R>,
+    state: &State<'_, AppState>,
+    stage: CacheRebuildStage,
+) -> Result<usize, String> {
+    match stage {
+        CacheRebuildStage::InstalledPackages => {
+            installed::rebuild_installed_cache(app.clone(), state.clone())
+                .await
+                .map(|packages| packages.len())
+        }
+        CacheRebuildStage::PackageVersions => {
+            installed::rebuild_package_versions_cache(state).await
+        }
+        CacheRebuildStage::SearchIndex => search::rebuild_manifest_cache(app.clone()).await,
+        CacheRebuildStage::DerivedCaches => Ok(installed::clear_package_size_cache()
+            + utils::clear_scoop_app_shortcuts_cache()
+            + bucket_parser::clear_memory_cache().await),
+        CacheRebuildStage::UpdateCheck => {
+            *state.update_check.lock().await = None;
+            updates::check_for_updates(app.clone(), state.clone())
+                .await
+                .map(|packages| packages.len())
+        }
+    }
+}
+
+/// Invalidates and repopulates the installed packages, versions, search index
+/// and update check caches, one after another, and flushes the caches derived
+/// from them.
+///
+/// The installed stage holds the scan guard, so ambient refreshes wait for it
+/// and then reuse its result. A failed stage is reported and the remaining
+/// stages still run.
+#[tauri::command]
+pub async fn rebuild_all_caches<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+) -> Result<Vec<CacheRebuildStageResult>, String> {
+    let total = STAGES.len();
+    let mut results = Vec::with_capacity(total);
+
+    for (index, stage) in STAGES.into_iter().enumerate() {
+        let emit = |done: bool| {
+            let _ = app.emit(
+                EVENT_CACHE_REBUILD_PROGRESS,
+                CacheRebuildProgressEvent {
+                    stage,
+                    current: index + 1,
+                    total,
+                    done,
+                },
+            );
+        };
+
+        emit(false);
+        let started = Instant::now();
+        let outcome = run_stage(&app, &state, stage).await;
+        let duration_ms = started.elapsed().as_millis() as u64;
+        emit(true);
+
+        let (items, error) = match outcome {
+            Ok(items) => {
+                log::info!(
+                    "Rebuilt {:?} cache in {}ms ({} entries)",
+                    stage,
+                    duration_ms,
+                    items
+                );
+                (items, None)
+            }
+            Err(e) => {
+                log::warn!("Failed to rebuild {:?} cache: {}", stage, e);
+                (0, Some(e))
+            }
+        };
+        results.push(CacheRebuildStageResult {
+            stage,
+            duration_ms,
+            items,
+            error,
+        });
+    }
+
+    Ok(results)
+}
@@ -1,8 +1,11 @@
 //! Commands for retrieving diagnostic information about the application.
 use crate::state::AppState;
 use chrono::Local;
+use once_cell::sync::Lazy;
+use regex::Regex;
 use std::fs;
 use std::path::PathBuf;
+use std::time::Duration;
 use tauri::State;
 
 // Note: Retry logic constants are defined locally in functions as needed
@@ -16,6 +19,16 @@ const VERSION_FILE: &str = "version.txt";
 const FACTORY_RESET_MARKER: &str = ".factory_reset";
 const WEBVIEW_CLEANUP_MARKER: &str = ".cleanup_webview_on_startup";
 
+// Timeout for the version probes included in debug info
+const VERSION_PROBE_TIMEOUT_SECS: u64 = 10;
+
+// Matches dotted version numbers such as `0.5.2` or `2.44.0.windows.1`
+static VERSION_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\d+(?:\.\d+)+\S*").unwrap());
+
+// Matches the `[LEVEL]` tag that tauri-plugin-log writes after the timestamp and target
+static LOG_LEVEL_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(?:\[[^\]]*\])*?\[(TRACE|DEBUG|INFO|WARN|ERROR)\]").unwrap());
+
 // Backup file extension
 const BACKUP_EXT: &str = ".bak";
 
@@ -300,6 +313,61 @@ pub fn factory_reset(app: tauri::AppHandle) -> Result<(), String> {
     Ok(())
 }
 
+/// Extracts the first version number from a `--version` output, falling back to its first line.
+fn parse_version_output(output: &str) -> Option<String> {
+    if let Some(m) = VERSION_RE.find(output) {
+        return Some(m.as_str().to_string());
+    }
+
+    output
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(str::to_string)
+}
+
+/// Runs a `--version` style command and returns the parsed version or a failure string.
+async fn probe_version(command: &str) -> Result<String, String> {
+    let mut cmd = crate::commands::powershell::create_powershell_command(command);
+    cmd.kill_on_drop(true);
+
+    let output = tokio::time::timeout(
+        Duration::from_secs(VERSION_PROBE_TIMEOUT_SECS),
+        cmd.output(),
+    )
+    .await
+    .map_err(|_| {
+        format!(
+            "`{}` timed out after {}s",
+            command, VERSION_PROBE_TIMEOUT_SECS
+        )
+    })?
+    .map_err(|e| format!("Failed to run `{}`: {}", command, e))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let message = [stderr.trim(), stdout.trim()]
+            .into_iter()
+            .find(|text| !text.is_empty())
+            .unwrap_or("no output");
+        return Err(format!("`{}` failed: {}", command, message));
+    }
+
+    parse_version_output(&stdout).ok_or_else(|| format!("`{}` produced no output", command))
+}
+
+/// Converts a version probe result into a JSON object with either `version` or `error` set.
+fn version_probe_to_json(result: Result<String, String>) -> serde_json::Value {
+    match result {
+        Ok(version) => serde_json::json!({ "version": version, "error": null }),
+        Err(e) => {
+            log::warn!("=== DEBUG INFO === {}", e);
+            serde_json::json!({ "version": null, "error": e })
+        }
+    }
+}
+
 /// Gets diagnostic information about the application's state.
 #[tauri::command]
 pub async fn get_debug_info(state: State<'_, AppState>) -> Result<serde_json::Value, String> {
@@ -343,9 +411,24 @@ pub async fn get_debug_info(state: State<'_, AppState>) -> Result<serde_json::Va
     };
     drop(cache_guard); // Explicitly drop guard
 
+    let scoop_path_str = scoop_path.display().to_string();
+    let scoop_path_validation =
+        match crate::commands::settings::validate_scoop_directory(scoop_path_str.clone()) {
+            Ok(result) => serde_json::to_value(result).unwrap_or(serde_json::Value::Null),
+            Err(e) => serde_json::json!({ "valid": false, "message": e }),
+        };
+
+    let (scoop_version, git_version) = tokio::join!(
+        probe_version("scoop --version"),
+        probe_version("git --version")
+    );
+
     let debug_result = serde_json::json!({
         "timestamp": Local::now().to_rfc3339(),
-        "scoop_path": scoop_path.display().to_string(),
+        "scoop_path": scoop_path_str,
+        "scoop_path_validation": scoop_path_validation,
+        "scoop_version": version_probe_to_json(scoop_version),
+        "git_version": version_probe_to_json(git_version),
         "apps_dir_exists": apps_dir_exists,
         "app_count": app_count,
         "cache_info": cache_info,
@@ -360,6 +443,85 @@ pub async fn get_debug_info(state: State<'_, AppState>) -> Result<serde_json::Va
     Ok(debug_result)
 }
 
+/// Bundles all log files and a `debug_info.json` snapshot into a zip archive at `dest`.
+///
+/// Log files are read and copied into the archive, never moved, so the active log keeps working.
+#[tauri::command]
+pub async fn export_logs_zip(state: State<'_, AppState>, dest: String) -> Result<String, String> {
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+
+    let dest_path = PathBuf::from(&dest);
+    if dest_path.is_dir() {
+        return Err(format!(
+            "Destination is a directory: {}",
+            dest_path.display()
+        ));
+    }
+    if let Some(parent) = dest_path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create directory {}: {}", parent.display(), e))?;
+    }
+
+    let debug_info = get_debug_info(state).await?;
+    let debug_info_json = serde_json::to_vec_pretty(&debug_info)
+        .map_err(|e| format!("Failed to serialize debug info: {}", e))?;
+
+    let log_files: Vec<PathBuf> = match get_log_dir() {
+        Some(log_dir) if log_dir.is_dir() => fs::read_dir(&log_dir)
+            .map_err(|e| format!("Failed to read log directory {}: {}", log_dir.display(), e))?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file())
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    let file = fs::File::create(&dest_path)
+        .map_err(|e| format!("Failed to create {}: {}", dest_path.display(), e))?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+    zip.start_file("debug_info.json", options)
+        .map_err(|e| format!("Failed to add debug_info.json: {}", e))?;
+    zip.write_all(&debug_info_json)
+        .map_err(|e| format!("Failed to write debug_info.json: {}", e))?;
+
+    let mut added_count = 0;
+    for path in &log_files {
+        let file_name = match path.file_name().and_then(|n| n.to_str()) {
+            Some(name) => name,
+            None => continue,
+        };
+
+        // Read the whole file so an in-use log is copied as-is without being locked or moved
+        let content = match fs::read(path) {
+            Ok(content) => content,
+            Err(e) => {
+                log::warn!("Skipping log file {}: {}", path.display(), e);
+                continue;
+            }
+        };
+
+        zip.start_file(format!("logs/{}", file_name), options)
+            .map_err(|e| format!("Failed to add {}: {}", file_name, e))?;
+        zip.write_all(&content)
+            .map_err(|e| format!("Failed to write {}: {}", file_name, e))?;
+        added_count += 1;
+    }
+
+    zip.finish()
+        .map_err(|e| format!("Failed to finalize {}: {}", dest_path.display(), e))?;
+
+    log::info!(
+        "Exported {} log files to {}",
+        added_count,
+        dest_path.display()
+    );
+
+    Ok(dest_path.to_string_lossy().to_string())
+}
+
 /// Gets the current application logs from the logging system
 #[tauri::command]
 pub fn get_app_logs() -> Result<String, String> {
@@ -451,18 +613,50 @@ pub fn get_app_logs() -> Result<String, String> {
     Ok(log_info)
 }
 
-/// Reads the current application log file
+/// Parses the level tag of a log line, returning `None` for lines without one.
+fn parse_log_line_level(line: &str) -> Option<log::Level> {
+    LOG_LEVEL_RE
+        .captures(line)
+        .and_then(|caps| caps.get(1))
+        .and_then(|level| level.as_str().parse().ok())
+}
+
+/// Keeps only the lines at or above `min_level` that contain `contains`.
+///
+/// Lines without a recognizable level are kept only when no minimum level is requested.
+fn filter_log_lines(
+    content: &str,
+    min_level: Option<log::Level>,
+    contains: Option<&str>,
+) -> String {
+    content
+        .lines()
+        .filter(|line| match min_level {
+            Some(min) => parse_log_line_level(line).is_some_and(|level| level <= min),
+            None => true,
+        })
+        .filter(|line| contains.is_none_or(|needle| line.contains(needle)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Reads the current application log file, optionally filtered by level and substring
 #[tauri::command]
-pub fn read_app_log_file() -> Result<String, String> {
-    // Determine log file path - use APPDATA\com.pailer.ks\logs\pailer.log on Windows
-    let log_file = if let Some(data_dir) = dirs::data_dir() {
-        data_dir
-            .join("com.pailer.ks")
-            .join("logs")
-            .join("pailer.log")
-    } else {
-        PathBuf::from("./logs/pailer.log")
+pub fn read_app_log_file(
+    min_level: Option<String>,
+    contains: Option<String>,
+) -> Result<String, String> {
+    let min_level = match min_level.as_deref().map(str::trim) {
+        Some(level) if !level.is_empty() => Some(
+            level
+                .parse::<log::Level>()
+                .map_err(|_| format!("Invalid log level: {}", level))?,
+        ),
+        _ => None,
     };
+    let contains = contains.as_deref().filter(|needle| !needle.is_empty());
+
+    let log_file = current_log_file();
 
     // Validate file exists and check size
     if !log_file.exists() {
@@ -472,6 +666,14 @@ pub fn read_app_log_file() -> Result<String, String> {
         ));
     }
 
+    let apply_filters = |content: String| {
+        if min_level.is_none() && contains.is_none() {
+            content
+        } else {
+            filter_log_lines(&content, min_level, contains)
+        }
+    };
+
     match log_file.metadata() {
         Ok(metadata) => {
             const MAX_LOG_SIZE: u64 = 10 * 1024 * 1024; // 10 MB limit
@@ -479,7 +681,7 @@ pub fn read_app_log_file() -> Result<String, String> {
                 return Ok(format!(
                     "Log file too large ({} MB). Showing last 1MB only.\n\n--- Last 1MB of log ---\n{}",
                     metadata.len() / (1024 * 1024),
-                    read_last_n_bytes(&log_file, 1024 * 1024)?
+                    apply_filters(read_last_n_bytes(&log_file, 1024 * 1024)?)
                 ));
             }
         }
@@ -490,11 +692,128 @@ pub fn read_app_log_file() -> Result<String, String> {
 
     // Read the log file
     match fs::read_to_string(&log_file) {
-        Ok(content) => Ok(content),
+        Ok(content) => Ok(apply_filters(content)),
         Err(e) => Err(format!("Failed to read log file: {}", e)),
     }
 }
 
+/// Path of the active log file - APPDATA\com.pailer.ks\logs\pailer.log on Windows
+fn current_log_file() -> PathBuf {
+    if let Some(data_dir) = dirs::data_dir() {
+        data_dir
+            .join("com.pailer.ks")
+            .join("logs")
+            .join("pailer.log")
+    } else {
+        PathBuf::from("./logs/pailer.log")
+    }
+}
+
+pub const EVENT_LOG_LINE: &str = "log-line";
+
+static LOG_TAIL_TASK: Lazy<std::sync::Mutex<Option<tauri::async_runtime::JoinHandle<()>>>> =
+    Lazy::new(|| std::sync::Mutex::new(None));
+
+/// Follows a log file from its current end, reopening it when it is rotated.
+struct LogTail {
+    path: PathBuf,
+    position: u64,
+    created: Option<std::time::SystemTime>,
+    partial: String,
+}
+
+impl LogTail {
+    fn new(path: PathBuf) -> Self {
+        let metadata = fs::metadata(&path).ok();
+        Self {
+            path,
+            position: metadata.as_ref().map(|m| m.len()).unwrap_or(0),
+            created: metadata.and_then(|m| m.created().ok()),
+            partial: String::new(),
+        }
+    }
+
+    /// Returns the complete lines appended since the last poll. A file that
+    /// shrank or was recreated is read again from the start.
+    fn poll(&mut self) -> Vec<String> {
+        use std::io::{Read, Seek, SeekFrom};
+
+        let Ok(metadata) = fs::metadata(&self.path) else {
+            return Vec::new();
+        };
+        let created = metadata.created().ok();
+        if metadata.len() < self.position || created != self.created {
+            self.position = 0;
+            self.partial.clear();
+            self.created = created;
+        }
+        if metadata.len() == self.position {
+            return Vec::new();
+        }
+
+        let Ok(mut file) = fs::File::open(&self.path) else {
+            return Vec::new();
+        };
+        let mut buffer = Vec::new();
+        if file.seek(SeekFrom::Start(self.position)).is_err()
+            || file.read_to_end(&mut buffer).is_err()
+        {
+            return Vec::new();
+        }
+        self.position += buffer.len() as u64;
+        self.partial.push_str(&String::from_utf8_lossy(&buffer));
+
+        // Keep a trailing line without newline until the rest of it is written
+        let complete = match self.partial.rfind('\n') {
+            Some(end) => self.partial.drain(..=end).collect::<String>(),
+            None => return Vec::new(),
+        };
+        complete
+            .lines()
+            .map(|line| line.trim_end_matches('\r').to_string())
+            .filter(|line| !line.is_empty())
+            .collect()
+    }
+}
+
+/// Starts emitting a `log-line` event for each line appended to the active log
+/// file. Restarting an already running tail only replaces it.
+#[tauri::command]
+pub fn start_log_tail(app: tauri::AppHandle) -> Result<(), String> {
+    use tauri::Emitter;
+
+    let mut tail = LogTail::new(current_log_file());
+    let task = tauri::async_runtime::spawn(async move {
+        loop {
+            for line in tail.poll() {
+                // Logging here would feed the tail its own output
+                let _ = app.emit(EVENT_LOG_LINE, line);
+            }
+            tokio::time::sleep(Duration::from_millis(500)).await;
+        }
+    });
+
+    let mut current = LOG_TAIL_TASK
+        .lock()
+        .map_err(|e| format!("Failed to lock log tail task: {}", e))?;
+    if let Some(previous) = current.replace(task) {
+        previous.abort();
+    }
+    Ok(())
+}
+
+/// Stops the tail started by `start_log_tail`, if any.
+#[tauri::command]
+pub fn stop_log_tail() -> Result<(), String> {
+    let mut current = LOG_TAIL_TASK
+        .lock()
+        .map_err(|e| format!("Failed to lock log tail task: {}", e))?;
+    if let Some(task) = current.take() {
+        task.abort();
+    }
+    Ok(())
+}
+
 fn read_last_n_bytes(file_path: &PathBuf, n: usize) -> Result<String, String> {
     use std::io::{Read, Seek, SeekFrom};
 
@@ -623,6 +942,7 @@ pub fn clear_registry_data() -> Result<(), String> {
     let registry_keys = vec![
         r"HKEY_CURRENT_USER\Software\com.pailer.ks",
         r"HKEY_CURRENT_USER\Software\Pailer",
+        r"HKEY_CURRENT_USER\Software\Classes\rscoop",
         r"HKEY_LOCAL_MACHINE\Software\Microsoft\Windows\CurrentVersion\Uninstall\Pailer",
         r"HKEY_LOCAL_MACHINE\Software\Wow6432Node\Microsoft\Windows\CurrentVersion\Uninstall\Pailer",
         r"HKEY_CURRENT_USER\Software\Microsoft\EdgeWebView\PreferenceMACs\Default\extensions.settings",
@@ -798,3 +1118,67 @@ fn get_log_dir() -> Option<PathBuf> {
 
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_LOG: &str = "[2025-01-01][10:00:00][pailer_lib][TRACE] scanning apps\n\
+[2025-01-01][10:00:01][pailer_lib][INFO] scan finished\n\
+continuation line\n\
+[2025-01-01][10:00:02][reqwest][WARN] slow response\n\
+[2025-01-01][10:00:03][pailer_lib][ERROR] scan failed";
+
+    #[test]
+    fn parses_level_after_timestamp_and_target() {
+        assert_eq!(
+            parse_log_line_level("[2025-01-01][10:00:00][pailer_lib::commands][DEBUG] hi"),
+            Some(log::Level::Debug)
+        );
+        assert_eq!(parse_log_line_level("continuation line"), None);
+    }
+
+    #[test]
+    fn filters_by_min_level_and_drops_unparsed_lines() {
+        let filtered = filter_log_lines(SAMPLE_LOG, Some(log::Level::Warn), None);
+        assert_eq!(
+            filtered,
+            "[2025-01-01][10:00:02][reqwest][WARN] slow response\n\
+[2025-01-01][10:00:03][pailer_lib][ERROR] scan failed"
+        );
+    }
+
+    #[test]
+    fn substring_filter_keeps_unparsed_lines_without_min_level() {
+        let filtered = filter_log_lines(SAMPLE_LOG, None, Some("line"));
+        assert_eq!(filtered, "continuation line");
+
+        let filtered = filter_log_lines(SAMPLE_LOG, Some(log::Level::Info), Some("scan"));
+        assert_eq!(
+            filtered,
+            "[2025-01-01][10:00:01][pailer_lib][INFO] scan finished\n\
+[2025-01-01][10:00:03][pailer_lib][ERROR] scan failed"
+        );
+    }
+
+    #[test]
+    fn log_tail_returns_appended_lines_and_restarts_after_truncation() {
+        use std::io::Write;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("pailer.log");
+        fs::write(&path, "old line\n").unwrap();
+
+        let mut tail = LogTail::new(path.clone());
+        assert!(tail.poll().is_empty());
+
+        let mut file = fs::OpenOptions::new().append(true).open(&path).unwrap();
+        write!(file, "first\nsecond\npart").unwrap();
+        assert_eq!(tail.poll(), vec!["first", "second"]);
+        write!(file, "ial\n").unwrap();
+        assert_eq!(tail.poll(), vec!["partial"]);
+
+        fs::write(&path, "rotated\n").unwrap();
+        assert_eq!(tail.poll(), vec!["rotated"]);
+    }
+}
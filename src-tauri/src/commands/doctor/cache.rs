@@ -136,7 +136,7 @@ pub async fn list_cache_contents<R: Runtime>(
     log::info!("Listing cache contents from filesystem with version-awareness");
 
     let scoop_path = state.scoop_path();
-    let cache_path = scoop_path.join("cache");
+    let (cache_path, _) = crate::commands::settings::resolve_scoop_cache_dir(&scoop_path);
 
     if !cache_path.is_dir() {
         log::warn!("Scoop cache directory not found at: {:?}", cache_path);
@@ -296,7 +296,7 @@ pub async fn clear_cache<R: Runtime>(
     );
 
     let scoop_path = state.scoop_path();
-    let cache_path = scoop_path.join("cache");
+    let (cache_path, _) = crate::commands::settings::resolve_scoop_cache_dir(&scoop_path);
 
     if !cache_path.is_dir() {
         return Ok((0, 0));
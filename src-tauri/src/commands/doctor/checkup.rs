@@ -5,15 +5,20 @@
 //! Original source: https://github.com/winpax/sfsu/blob/trunk/src/commands/checkup.rs
 
 use crate::commands::powershell::create_powershell_command;
-use crate::state::AppState;
+use crate::state::{AppState, CheckupCache};
 use serde::Serialize;
+use std::future::Future;
 use std::path::Path;
-use tauri::State;
+use std::pin::Pin;
+use tauri::{AppHandle, State, Window};
 
 // Import Windows-specific checks only on Windows.
 #[cfg(windows)]
 use super::windows_checks;
 
+/// Helpers Scoop uses to extract installers; also the allowlist for `install_missing_helper`.
+const HELPERS: &[&str] = &["7zip", "dark", "innounp", "lessmsi"];
+
 /// Represents the result of a single checkup item.
 #[derive(Serialize, Debug, Clone)]
 pub struct CheckupItem {
@@ -52,6 +57,101 @@ async fn check_git_installed() -> CheckupItem {
     }
 }
 
+/// Reads the PowerShell execution policy that applies to the current user.
+///
+/// The `CurrentUser` scope is queried first; when it is `Undefined`, the
+/// effective policy from the remaining scopes is returned instead.
+async fn get_current_user_execution_policy() -> Result<String, String> {
+    let output = create_powershell_command(
+        "$policy = Get-ExecutionPolicy -Scope CurrentUser; \
+         if ($policy -eq 'Undefined') { $policy = Get-ExecutionPolicy }; \
+         $policy.ToString()",
+    )
+    .output()
+    .await
+    .map_err(|e| format!("Failed to query execution policy: {}", e))?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Checks that the PowerShell execution policy allows Scoop's scripts to run.
+///
+/// `Restricted` blocks all scripts and `AllSigned` rejects Scoop's unsigned scripts.
+pub async fn check_execution_policy() -> CheckupItem {
+    let policy = match get_current_user_execution_policy().await {
+        Ok(policy) => policy,
+        Err(e) => {
+            log::error!("Failed to get execution policy: {}", e);
+            "Unknown".to_string()
+        }
+    };
+
+    let is_allowed =
+        !policy.eq_ignore_ascii_case("Restricted") && !policy.eq_ignore_ascii_case("AllSigned");
+
+    CheckupItem {
+        id: None,
+        status: is_allowed,
+        key: "executionPolicyAllowed".to_string(),
+        params: Some(serde_json::json!({"policy": policy})),
+        suggestion_key: if is_allowed {
+            None
+        } else {
+            Some("executionPolicySuggestion".to_string())
+        },
+        suggestion_params: None,
+    }
+}
+
+/// Sets the PowerShell execution policy to `RemoteSigned` for the current user.
+///
+/// This scope does not require administrator rights.
+#[tauri::command]
+pub async fn set_execution_policy_remotesigned(state: State<'_, AppState>) -> Result<(), String> {
+    log::info!("Setting PowerShell execution policy to RemoteSigned for CurrentUser");
+    *state.last_checkup.lock().await = None;
+
+    let output = create_powershell_command(
+        "Set-ExecutionPolicy -ExecutionPolicy RemoteSigned -Scope CurrentUser -Force",
+    )
+    .output()
+    .await
+    .map_err(|e| format!("Failed to set execution policy: {}", e))?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Failed to set execution policy: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+    }
+}
+
+/// Enables Win32 long path support and returns the refreshed long paths checkup item.
+///
+/// Requires administrator rights; without them a clear error is returned instead.
+#[tauri::command]
+pub async fn enable_long_paths(state: State<'_, AppState>) -> Result<CheckupItem, String> {
+    #[cfg(windows)]
+    {
+        log::info!("Enabling Windows long path support");
+        windows_checks::set_long_paths_enabled()?;
+        *state.last_checkup.lock().await = None;
+        Ok(windows_checks::check_long_paths_enabled())
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = state;
+        Err("Long paths can only be enabled on Windows".to_string())
+    }
+}
+
 /// Checks if the main Scoop bucket is installed.
 fn check_main_bucket_installed(scoop_path: &Path) -> CheckupItem {
     let main_bucket_installed = scoop_path.join("buckets").join("main").is_dir();
@@ -69,39 +169,188 @@ fn check_main_bucket_installed(scoop_path: &Path) -> CheckupItem {
     }
 }
 
+/// Checks whether a single helper package is installed.
+fn check_helper(scoop_path: &Path, helper: &str) -> CheckupItem {
+    let is_installed = scoop_path
+        .join("apps")
+        .join(helper)
+        .join("current")
+        .exists();
+    CheckupItem {
+        id: if is_installed {
+            None
+        } else {
+            Some(helper.to_string())
+        },
+        status: is_installed,
+        key: "helperInstalled".to_string(),
+        params: Some(serde_json::json!({"name": helper})),
+        suggestion_key: if is_installed {
+            None
+        } else {
+            Some("helperSuggestion".to_string())
+        },
+        suggestion_params: if is_installed {
+            None
+        } else {
+            Some(serde_json::json!({"name": helper}))
+        },
+    }
+}
+
 /// Checks for missing recommended helper packages.
 fn check_missing_helpers(scoop_path: &Path) -> Vec<CheckupItem> {
-    const HELPERS: &[&str] = &["7zip", "dark", "innounp", "lessmsi"];
-    let apps_path = scoop_path.join("apps");
-
     HELPERS
         .iter()
-        .map(|&helper| {
-            let is_installed = apps_path.join(helper).join("current").exists();
-            CheckupItem {
-                id: if is_installed {
-                    None
-                } else {
-                    Some(helper.to_string())
-                },
-                status: is_installed,
-                key: "helperInstalled".to_string(),
-                params: Some(serde_json::json!({"name": helper})),
-                suggestion_key: if is_installed {
-                    None
-                } else {
-                    Some("helperSuggestion".to_string())
-                },
-                suggestion_params: if is_installed {
-                    None
-                } else {
-                    Some(serde_json::json!({"name": helper}))
-                },
-            }
-        })
+        .map(|&helper| check_helper(scoop_path, helper))
         .collect()
 }
 
+/// Installs a missing helper package and returns its refreshed checkup item.
+///
+/// Only names from `HELPERS` are accepted so this can't be used to install arbitrary
+/// packages. Output is streamed like any other install.
+#[tauri::command]
+pub async fn install_missing_helper(
+    window: Window,
+    app: AppHandle,
+    state: State<'_, AppState>,
+    name: String,
+) -> Result<CheckupItem, String> {
+    let helper = HELPERS
+        .iter()
+        .copied()
+        .find(|helper| helper.eq_ignore_ascii_case(name.trim()))
+        .ok_or_else(|| format!("'{}' is not a known Scoop helper", name.trim()))?;
+
+    let scoop_path = state.scoop_path();
+    let item = check_helper(&scoop_path, helper);
+    if item.status {
+        log::info!("Helper '{}' is already installed", helper);
+        return Ok(item);
+    }
+
+    log::info!("Installing missing helper '{}'", helper);
+    *state.last_checkup.lock().await = None;
+    crate::commands::install::install_package(
+        window,
+        app,
+        state.clone(),
+        helper.to_string(),
+        String::new(),
+        None,
+    )
+    .await?;
+
+    Ok(check_helper(&scoop_path, helper))
+}
+
+/// Runs the check identified by `key`, the same key its `CheckupItem` reports.
+///
+/// Helpers share the `helperInstalled` key, so they are addressed as
+/// `helperInstalled:<name>`.
+type CheckFuture = Pin<Box<dyn Future<Output = CheckupItem> + Send>>;
+
+/// How a checkup item is computed.
+enum Check {
+    Sync(fn(&Path) -> CheckupItem),
+    Async(fn() -> CheckFuture),
+}
+
+/// Every single-item check keyed by its [`CheckupItem::key`], in checkup order.
+/// The helper checks follow them, one per entry in [`HELPERS`].
+const CHECKS: &[(&str, Check)] = &[
+    (
+        "gitInstalled",
+        Check::Async(|| Box::pin(check_git_installed())),
+    ),
+    (
+        "executionPolicyAllowed",
+        Check::Async(|| Box::pin(check_execution_policy())),
+    ),
+    (
+        "mainBucketInstalled",
+        Check::Sync(check_main_bucket_installed),
+    ),
+    #[cfg(windows)]
+    (
+        "windowsDeveloperModeEnabled",
+        Check::Sync(|_| windows_checks::check_windows_developer_mode()),
+    ),
+    #[cfg(windows)]
+    (
+        "longPathsEnabled",
+        Check::Sync(|_| windows_checks::check_long_paths_enabled()),
+    ),
+    #[cfg(windows)]
+    (
+        "scoopOnNtfs",
+        Check::Sync(windows_checks::check_scoop_on_ntfs),
+    ),
+    #[cfg(windows)]
+    (
+        "pathLength",
+        Check::Sync(|_| windows_checks::check_path_length()),
+    ),
+];
+
+async fn run_check(key: &str, scoop_path: &Path) -> Result<CheckupItem, String> {
+    if let Some(name) = key.strip_prefix("helperInstalled:") {
+        let helper = HELPERS
+            .iter()
+            .copied()
+            .find(|helper| helper.eq_ignore_ascii_case(name))
+            .ok_or_else(|| format!("'{}' is not a known Scoop helper", name))?;
+        return Ok(check_helper(scoop_path, helper));
+    }
+
+    match CHECKS.iter().find(|(check_key, _)| *check_key == key) {
+        Some((_, Check::Sync(run))) => Ok(run(scoop_path)),
+        Some((_, Check::Async(run))) => Ok(run().await),
+        None => Err(format!("Unknown checkup item '{}'", key)),
+    }
+}
+
+/// Whether `item` is the result of the check addressed by `key`.
+fn item_matches_key(item: &CheckupItem, key: &str) -> bool {
+    match key.strip_prefix("helperInstalled:") {
+        Some(name) => {
+            item.key == "helperInstalled"
+                && item
+                    .params
+                    .as_ref()
+                    .and_then(|params| params.get("name"))
+                    .and_then(|v| v.as_str())
+                    .is_some_and(|helper| helper.eq_ignore_ascii_case(name))
+        }
+        None => item.key == key,
+    }
+}
+
+/// Re-runs a single check, e.g. after an autofix, without running the whole suite.
+///
+/// The item is also updated in the cached results of the last full checkup.
+#[tauri::command]
+pub async fn run_single_check(
+    state: State<'_, AppState>,
+    key: String,
+) -> Result<CheckupItem, String> {
+    let key = key.trim();
+    let item = run_check(key, &state.scoop_path()).await?;
+
+    if let Some(cache) = state.last_checkup.lock().await.as_mut() {
+        if let Some(cached) = cache
+            .items
+            .iter_mut()
+            .find(|cached| item_matches_key(cached, key))
+        {
+            *cached = item.clone();
+        }
+    }
+
+    Ok(item)
+}
+
 /// Runs the Scoop checkup process, performing various system checks.
 #[tauri::command]
 pub async fn run_scoop_checkup(state: State<'_, AppState>) -> Result<Vec<CheckupItem>, String> {
@@ -109,26 +358,150 @@ pub async fn run_scoop_checkup(state: State<'_, AppState>) -> Result<Vec<Checkup
 
     let scoop_path = state.scoop_path();
 
-    // Run the async git check concurrently with the sync checks.
-    let git_check_future = check_git_installed();
-
-    // Run synchronous checks.
-    let mut items = vec![];
-    items.push(check_main_bucket_installed(&scoop_path));
+    // Start the async checks so they run concurrently with the sync ones.
+    let mut items = Vec::with_capacity(CHECKS.len() + HELPERS.len());
+    let mut running = Vec::new();
+    for (index, (_, check)) in CHECKS.iter().enumerate() {
+        match check {
+            Check::Sync(run) => items.push(run(&scoop_path)),
+            Check::Async(run) => running.push((index, tauri::async_runtime::spawn(run()))),
+        }
+    }
 
-    // Add Windows-specific checks.
-    #[cfg(windows)]
-    {
-        items.push(windows_checks::check_windows_developer_mode());
-        items.push(windows_checks::check_long_paths_enabled());
-        items.push(windows_checks::check_scoop_on_ntfs(&scoop_path));
+    // Inserting in ascending order puts every async result back at its position.
+    for (index, task) in running {
+        let item = task
+            .await
+            .map_err(|e| format!("Checkup task failed: {}", e))?;
+        items.insert(index, item);
     }
 
     items.extend(check_missing_helpers(&scoop_path));
 
-    // Await the async check and prepend its result to the list.
-    let git_check_result = git_check_future.await;
-    items.insert(0, git_check_result);
+    *state.last_checkup.lock().await = Some(CheckupCache {
+        items: items.clone(),
+        ran_at: AppState::now_ms(),
+    });
 
     Ok(items)
 }
+
+/// Returns the results of the last checkup run, if they are still valid.
+#[tauri::command]
+pub async fn get_last_checkup(state: State<'_, AppState>) -> Result<Option<CheckupCache>, String> {
+    Ok(state.last_checkup.lock().await.clone())
+}
+
+/// Formats one checkup item as a markdown list entry.
+fn format_checkup_line(item: &CheckupItem) -> String {
+    let mut line = format!("- {} `{}`", if item.status { "✓" } else { "✗" }, item.key);
+    if let Some(params) = &item.params {
+        line.push_str(&format!(" {}", params));
+    }
+    if let Some(suggestion) = &item.suggestion_key {
+        line.push_str(&format!(" — suggestion: `{}`", suggestion));
+        if let Some(params) = &item.suggestion_params {
+            line.push_str(&format!(" {}", params));
+        }
+    }
+    line
+}
+
+/// Renders checkup results as markdown for pasting into a bug report, with the
+/// failed checks listed before the passed ones.
+fn format_checkup_markdown(items: &[CheckupItem], debug_info: &serde_json::Value) -> String {
+    let version = |key: &str| {
+        let probe = &debug_info[key];
+        probe["version"]
+            .as_str()
+            .or_else(|| probe["error"].as_str())
+            .unwrap_or("unknown")
+            .to_string()
+    };
+
+    let mut lines = vec![
+        "## Scoop checkup".to_string(),
+        String::new(),
+        format!("- Pailer version: {}", env!("CARGO_PKG_VERSION")),
+        format!(
+            "- Scoop path: `{}`",
+            debug_info["scoop_path"].as_str().unwrap_or("unknown")
+        ),
+        format!("- Scoop version: {}", version("scoop_version")),
+        format!("- Git version: {}", version("git_version")),
+    ];
+
+    let (passed, failed): (Vec<&CheckupItem>, Vec<&CheckupItem>) =
+        items.iter().partition(|item| item.status);
+    for (title, group) in [("Failed", failed), ("Passed", passed)] {
+        if group.is_empty() {
+            continue;
+        }
+        lines.push(String::new());
+        lines.push(format!("### {} ({})", title, group.len()));
+        lines.push(String::new());
+        lines.extend(group.into_iter().map(format_checkup_line));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// Returns the checkup results as markdown, reusing the cached results of the
+/// last run when there are any.
+#[tauri::command]
+pub async fn export_checkup_markdown(state: State<'_, AppState>) -> Result<String, String> {
+    let cached = state
+        .last_checkup
+        .lock()
+        .await
+        .as_ref()
+        .map(|cache| cache.items.clone());
+    let items = match cached {
+        Some(items) => items,
+        None => run_scoop_checkup(state.clone()).await?,
+    };
+    let debug_info = crate::commands::debug::get_debug_info(state).await?;
+
+    Ok(format_checkup_markdown(&items, &debug_info))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(key: &str, status: bool) -> CheckupItem {
+        CheckupItem {
+            id: None,
+            status,
+            key: key.to_string(),
+            params: None,
+            suggestion_key: None,
+            suggestion_params: None,
+        }
+    }
+
+    #[test]
+    fn groups_failed_checks_before_passed_ones() {
+        let mut failed = item("doctor.checkup.mainBucketMissing", false);
+        failed.suggestion_key = Some("doctor.checkup.addMainBucket".to_string());
+        let items = vec![item("doctor.checkup.gitInstalled", true), failed];
+        let debug_info = serde_json::json!({
+            "scoop_path": "C:\\scoop",
+            "scoop_version": { "version": "v0.5.2", "error": null },
+            "git_version": { "version": null, "error": "git not found" },
+        });
+
+        let markdown = format_checkup_markdown(&items, &debug_info);
+
+        assert!(markdown.contains("- Scoop path: `C:\\scoop`"));
+        assert!(markdown.contains("- Scoop version: v0.5.2"));
+        assert!(markdown.contains("- Git version: git not found"));
+        let failed_at = markdown.find("### Failed (1)").unwrap();
+        let passed_at = markdown.find("### Passed (1)").unwrap();
+        assert!(failed_at < passed_at);
+        assert!(markdown.contains(
+            "- ✗ `doctor.checkup.mainBucketMissing` — suggestion: `doctor.checkup.addMainBucket`"
+        ));
+        assert!(markdown.contains("- ✓ `doctor.checkup.gitInstalled`"));
+    }
+}
@@ -3,7 +3,9 @@ use crate::commands::auto_cleanup;
 use crate::commands::installed::get_installed_packages_full;
 use crate::commands::powershell;
 use crate::state::AppState;
-use tauri::{AppHandle, Emitter, Runtime, State, Window};
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Emitter, Listener, Runtime, State, Window};
 
 /// Runs a specific Scoop cleanup command and streams its output.
 ///
@@ -26,8 +28,8 @@ async fn run_cleanup_command(
         operation_name.to_string(),
         powershell::EVENT_OUTPUT,
         powershell::EVENT_FINISHED,
-        powershell::EVENT_CANCEL,
         operation_id.to_string(),
+        powershell::StreamOptions::default(),
     )
     .await;
 
@@ -36,7 +38,7 @@ async fn run_cleanup_command(
         Err(e) => log::error!("Failed to execute cleanup command '{}': {}", command, e),
     }
 
-    result
+    result.map_err(String::from)
 }
 
 /// Cleans up old versions of all installed apps, with an option to include/exclude versioned installs.
@@ -356,3 +358,93 @@ pub async fn cleanup_outdated_cache<R: Runtime>(
 
     Ok(())
 }
+
+/// Old versions `scoop cleanup` reported removing for one package.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct NativeCleanupRemoval {
+    pub package: String,
+    pub versions: Vec<String>,
+}
+
+/// Parses a `Removing <app>: <version> <version>...` line printed by `scoop cleanup`.
+fn parse_cleanup_removal(line: &str) -> Option<NativeCleanupRemoval> {
+    let (package, versions) = line.trim().strip_prefix("Removing ")?.split_once(':')?;
+    let package = package.trim();
+    if package.is_empty() || package.contains(char::is_whitespace) {
+        return None;
+    }
+    Some(NativeCleanupRemoval {
+        package: package.to_string(),
+        versions: versions.split_whitespace().map(str::to_string).collect(),
+    })
+}
+
+/// Runs Scoop's own `scoop cleanup` for one package, or all of them, and
+/// returns the versions it removed.
+///
+/// Unlike the smart cleanup this leaves the bookkeeping to Scoop, including
+/// for versioned installs. Output is streamed like other Scoop operations.
+#[tauri::command]
+pub async fn run_native_scoop_cleanup(
+    window: Window,
+    app: AppHandle,
+    state: State<'_, AppState>,
+    package: Option<String>,
+) -> Result<Vec<NativeCleanupRemoval>, String> {
+    let target = match package.as_deref().map(str::trim) {
+        Some(name) if !name.is_empty() && name != "*" => auto_cleanup::quote_powershell_arg(name),
+        _ => "*".to_string(),
+    };
+    let command = format!("scoop cleanup {}", target);
+    let operation_id = "cleanup-native";
+
+    let removals = Arc::new(Mutex::new(Vec::new()));
+    let listener = {
+        let removals = removals.clone();
+        app.listen(powershell::EVENT_OUTPUT, move |event| {
+            let Ok(output) = serde_json::from_str::<serde_json::Value>(event.payload()) else {
+                return;
+            };
+            if output.get("operationId").and_then(|id| id.as_str()) != Some(operation_id) {
+                return;
+            }
+            if let Some(removal) = output
+                .get("line")
+                .and_then(|line| line.as_str())
+                .and_then(parse_cleanup_removal)
+            {
+                removals.lock().unwrap().push(removal);
+            }
+        })
+    };
+
+    let result = run_cleanup_command(window, &command, "Scoop Cleanup", operation_id).await;
+    app.unlisten(listener);
+    crate::commands::installed::invalidate_installed_cache(state).await;
+    result?;
+
+    let removals = std::mem::take(&mut *removals.lock().unwrap());
+    log::info!(
+        "scoop cleanup removed old versions of {} package(s)",
+        removals.len()
+    );
+    Ok(removals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_removed_versions_from_cleanup_output() {
+        assert_eq!(
+            parse_cleanup_removal("Removing git: 2.40.0 2.41.0"),
+            Some(NativeCleanupRemoval {
+                package: "git".to_string(),
+                versions: vec!["2.40.0".to_string(), "2.41.0".to_string()],
+            })
+        );
+        assert_eq!(parse_cleanup_removal("Everything is shiny now!"), None);
+        assert_eq!(parse_cleanup_removal("Removing old files: done"), None);
+    }
+}
@@ -36,6 +36,9 @@ pub struct AddShimArgs {
 static PATH_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"path\s*=\s*['"](.*?)['"]"#).unwrap());
 static ARGS_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"args\s*=\s*(.*)"#).unwrap());
 static SOURCE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\\/]apps[\\/]([^\\/]+)[\\/]").unwrap());
+// Splits a target inside an app into its Scoop root, package, version directory and relative path.
+static APP_TARGET_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(.*?)[\\/]apps[\\/]([^\\/]+)[\\/][^\\/]+[\\/](.+)$").unwrap());
 
 /// Parses the content of a `.shim` file to extract the target path and arguments.
 fn parse_shim_file_content(content: &str) -> (Option<String>, Option<String>) {
@@ -116,17 +119,464 @@ fn process_shim_dir(dir: &Path, is_global: bool) -> Result<Vec<Shim>, String> {
     Ok(shims)
 }
 
-/// Lists all Scoop shims from both local and global shim paths.
+/// Resolves the executable behind a package's primary shim.
+///
+/// The shim named after the package wins; otherwise the alphabetically first shim that
+/// points into the package's `apps` directory is used.
+pub(crate) fn find_primary_shim_target(scoop_path: &Path, package_name: &str) -> Option<PathBuf> {
+    let mut shims: Vec<Shim> = process_shim_dir(&scoop_path.join("shims"), false)
+        .ok()?
+        .into_iter()
+        .filter(|shim| shim.source.eq_ignore_ascii_case(package_name))
+        .collect();
+    shims.sort_by(|a, b| {
+        let a_primary = a.name.eq_ignore_ascii_case(package_name);
+        let b_primary = b.name.eq_ignore_ascii_case(package_name);
+        b_primary
+            .cmp(&a_primary)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+
+    shims
+        .into_iter()
+        .map(|shim| PathBuf::from(shim.path))
+        .find(|target| target.is_file())
+}
+
+/// Names of the shims in `shims_dir` that point into the package's `apps` directory.
+pub(crate) fn package_shim_names(shims_dir: &Path, package_name: &str) -> Vec<String> {
+    let mut names: Vec<String> = process_shim_dir(shims_dir, false)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|shim| shim.source.eq_ignore_ascii_case(package_name))
+        .map(|shim| shim.name)
+        .collect();
+    names.sort_by_key(|name| name.to_lowercase());
+    names.dedup();
+    names
+}
+
+/// Audit record for a single shim, as returned by `export_shims`.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ShimExport {
+    name: String,
+    /// `"user"` or `"global"`.
+    scope: String,
+    /// The file the record was read from (`.shim` or a script shim).
+    shim_file: String,
+    /// Raw `path =` value, only present for `.shim` files.
+    raw_path: Option<String>,
+    /// Raw `args =` value, only present for `.shim` files.
+    raw_args: Option<String>,
+    resolved_target: Option<String>,
+    target_exists: bool,
+    /// Whether the resolved target lives inside the Scoop root.
+    inside_scoop: bool,
+}
+
+fn shim_stem(file_name: &str) -> &str {
+    let base = file_name.strip_suffix(".shimmed").unwrap_or(file_name);
+    match base.rsplit_once('.') {
+        Some((stem, "exe" | "shim" | "cmd" | "bat" | "ps1")) => stem,
+        _ => base,
+    }
+}
+
+/// Builds audit records for every shim in `dir`, one per shim name.
+fn export_shim_dir(dir: &Path, scope: &str, scoop_roots: &[PathBuf]) -> Vec<ShimExport> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .collect();
+    files.sort();
+
+    let mut seen = HashSet::new();
+    let mut exports = Vec::new();
+    for file in &files {
+        let Some(name) = file.file_name().and_then(|n| n.to_str()).map(shim_stem) else {
+            continue;
+        };
+        if !seen.insert(name.to_lowercase()) {
+            continue;
+        }
+
+        let shim_file = dir.join(format!("{}.shim", name));
+        let (shim_file, raw_path, raw_args) = if shim_file.is_file() {
+            let content = fs::read_to_string(&shim_file).unwrap_or_default();
+            let (path, args) = parse_shim_file_content(&content);
+            (shim_file, path, args)
+        } else {
+            let Some(script) = [".ps1", ".cmd", ".bat", ""]
+                .iter()
+                .map(|ext| dir.join(format!("{}{}", name, ext)))
+                .find(|path| path.is_file())
+            else {
+                continue;
+            };
+            (script, None, None)
+        };
+
+        let resolved = match &raw_path {
+            Some(raw) => {
+                let target = PathBuf::from(raw.replace("\\\\", "\\"));
+                Some(if target.is_absolute() {
+                    target
+                } else {
+                    dir.join(target)
+                })
+            }
+            None => Some(shim_file.clone()),
+        };
+
+        exports.push(ShimExport {
+            name: name.to_string(),
+            scope: scope.to_string(),
+            shim_file: shim_file.to_string_lossy().to_string(),
+            target_exists: resolved.as_ref().is_some_and(|t| t.exists()),
+            inside_scoop: resolved
+                .as_ref()
+                .is_some_and(|t| scoop_roots.iter().any(|root| t.starts_with(root))),
+            resolved_target: resolved.map(|t| t.to_string_lossy().to_string()),
+            raw_path,
+            raw_args,
+        });
+    }
+
+    exports
+}
+
+/// Returns the Scoop roots whose shims are managed: the user root, then the
+/// configured global root, if any.
+fn scope_roots(state: &AppState) -> Vec<(PathBuf, &'static str)> {
+    let mut roots = vec![(state.scoop_path(), "user")];
+    roots.extend(state.global_scoop_path().map(|root| (root, "global")));
+    roots
+}
+
+/// Returns the shims directories of [`scope_roots`], user first.
+fn shim_dirs(state: &AppState) -> Vec<PathBuf> {
+    scope_roots(state)
+        .into_iter()
+        .map(|(root, _)| root.join("shims"))
+        .collect()
+}
+
+/// Exports every user and global shim with its raw values and resolved target.
+///
+/// Intended for auditing, e.g. spotting shims that point outside the Scoop tree.
 #[tauri::command]
-pub fn list_shims(state: State<'_, AppState>) -> Result<Vec<Shim>, String> {
-    log::info!("Listing shims from filesystem");
+pub fn export_shims(state: State<'_, AppState>) -> Result<Vec<ShimExport>, String> {
+    log::info!("Exporting shims for audit");
+    let roots = scope_roots(&state);
+    let scoop_roots: Vec<PathBuf> = roots.iter().map(|(root, _)| root.clone()).collect();
+
+    Ok(roots
+        .iter()
+        .flat_map(|(root, scope)| export_shim_dir(&root.join("shims"), scope, &scoop_roots))
+        .collect())
+}
+
+/// Resolves the target a binary name runs through Scoop's shims, like a
+/// Scoop-aware `where.exe`.
+///
+/// When both the user and the global shims directory provide the binary, the
+/// one whose directory comes first on PATH wins, defaulting to the user shim.
+#[tauri::command]
+pub fn which_binary(state: State<'_, AppState>, name: String) -> Result<Option<String>, String> {
+    let name = name.trim();
+    let name = shim_stem(name);
+    if name.is_empty() {
+        return Err("A binary name is required.".to_string());
+    }
+
     let scoop_path = state.scoop_path();
+    let global_root = state.global_scoop_path();
+    let scoop_roots: Vec<PathBuf> = std::iter::once(scoop_path.clone())
+        .chain(global_root.clone())
+        .collect();
+    let user_shims = scoop_path.join("shims");
 
-    let local_shims = process_shim_dir(&scoop_path.join("shims"), false)?;
-    let global_shims = process_shim_dir(&scoop_path.join("global").join("shims"), true)?;
+    let find = |dir: &Path, scope: &str| {
+        export_shim_dir(dir, scope, &scoop_roots)
+            .into_iter()
+            .find(|shim| shim.name.eq_ignore_ascii_case(name))
+    };
+    let user = find(&user_shims, "user");
+    let global = global_root.as_ref().and_then(|root| {
+        find(&root.join("shims"), "global").map(|shim| (root.join("shims"), shim))
+    });
+
+    let winner = match (user, global) {
+        (Some(user), Some((global_shims, global))) => {
+            let path_entries = std::env::var_os("PATH")
+                .map(|path| std::env::split_paths(&path).collect::<Vec<_>>())
+                .unwrap_or_default();
+            let precedence = crate::commands::installed::shim_dir_precedence(
+                path_entries,
+                &user_shims,
+                &global_shims,
+            );
+            if precedence.as_deref() == Some("global") {
+                global
+            } else {
+                user
+            }
+        }
+        (Some(shim), None) | (None, Some((_, shim))) => shim,
+        (None, None) => return Ok(None),
+    };
+
+    log::debug!(
+        "Binary '{}' resolves through the {} shim {}",
+        name,
+        winner.scope,
+        winner.shim_file
+    );
+    Ok(winner.resolved_target)
+}
 
-    let mut shim_set: HashSet<Shim> = local_shims.into_iter().collect();
-    shim_set.extend(global_shims);
+/// Executable extensions Windows resolves from PATH.
+const PATH_EXECUTABLE_EXTENSIONS: &[&str] = &["exe", "cmd", "bat", "com", "ps1"];
+
+/// One PATH entry and the Scoop binaries it shadows.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PathEntryAnalysis {
+    entry: String,
+    exists: bool,
+    is_scoop_shims: bool,
+    /// Shimmed binaries this directory provides too. Only set for entries that
+    /// come before the first Scoop shims directory, since those win.
+    shadowed_binaries: Vec<String>,
+}
+
+/// Lowercased names of the shims in `dir`.
+fn shim_names(dir: &Path) -> HashSet<String> {
+    fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| shim_stem(&entry.file_name().to_string_lossy()).to_lowercase())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Lists the binaries in `dir` that are also provided by a Scoop shim.
+fn shadowed_in_dir(dir: &Path, shims: &HashSet<String>) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut shadowed: Vec<String> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            let extension = path.extension()?.to_str()?.to_lowercase();
+            if !PATH_EXECUTABLE_EXTENSIONS.contains(&extension.as_str()) || !path.is_file() {
+                return None;
+            }
+            let stem = path.file_stem()?.to_str()?.to_lowercase();
+            shims.contains(&stem).then_some(stem)
+        })
+        .collect();
+    shadowed.sort();
+    shadowed.dedup();
+    shadowed
+}
+
+/// Walks PATH in order, marking the Scoop shims directories and the entries
+/// before them that shadow shimmed binaries. This explains why a system copy of
+/// a tool runs instead of the one installed with Scoop.
+#[tauri::command]
+pub fn analyze_path(state: State<'_, AppState>) -> Result<Vec<PathEntryAnalysis>, String> {
+    let shims_dirs = shim_dirs(&state);
+    let shims: HashSet<String> = shims_dirs.iter().flat_map(|dir| shim_names(dir)).collect();
+
+    let path_entries = std::env::var_os("PATH")
+        .map(|path| std::env::split_paths(&path).collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    let mut before_shims = true;
+    let analysis = path_entries
+        .into_iter()
+        .filter(|entry| !entry.as_os_str().is_empty())
+        .map(|entry| {
+            let is_scoop_shims = shims_dirs
+                .iter()
+                .any(|dir| crate::commands::installed::same_dir(&entry, dir));
+            if is_scoop_shims {
+                before_shims = false;
+            }
+            let shadowed_binaries = if before_shims {
+                shadowed_in_dir(&entry, &shims)
+            } else {
+                Vec::new()
+            };
+
+            PathEntryAnalysis {
+                entry: entry.to_string_lossy().to_string(),
+                exists: entry.is_dir(),
+                is_scoop_shims,
+                shadowed_binaries,
+            }
+        })
+        .collect();
+
+    Ok(analysis)
+}
+
+/// Outcome of repairing a single broken shim.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ShimRepairResult {
+    name: String,
+    scope: String,
+    /// `"repointed"`, `"removed"` or `"skipped"`.
+    action: String,
+    success: bool,
+    message: Option<String>,
+}
+
+/// Removes the files that make up the shim `name`, leaving other shims alone.
+fn remove_shim_files_exact(dir: &Path, name: &str) -> Result<(), String> {
+    for ext in ["shim", "exe", "exe.shimmed", "cmd", "ps1"] {
+        let path = dir.join(format!("{}.{}", name, ext));
+        if path.exists() {
+            fs::remove_file(&path)
+                .map_err(|e| format!("Failed to remove '{}': {}", path.display(), e))?;
+        }
+    }
+    let extensionless = dir.join(name);
+    if extensionless.is_file() {
+        fs::remove_file(&extensionless)
+            .map_err(|e| format!("Failed to remove '{}': {}", extensionless.display(), e))?;
+    }
+    Ok(())
+}
+
+/// Repairs one `.shim` file whose target is missing, if its package is identifiable.
+///
+/// The package is looked up under the root the target points into first, then
+/// under every scope root, since a shim may point at the other scope's apps.
+fn repair_shim(shim_file: &Path, scope_roots: &[&Path], scope: &str) -> Option<ShimRepairResult> {
+    let dir = shim_file.parent()?;
+    let name = shim_file.file_stem()?.to_str()?.to_string();
+    let content = fs::read_to_string(shim_file).ok()?;
+    let raw_path = parse_shim_file_content(&content).0?.replace("\\\\", "\\");
+    if Path::new(&raw_path).exists() {
+        return None;
+    }
+
+    let result = |action: &str, success: bool, message: Option<String>| ShimRepairResult {
+        name: name.clone(),
+        scope: scope.to_string(),
+        action: action.to_string(),
+        success,
+        message,
+    };
+
+    let Some(captures) = APP_TARGET_RE.captures(&raw_path) else {
+        return Some(result(
+            "skipped",
+            false,
+            Some(format!("Owning package of '{}' is unknown", raw_path)),
+        ));
+    };
+    let target_root = Path::new(&captures[1]);
+    let package = &captures[2];
+    let relative = &captures[3];
+    let package_dir = std::iter::once(target_root)
+        .chain(scope_roots.iter().copied())
+        .map(|root| root.join("apps").join(package))
+        .find(|package_dir| package_dir.is_dir());
+
+    let Some(package_dir) = package_dir else {
+        return Some(match remove_shim_files_exact(dir, &name) {
+            Ok(()) => result(
+                "removed",
+                true,
+                Some(format!("Package '{}' is no longer installed", package)),
+            ),
+            Err(e) => result("removed", false, Some(e)),
+        });
+    };
+
+    let new_target = package_dir.join("current").join(relative);
+    if !new_target.is_file() {
+        return Some(result(
+            "skipped",
+            false,
+            Some(format!("'{}' does not exist", new_target.display())),
+        ));
+    }
+
+    let new_target = new_target.to_string_lossy().to_string();
+    let new_content = PATH_RE.replace(&content, |_: &regex::Captures| {
+        format!("path = \"{}\"", new_target)
+    });
+    Some(match fs::write(shim_file, new_content.as_ref()) {
+        Ok(()) => result("repointed", true, Some(new_target)),
+        Err(e) => result(
+            "repointed",
+            false,
+            Some(format!("Failed to write shim file: {}", e)),
+        ),
+    })
+}
+
+/// Repairs shims whose target no longer exists.
+///
+/// Shims are re-pointed at `apps/<package>/current/...` when that file exists, or
+/// removed when the owning package is gone. Shims whose package can't be identified
+/// or that have no replacement are reported as skipped and left untouched.
+#[tauri::command]
+pub fn repair_broken_shims(state: State<'_, AppState>) -> Result<Vec<ShimRepairResult>, String> {
+    log::info!("Repairing broken shims");
+    let roots = scope_roots(&state);
+    let root_paths: Vec<&Path> = roots.iter().map(|(root, _)| root.as_path()).collect();
+
+    let mut results = Vec::new();
+    for (root, scope) in &roots {
+        let Ok(entries) = fs::read_dir(root.join("shims")) else {
+            continue;
+        };
+        let mut shim_files: Vec<PathBuf> = entries
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "shim"))
+            .collect();
+        shim_files.sort();
+
+        results.extend(
+            shim_files
+                .iter()
+                .filter_map(|shim_file| repair_shim(shim_file, &root_paths, scope)),
+        );
+    }
+
+    log::info!(
+        "Shim repair finished: {} broken, {} fixed",
+        results.len(),
+        results.iter().filter(|r| r.success).count()
+    );
+    Ok(results)
+}
+
+/// Lists all Scoop shims from both local and global shim paths.
+#[tauri::command]
+pub fn list_shims(state: State<'_, AppState>) -> Result<Vec<Shim>, String> {
+    log::info!("Listing shims from filesystem");
+    let mut shim_set: HashSet<Shim> = HashSet::new();
+    for (root, scope) in scope_roots(&state) {
+        shim_set.extend(process_shim_dir(&root.join("shims"), scope == "global")?);
+    }
 
     let mut shims: Vec<Shim> = shim_set.into_iter().collect();
     shims.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
@@ -138,7 +588,6 @@ pub fn list_shims(state: State<'_, AppState>) -> Result<Vec<Shim>, String> {
 #[tauri::command]
 pub fn alter_shim(state: State<'_, AppState>, shim_name: String) -> Result<(), String> {
     log::info!("Altering shim '{}' on filesystem", shim_name);
-    let scoop_path = state.scoop_path();
 
     let attempt_rename = |dir: &Path| -> Result<bool, String> {
         if !dir.is_dir() {
@@ -158,8 +607,13 @@ pub fn alter_shim(state: State<'_, AppState>, shim_name: String) -> Result<(), S
         }
     };
 
-    let was_altered = attempt_rename(&scoop_path.join("shims"))?
-        || attempt_rename(&scoop_path.join("global").join("shims"))?;
+    let mut was_altered = false;
+    for dir in shim_dirs(&state) {
+        if attempt_rename(&dir)? {
+            was_altered = true;
+            break;
+        }
+    }
 
     if was_altered {
         Ok(())
@@ -171,13 +625,9 @@ pub fn alter_shim(state: State<'_, AppState>, shim_name: String) -> Result<(), S
     }
 }
 
-/// Finds all files associated with a given shim name in both local and global paths.
-fn find_shim_files(scoop_path: &Path, shim_name: &str) -> Result<Vec<PathBuf>, String> {
+/// Finds all files associated with a given shim name in the given shims directories.
+fn find_shim_files(shim_dirs: &[PathBuf], shim_name: &str) -> Result<Vec<PathBuf>, String> {
     let mut files = Vec::new();
-    let shim_dirs = [
-        scoop_path.join("shims"),
-        scoop_path.join("global").join("shims"),
-    ];
 
     for dir in shim_dirs.iter().filter(|d| d.is_dir()) {
         let entries = fs::read_dir(dir).map_err(|e| e.to_string())?;
@@ -194,9 +644,8 @@ fn find_shim_files(scoop_path: &Path, shim_name: &str) -> Result<Vec<PathBuf>, S
 #[tauri::command]
 pub fn remove_shim(state: State<'_, AppState>, shim_name: String) -> Result<(), String> {
     log::info!("Removing shim '{}' from filesystem", shim_name);
-    let scoop_path = state.scoop_path();
 
-    let files_to_remove = find_shim_files(&scoop_path, &shim_name)?;
+    let files_to_remove = find_shim_files(&shim_dirs(&state), &shim_name)?;
 
     if files_to_remove.is_empty() {
         return Err(format!("Shim '{}' not found.", shim_name));
@@ -218,17 +667,37 @@ pub fn add_shim(state: State<'_, AppState>, args: AddShimArgs) -> Result<(), Str
     let scoop_path = state.scoop_path();
 
     let shims_dir = if args.global {
-        scoop_path.join("global").join("shims")
+        state
+            .global_scoop_path()
+            .ok_or_else(|| "No global Scoop root is configured".to_string())?
+            .join("shims")
     } else {
         scoop_path.join("shims")
     };
 
-    fs::create_dir_all(&shims_dir)
+    write_shim(
+        &scoop_path,
+        &shims_dir,
+        &args.name,
+        &args.path,
+        args.args.as_deref(),
+    )
+}
+
+/// Writes a `.shim` file and copies Scoop's shim executable next to it.
+fn write_shim(
+    scoop_path: &Path,
+    shims_dir: &Path,
+    name: &str,
+    target: &str,
+    args: Option<&str>,
+) -> Result<(), String> {
+    fs::create_dir_all(shims_dir)
         .map_err(|e| format!("Failed to create shims directory: {}", e))?;
 
-    let shim_file_path = shims_dir.join(format!("{}.shim", args.name));
-    let mut shim_content = format!("path = \"{}\"\n", args.path.replace('\\', "\\\\"));
-    if let Some(shim_args) = &args.args {
+    let shim_file_path = shims_dir.join(format!("{}.shim", name));
+    let mut shim_content = format!("path = \"{}\"\n", target.replace('\\', "\\\\"));
+    if let Some(shim_args) = args {
         if !shim_args.is_empty() {
             shim_content.push_str(&format!("args = {}", shim_args));
         }
@@ -242,13 +711,71 @@ pub fn add_shim(state: State<'_, AppState>, args: AddShimArgs) -> Result<(), Str
             "Scoop's shim.exe template not found. Is Scoop installed correctly?".to_string(),
         );
     }
-    let new_shim_exe_path = shims_dir.join(format!("{}.exe", args.name));
+    let new_shim_exe_path = shims_dir.join(format!("{}.exe", name));
     fs::copy(&shim_template_path, &new_shim_exe_path)
         .map_err(|e| format!("Failed to copy shim executable: {}", e))?;
 
     Ok(())
 }
 
+/// Returns true if any shim file (`.exe`, `.shim`, scripts, hidden) uses `name`.
+fn shim_name_in_use(shims_dir: &Path, name: &str) -> bool {
+    let Ok(entries) = fs::read_dir(shims_dir) else {
+        return false;
+    };
+
+    entries.filter_map(Result::ok).any(|entry| {
+        let file_name = entry.file_name().to_string_lossy().to_lowercase();
+        let stem = file_name.strip_suffix(".shimmed").unwrap_or(&file_name);
+        Path::new(stem)
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .is_some_and(|s| s.eq_ignore_ascii_case(name))
+    })
+}
+
+/// Creates a new shim named `alias` pointing at the same target as `existing`.
+///
+/// The alias is created in the same (local or global) shims directory and keeps
+/// the existing shim's args.
+#[tauri::command]
+pub fn add_shim_alias(
+    state: State<'_, AppState>,
+    existing: String,
+    alias: String,
+) -> Result<(), String> {
+    log::info!("Adding shim alias '{}' for '{}'", alias, existing);
+    let scoop_path = state.scoop_path();
+
+    let alias = alias.trim();
+    if alias.is_empty() || alias.contains(['\\', '/', ':', '.']) {
+        return Err(format!("'{}' is not a valid shim name", alias));
+    }
+
+    let shim_dirs = shim_dirs(&state);
+
+    if shim_dirs.iter().any(|dir| shim_name_in_use(dir, alias)) {
+        return Err(format!("A shim named '{}' already exists", alias));
+    }
+
+    let (shims_dir, shim_file) = shim_dirs
+        .iter()
+        .map(|dir| (dir, dir.join(format!("{}.shim", existing))))
+        .find(|(_, shim_file)| shim_file.is_file())
+        .ok_or_else(|| format!("Shim '{}' not found", existing))?;
+
+    let content =
+        fs::read_to_string(&shim_file).map_err(|e| format!("Failed to read shim file: {}", e))?;
+    let (target, args) = parse_shim_file_content(&content);
+    // Shims written by `add_shim` store escaped backslashes
+    let target = target
+        .map(|target| target.replace("\\\\", "\\"))
+        .filter(|target| Path::new(target).is_file())
+        .ok_or_else(|| format!("Shim '{}' does not point to an existing file", existing))?;
+
+    write_shim(&scoop_path, shims_dir, alias, &target, args.as_deref())
+}
+
 /// Updates the args field in a shim's .shim file.
 #[tauri::command]
 pub fn update_shim_args(
@@ -257,16 +784,10 @@ pub fn update_shim_args(
     args: Option<String>,
 ) -> Result<(), String> {
     log::info!("Updating args for shim '{}' to {:?}", shim_name, args);
-    let scoop_path = state.scoop_path();
 
     // Find the .shim file in local or global shims directory
-    let shim_dirs = [
-        scoop_path.join("shims"),
-        scoop_path.join("global").join("shims"),
-    ];
-
     let mut shim_file_path: Option<PathBuf> = None;
-    for dir in &shim_dirs {
+    for dir in shim_dirs(&state) {
         let path = dir.join(format!("{}.shim", shim_name));
         if path.exists() {
             shim_file_path = Some(path);
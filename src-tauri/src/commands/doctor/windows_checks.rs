@@ -63,6 +63,28 @@ pub fn check_long_paths_enabled() -> CheckupItem {
     }
 }
 
+/// Sets `LongPathsEnabled` to 1 in the Windows registry.
+///
+/// The key lives under HKLM, so this fails with a descriptive error unless the app runs elevated.
+#[cfg(windows)]
+pub fn set_long_paths_enabled() -> Result<(), String> {
+    let hklm = RegKey::predef(HKEY_LOCAL_MACHINE);
+    let key_path = r"SYSTEM\CurrentControlSet\Control\FileSystem";
+
+    let key = hklm
+        .open_subkey_with_flags(key_path, KEY_SET_VALUE)
+        .map_err(|e| {
+            if e.kind() == std::io::ErrorKind::PermissionDenied {
+                "Enabling long paths requires administrator rights. Please relaunch Pailer as administrator and try again.".to_string()
+            } else {
+                format!("Failed to open HKLM\\{}: {}", key_path, e)
+            }
+        })?;
+
+    key.set_value("LongPathsEnabled", &1u32)
+        .map_err(|e| format!("Failed to set LongPathsEnabled: {}", e))
+}
+
 /// Retrieves the filesystem type (e.g., "NTFS") for a given path.
 ///
 /// This function uses Windows-specific APIs to determine the filesystem.
@@ -155,3 +177,50 @@ pub fn check_scoop_on_ntfs(scoop_path: &Path) -> CheckupItem {
         suggestion_params: None,
     }
 }
+
+/// Combined user and system `PATH` length above which shells start to misbehave.
+#[cfg(windows)]
+const PATH_LENGTH_LIMIT: usize = 2000;
+
+/// Checks that the combined user and system `PATH` stays below a safe length.
+///
+/// Lengths are measured on the stored, unexpanded values. Entries that point to
+/// missing directories are counted so the suggestion can mention them; entries
+/// with `%VARIABLES%` are not resolved and never counted as dead.
+#[cfg(windows)]
+pub fn check_path_length() -> CheckupItem {
+    let read_path = |hive, key_path: &str| {
+        RegKey::predef(hive)
+            .open_subkey(key_path)
+            .and_then(|key| key.get_value::<String, _>("Path"))
+            .unwrap_or_default()
+    };
+    let user_path = read_path(HKEY_CURRENT_USER, "Environment");
+    let system_path = read_path(
+        HKEY_LOCAL_MACHINE,
+        r"SYSTEM\CurrentControlSet\Control\Session Manager\Environment",
+    );
+
+    // Windows joins the two with a separator; the limit counts UTF-16 units, not bytes
+    let length = user_path.encode_utf16().count() + system_path.encode_utf16().count() + 1;
+    let dead_entries = user_path
+        .split(';')
+        .chain(system_path.split(';'))
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty() && !entry.contains('%') && !Path::new(entry).exists())
+        .count();
+    let status = length <= PATH_LENGTH_LIMIT;
+
+    CheckupItem {
+        id: None,
+        status,
+        key: "pathLength".to_string(),
+        params: Some(serde_json::json!({"length": length, "limit": PATH_LENGTH_LIMIT})),
+        suggestion_key: if status {
+            None
+        } else {
+            Some("pathLengthSuggestion".to_string())
+        },
+        suggestion_params: (!status).then(|| serde_json::json!({"deadEntries": dead_entries})),
+    }
+}
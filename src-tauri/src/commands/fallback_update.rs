@@ -1,8 +1,6 @@
 use base64::{engine::general_purpose, Engine as _};
 use serde::{Deserialize, Serialize};
-use sha2::{Digest, Sha256};
-use std::fs::File;
-use std::io::{BufReader, Read};
+use sha2::Sha256;
 use std::process::Command;
 use std::str;
 use tauri::AppHandle;
@@ -209,27 +207,7 @@ async fn get_signature_for_version(_version: &str) -> Result<String, String> {
 
 /// Calculate SHA256 hash of a file
 fn calculate_file_hash(file_path: &std::path::Path) -> Result<String, String> {
-    let file =
-        File::open(file_path).map_err(|e| format!("Failed to open file for hashing: {}", e))?;
-
-    let mut reader = BufReader::new(file);
-    let mut hasher = Sha256::new();
-    let mut buffer = [0; 8192];
-
-    loop {
-        let bytes_read = reader
-            .read(&mut buffer)
-            .map_err(|e| format!("Failed to read file for hashing: {}", e))?;
-
-        if bytes_read == 0 {
-            break;
-        }
-
-        hasher.update(&buffer[..bytes_read]);
-    }
-
-    let hash = hasher.finalize();
-    Ok(hash.iter().map(|b| format!("{:02x}", b)).collect())
+    crate::utils::file_digest_hex::<Sha256>(file_path)
 }
 
 /// Verify downloaded file integrity against expected signature
@@ -266,6 +244,14 @@ pub async fn download_and_install_fallback_update(
 ) -> Result<(), String> {
     log::info!("Starting fallback update download and installation");
 
+    if crate::utils::is_scoop_managed_executable() {
+        log::warn!("Fallback update rejected: executable is managed by Scoop");
+        return Err(
+            "Pailer is managed by Scoop. Update it through Scoop instead of the installer."
+                .to_string(),
+        );
+    }
+
     // Create a temporary directory for the download
     let temp_dir = std::env::temp_dir();
     let installer_path = temp_dir.join(format!("pailer_update_{}.exe", update_info.version));
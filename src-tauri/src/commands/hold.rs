@@ -1,11 +1,104 @@
 //! Commands for holding and unholding Scoop packages.
+use crate::commands::installed::get_installed_packages_full;
+use crate::commands::settings::{get_config_value, set_config_value};
+use crate::models::PackageScope;
 use crate::state::AppState;
 use rayon::prelude::*;
-use serde_json::Value;
+use serde::Serialize;
+use serde_json::{Map, Value};
 use std::fs;
 use std::path::{Path, PathBuf};
 use tauri::{AppHandle, Runtime, State};
 
+/// Settings store key mapping each frozen bucket to the packages its hold placed.
+///
+/// Only packages held by the bucket hold are recorded, so unfreezing the bucket
+/// leaves holds that were placed individually alone.
+pub(crate) const HELD_BUCKETS_KEY: &str = "heldBuckets";
+
+/// A held package, and the bucket whose hold placed it, if any.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct HeldPackage {
+    pub name: String,
+    pub bucket_hold: Option<String>,
+}
+
+fn read_held_buckets<R: Runtime>(app: &AppHandle<R>) -> Map<String, Value> {
+    get_config_value(app.clone(), HELD_BUCKETS_KEY.to_string())
+        .ok()
+        .flatten()
+        .and_then(|value| value.as_object().cloned())
+        .unwrap_or_default()
+}
+
+fn write_held_buckets(app: &AppHandle, held_buckets: Map<String, Value>) -> Result<(), String> {
+    set_config_value(
+        app.clone(),
+        HELD_BUCKETS_KEY.to_string(),
+        Value::Object(held_buckets),
+    )
+}
+
+/// Finds the frozen bucket whose hold placed `package_name`.
+fn bucket_holding(held_buckets: &Map<String, Value>, package_name: &str) -> Option<String> {
+    held_buckets.iter().find_map(|(bucket, packages)| {
+        packages
+            .as_array()?
+            .iter()
+            .filter_map(Value::as_str)
+            .any(|name| name.eq_ignore_ascii_case(package_name))
+            .then(|| bucket.clone())
+    })
+}
+
+/// Records `package_name` under the frozen bucket `bucket`.
+fn record_bucket_hold(held_buckets: &mut Map<String, Value>, bucket: &str, package_name: &str) {
+    let packages = held_buckets
+        .entry(bucket.to_string())
+        .or_insert_with(|| Value::Array(Vec::new()));
+    if let Some(packages) = packages.as_array_mut() {
+        if !packages
+            .iter()
+            .filter_map(Value::as_str)
+            .any(|name| name.eq_ignore_ascii_case(package_name))
+        {
+            packages.push(Value::String(package_name.to_string()));
+        }
+    }
+}
+
+/// Removes `package_name` from every frozen bucket's record. Returns whether it was recorded.
+fn forget_bucket_hold(held_buckets: &mut Map<String, Value>, package_name: &str) -> bool {
+    let mut removed = false;
+    for packages in held_buckets.values_mut() {
+        if let Some(packages) = packages.as_array_mut() {
+            let before = packages.len();
+            packages.retain(|name| {
+                !name
+                    .as_str()
+                    .is_some_and(|name| name.eq_ignore_ascii_case(package_name))
+            });
+            removed |= packages.len() != before;
+        }
+    }
+    removed
+}
+
+fn find_held_bucket_key(held_buckets: &Map<String, Value>, bucket: &str) -> Option<String> {
+    held_buckets
+        .keys()
+        .find(|key| key.eq_ignore_ascii_case(bucket))
+        .cloned()
+}
+
+/// Reads the bucket recorded in a package's `install.json`.
+fn installed_bucket(scoop_dir: &Path, package_name: &str) -> Option<String> {
+    let install_json_path = get_current_install_json_path(scoop_dir, package_name).ok()?;
+    let content = fs::read_to_string(install_json_path).ok()?;
+    let value: Value = serde_json::from_str(&content).ok()?;
+    value.get("bucket")?.as_str().map(str::to_string)
+}
+
 /// Resolves the path to the `install.json` file for the currently installed version of a package.
 /// This file contains metadata about the installation, including its hold status.
 fn get_current_install_json_path(
@@ -78,9 +171,9 @@ fn modify_hold_status(scoop_dir: &Path, package_name: &str, hold: bool) -> Resul
 /// then only scanning directories if needed.
 #[tauri::command]
 pub async fn list_held_packages<R: Runtime>(
-    _app: AppHandle<R>,
+    app: AppHandle<R>,
     state: State<'_, AppState>,
-) -> Result<Vec<String>, String> {
+) -> Result<Vec<HeldPackage>, String> {
     log::debug!("Listing held packages by checking install.json files");
 
     let scoop_path = state.scoop_path();
@@ -125,7 +218,14 @@ pub async fn list_held_packages<R: Runtime>(
         .collect::<Vec<String>>();
 
     log::debug!("Found {} held packages", held_packages.len());
-    Ok(held_packages)
+    let held_buckets = read_held_buckets(&app);
+    Ok(held_packages
+        .into_iter()
+        .map(|name| HeldPackage {
+            bucket_hold: bucket_holding(&held_buckets, &name),
+            name,
+        })
+        .collect())
 }
 
 /// Places a hold on a package to prevent it from being updated.
@@ -141,13 +241,158 @@ pub async fn hold_package<R: Runtime>(
 }
 
 /// Removes the hold on a package, allowing it to be updated.
+/// A hold placed by a frozen bucket is released as well.
 #[tauri::command]
-pub async fn unhold_package<R: Runtime>(
-    _app: AppHandle<R>,
+pub async fn unhold_package(
+    app: AppHandle,
     state: State<'_, AppState>,
     package_name: String,
 ) -> Result<(), String> {
     log::info!("Removing hold from: {}", package_name);
     let scoop_path = state.scoop_path();
-    modify_hold_status(&scoop_path, &package_name, false)
+    modify_hold_status(&scoop_path, &package_name, false)?;
+
+    let mut held_buckets = read_held_buckets(&app);
+    if forget_bucket_hold(&mut held_buckets, &package_name) {
+        write_held_buckets(&app, held_buckets)?;
+    }
+    Ok(())
+}
+
+/// Freezes a bucket: holds every installed package sourced from it and
+/// remembers the bucket so packages installed from it later are held too.
+///
+/// Packages that are already held keep their individual hold. Returns the
+/// packages that were newly held.
+#[tauri::command]
+pub async fn hold_bucket(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    bucket: String,
+) -> Result<Vec<String>, String> {
+    let bucket = bucket.trim().to_string();
+    let scoop_path = state.scoop_path();
+    if bucket.is_empty() || !scoop_path.join("buckets").join(&bucket).is_dir() {
+        return Err(format!("Bucket '{}' is not installed.", bucket));
+    }
+
+    let packages = get_installed_packages_full(app.clone(), state.clone()).await?;
+    let mut held_buckets = read_held_buckets(&app);
+    let bucket_key = find_held_bucket_key(&held_buckets, &bucket).unwrap_or(bucket);
+    held_buckets
+        .entry(bucket_key.clone())
+        .or_insert_with(|| Value::Array(Vec::new()));
+
+    let mut newly_held = Vec::new();
+    for package in packages.iter().filter(|package| {
+        package.scope == PackageScope::User && package.source.eq_ignore_ascii_case(&bucket_key)
+    }) {
+        if is_package_held(&scoop_path, &package.name).unwrap_or(false) {
+            continue;
+        }
+        match modify_hold_status(&scoop_path, &package.name, true) {
+            Ok(()) => {
+                record_bucket_hold(&mut held_buckets, &bucket_key, &package.name);
+                newly_held.push(package.name.clone());
+            }
+            Err(e) => log::warn!("Failed to hold '{}': {}", package.name, e),
+        }
+    }
+
+    write_held_buckets(&app, held_buckets)?;
+    log::info!(
+        "Froze bucket '{}': {} packages newly held",
+        bucket_key,
+        newly_held.len()
+    );
+    Ok(newly_held)
+}
+
+/// Unfreezes a bucket, releasing the holds its freeze placed.
+/// Returns the packages that were unheld.
+#[tauri::command]
+pub async fn unhold_bucket(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    bucket: String,
+) -> Result<Vec<String>, String> {
+    let mut held_buckets = read_held_buckets(&app);
+    let Some(bucket_key) = find_held_bucket_key(&held_buckets, bucket.trim()) else {
+        return Err(format!("Bucket '{}' is not held.", bucket.trim()));
+    };
+    let packages = held_buckets.remove(&bucket_key).unwrap_or_default();
+
+    let scoop_path = state.scoop_path();
+    let mut unheld = Vec::new();
+    for name in packages
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+    {
+        match modify_hold_status(&scoop_path, name, false) {
+            Ok(()) => unheld.push(name.to_string()),
+            // The package may have been uninstalled since the freeze
+            Err(e) => log::debug!("Skipping unhold of '{}': {}", name, e),
+        }
+    }
+
+    write_held_buckets(&app, held_buckets)?;
+    log::info!(
+        "Unfroze bucket '{}': {} packages unheld",
+        bucket_key,
+        unheld.len()
+    );
+    Ok(unheld)
+}
+
+/// Holds a freshly installed package if the bucket it came from is frozen.
+pub(crate) fn apply_bucket_hold(app: &AppHandle, scoop_dir: &Path, package_name: &str) {
+    let mut held_buckets = read_held_buckets(app);
+    let Some(bucket) = installed_bucket(scoop_dir, package_name) else {
+        return;
+    };
+    let Some(bucket_key) = find_held_bucket_key(&held_buckets, &bucket) else {
+        return;
+    };
+    if is_package_held(scoop_dir, package_name).unwrap_or(false) {
+        return;
+    }
+
+    match modify_hold_status(scoop_dir, package_name, true) {
+        Ok(()) => {
+            log::info!(
+                "Held '{}' because bucket '{}' is frozen",
+                package_name,
+                bucket_key
+            );
+            record_bucket_hold(&mut held_buckets, &bucket_key, package_name);
+            if let Err(e) = write_held_buckets(app, held_buckets) {
+                log::warn!("Failed to record bucket hold for '{}': {}", package_name, e);
+            }
+        }
+        Err(e) => log::warn!("Failed to hold '{}': {}", package_name, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bucket_holding, forget_bucket_hold, record_bucket_hold};
+    use serde_json::{json, Map, Value};
+
+    #[test]
+    fn tracks_packages_held_by_a_bucket() {
+        let mut held_buckets: Map<String, Value> = Map::new();
+        record_bucket_hold(&mut held_buckets, "extras", "vscode");
+        record_bucket_hold(&mut held_buckets, "extras", "VSCode");
+        assert_eq!(held_buckets["extras"], json!(["vscode"]));
+        assert_eq!(
+            bucket_holding(&held_buckets, "VSCODE"),
+            Some("extras".to_string())
+        );
+
+        assert!(forget_bucket_hold(&mut held_buckets, "vscode"));
+        assert!(!forget_bucket_hold(&mut held_buckets, "vscode"));
+        assert_eq!(bucket_holding(&held_buckets, "vscode"), None);
+    }
 }
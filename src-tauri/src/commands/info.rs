@@ -16,6 +16,12 @@ pub struct ScoopInfo {
     pub details: Vec<(String, String)>,
     /// Optional installation notes provided by the package manifest.
     pub notes: Option<String>,
+    /// The project homepage declared in the manifest.
+    pub homepage: Option<String>,
+    /// The license identifier declared in the manifest.
+    pub license: Option<String>,
+    /// Names of the binaries and shims the package provides.
+    pub binaries: Vec<String>,
 }
 
 #[derive(Serialize, Debug, Clone)]
@@ -81,6 +87,29 @@ fn format_bin_value(value: &Value) -> String {
     }
 }
 
+/// Extracts the license identifier, which may be a plain string or an `{ identifier, url }` object.
+fn parse_license_field(json_value: &Value) -> Option<String> {
+    match json_value.get("license")? {
+        Value::String(s) => Some(s.clone()),
+        Value::Object(obj) => obj
+            .get("identifier")
+            .and_then(|v| v.as_str())
+            .map(String::from),
+        _ => None,
+    }
+    .filter(|license| !license.trim().is_empty())
+}
+
+/// Lists the shim names a manifest provides, including architecture-specific ones.
+fn parse_binary_names(json_value: &Value, package_name: &str) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+    collect_manifest_bin_candidates(json_value, package_name)
+        .into_iter()
+        .map(|(alias, _)| alias)
+        .filter(|alias| seen.insert(alias.to_ascii_lowercase()))
+        .collect()
+}
+
 /// Parses the JSON manifest content into a structured format for display.
 fn parse_manifest_details(json_value: &Value) -> (Vec<(String, String)>, Option<String>) {
     let mut details = vec![];
@@ -206,6 +235,12 @@ pub fn get_package_info(
     Ok(ScoopInfo {
         details: ordered_details,
         notes,
+        homepage: json_value
+            .get("homepage")
+            .and_then(|v| v.as_str())
+            .map(String::from),
+        license: parse_license_field(&json_value),
+        binaries: parse_binary_names(&json_value, &package_name),
     })
 }
 
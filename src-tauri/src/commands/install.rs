@@ -1,9 +1,36 @@
 //! Command for installing Scoop packages.
 use crate::commands::auto_cleanup::trigger_auto_cleanup;
+use crate::commands::manifest::read_manifest_json;
 use crate::commands::package_mutation::{finalize_single_package_mutation, PackageMutationKind};
+use crate::commands::powershell::{StreamOutput, EVENT_OUTPUT};
 use crate::commands::scoop::{self, generate_operation_id, ScoopOp};
+use crate::error::AppError;
 use crate::state::AppState;
-use tauri::{AppHandle, State, Window};
+use crate::utils;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::path::Path;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Runtime, State, Window};
+
+/// Extracted files usually take a couple of times the download size, and the
+/// download itself stays in Scoop's cache.
+const EXTRACTION_SPACE_FACTOR: u64 = 3;
+/// Per-request timeout when probing download sizes for the space check.
+const DOWNLOAD_SIZE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Optional settings for [`install_package`]; every field may be omitted.
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct InstallOptions {
+    /// Operation ID for tracking. Generated when missing.
+    pub operation_id: Option<String>,
+    /// Whether to bypass Scoop's stale self/bucket refresh check.
+    pub skip_pre_update_refresh: bool,
+    /// Whether to warn in the output when the drive looks too full.
+    pub check_disk_space: bool,
+}
 
 /// Installs a Scoop package.
 ///
@@ -13,8 +40,7 @@ use tauri::{AppHandle, State, Window};
 /// * `state` - The application state.
 /// * `package_name` - The name of package to install.
 /// * `bucket` - The name of bucket to install from. If empty or "None", default buckets are used.
-/// * `operation_id` - Optional operation ID for tracking.
-/// * `skip_pre_update_refresh` - Whether to bypass Scoop's stale self/bucket refresh check.
+/// * `options` - Operation ID and optional install behaviour.
 #[tauri::command]
 pub async fn install_package(
     window: Window,
@@ -22,9 +48,9 @@ pub async fn install_package(
     state: State<'_, AppState>,
     package_name: String,
     bucket: String,
-    operation_id: Option<String>,
-    skip_pre_update_refresh: Option<bool>,
-) -> Result<(), String> {
+    options: Option<InstallOptions>,
+) -> Result<(), AppError> {
+    let options = options.unwrap_or_default();
     let event_window = window.clone();
     let bucket_opt =
         (!bucket.is_empty() && !bucket.eq_ignore_ascii_case("none")).then(|| bucket.as_str());
@@ -35,16 +61,42 @@ pub async fn install_package(
         bucket_opt.unwrap_or("default")
     );
 
-    let operation_id = operation_id
+    let operation_id = options
+        .operation_id
         .unwrap_or_else(|| generate_operation_id(ScoopOp::Install, Some(&package_name)));
 
+    if options.check_disk_space {
+        let reference = match bucket_opt {
+            Some(bucket) => format!("{}/{}", bucket, package_name),
+            None => package_name.clone(),
+        };
+        match check_space_for_install(app.clone(), state.clone(), reference).await {
+            Ok(check) if !check.sufficient => {
+                let _ = window.emit(
+                    EVENT_OUTPUT,
+                    StreamOutput {
+                        operation_id: operation_id.clone(),
+                        line: format!(
+                            "Warning: installing '{}' may need about {} bytes, but only {} bytes are free",
+                            package_name, check.required_estimate, check.available
+                        ),
+                        source: "stderr".to_string(),
+                    },
+                );
+            }
+            Ok(_) => {}
+            Err(e) => log::debug!("Skipping disk space check for '{}': {}", package_name, e),
+        }
+    }
+
     let install_result = scoop::execute_scoop(
         window,
         ScoopOp::Install,
         Some(&package_name),
         bucket_opt,
         operation_id.clone(),
-        skip_pre_update_refresh.unwrap_or(false),
+        options.skip_pre_update_refresh,
+        false,
     )
     .await;
 
@@ -59,6 +111,8 @@ pub async fn install_package(
 
     install_result?;
 
+    crate::commands::hold::apply_bucket_hold(&app, &state.scoop_path(), &package_name);
+
     finalize_single_package_mutation(
         &event_window,
         state.clone(),
@@ -72,3 +126,393 @@ pub async fn install_package(
 
     Ok(())
 }
+
+/// Loads the manifest behind `source`, a local `.json` file or an https URL.
+async fn read_manifest_source(source: &str) -> Result<Value, AppError> {
+    let content = if source.starts_with("https://") {
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(15))
+            .build()
+            .map_err(|e| AppError::Network(e.to_string()))?;
+        client
+            .get(source)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| AppError::Network(format!("Failed to download {}: {}", source, e)))?
+            .text()
+            .await
+            .map_err(|e| AppError::Network(format!("Failed to download {}: {}", source, e)))?
+    } else if source.contains("://") {
+        return Err(AppError::Parse(
+            "Only local files and https URLs are supported".to_string(),
+        ));
+    } else {
+        std::fs::read_to_string(source)?
+    };
+
+    serde_json::from_str(content.trim_start_matches('\u{feff}'))
+        .map_err(|e| AppError::Parse(format!("Invalid manifest JSON: {}", e)))
+}
+
+/// Installs a package from a manifest outside of any bucket, given as a local
+/// `.json` path or an https URL such as a raw gist.
+///
+/// The manifest is downloaded and checked before Scoop runs, so malformed
+/// manifests fail fast with the validation errors instead of a Scoop trace.
+#[tauri::command]
+pub async fn install_from_manifest(
+    window: Window,
+    app: AppHandle,
+    state: State<'_, AppState>,
+    source: String,
+    operation_id: Option<String>,
+) -> Result<(), AppError> {
+    let source = source.trim();
+    let package_name = Path::new(source.split(['?', '#']).next().unwrap_or(source))
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.strip_suffix(".json"))
+        .filter(|name| !name.is_empty())
+        .ok_or_else(|| AppError::Parse(format!("'{}' is not a .json manifest", source)))?
+        .to_string();
+
+    let manifest = read_manifest_source(source).await?;
+    let errors: Vec<String> = crate::commands::manifest::check_manifest_structure(&manifest)
+        .into_iter()
+        .filter(|issue| issue.severity == crate::commands::manifest::IssueSeverity::Error)
+        .map(|issue| format!("{}: {}", issue.field, issue.message))
+        .collect();
+    if !errors.is_empty() {
+        return Err(AppError::Parse(format!(
+            "Manifest is not installable: {}",
+            errors.join("; ")
+        )));
+    }
+
+    log::info!("Installing '{}' from manifest {}", package_name, source);
+
+    let operation_id = operation_id
+        .unwrap_or_else(|| generate_operation_id(ScoopOp::Install, Some(&package_name)));
+    let quoted_source = crate::commands::auto_cleanup::quote_powershell_arg(source);
+    scoop::execute_scoop(
+        window.clone(),
+        ScoopOp::Install,
+        Some(&quoted_source),
+        None,
+        operation_id.clone(),
+        false,
+        false,
+    )
+    .await?;
+
+    finalize_single_package_mutation(
+        &window,
+        state.clone(),
+        PackageMutationKind::Install,
+        &package_name,
+        None,
+        operation_id,
+    )
+    .await;
+    trigger_auto_cleanup(app, state).await;
+
+    Ok(())
+}
+
+/// Formats the canonical `scoop install` command for a package.
+///
+/// The package is qualified with its bucket when one is known, and pinned with
+/// the `name@version` form when a version is given.
+fn format_install_command(name: &str, bucket: Option<&str>, version: Option<&str>) -> String {
+    let mut target = match bucket {
+        Some(bucket) => format!("{}/{}", bucket, name),
+        None => name.to_string(),
+    };
+
+    if let Some(version) = version {
+        target.push('@');
+        target.push_str(version);
+    }
+
+    format!("scoop install {}", target)
+}
+
+/// Returns the `scoop install` command for a package so it can be copied or shared.
+#[tauri::command]
+pub fn get_install_command(
+    name: String,
+    bucket: Option<String>,
+    version: Option<String>,
+) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("A package name is required.".to_string());
+    }
+
+    let bucket = bucket
+        .as_deref()
+        .map(str::trim)
+        .filter(|b| !b.is_empty() && !b.eq_ignore_ascii_case("none"));
+    let version = version.as_deref().map(str::trim).filter(|v| !v.is_empty());
+
+    Ok(format_install_command(name, bucket, version))
+}
+
+/// A package that would be installed as part of an install plan.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct InstallPlanEntry {
+    pub name: String,
+    pub bucket: String,
+    pub version: Option<String>,
+}
+
+/// The packages `scoop install` would pull in for a package.
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct InstallPlan {
+    /// Packages to install in order, dependencies first and the requested package last.
+    pub packages: Vec<InstallPlanEntry>,
+    /// Dependencies that are already installed and therefore skipped.
+    pub already_installed: Vec<String>,
+    /// Dependencies without a manifest in any local bucket.
+    pub missing: Vec<String>,
+    /// Dependency cycles, each listed from the first repeated package back to itself.
+    pub cycles: Vec<Vec<String>>,
+}
+
+/// Splits a `bucket/name` reference into its optional bucket and name.
+fn split_package_reference(reference: &str) -> (Option<&str>, &str) {
+    match reference.split_once('/') {
+        Some((bucket, name)) => (Some(bucket), name),
+        None => (None, reference),
+    }
+}
+
+/// Reads the `depends` field, which is either a string or an array of strings.
+pub(crate) fn manifest_dependencies(manifest: &Value) -> Vec<String> {
+    match manifest.get("depends") {
+        Some(Value::String(dep)) => vec![dep.clone()],
+        Some(Value::Array(deps)) => deps
+            .iter()
+            .filter_map(|dep| dep.as_str().map(str::to_string))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Depth-first walk over `depends` that records packages in install order.
+struct InstallPlanResolver<'a> {
+    scoop_dir: &'a Path,
+    plan: InstallPlan,
+    stack: Vec<String>,
+    resolved: HashSet<String>,
+}
+
+impl InstallPlanResolver<'_> {
+    fn visit(&mut self, name: &str, bucket: Option<&str>) {
+        let key = name.to_lowercase();
+        if self.resolved.contains(&key) {
+            return;
+        }
+        if let Some(pos) = self
+            .stack
+            .iter()
+            .position(|entry| entry.eq_ignore_ascii_case(name))
+        {
+            let mut cycle = self.stack[pos..].to_vec();
+            cycle.push(name.to_string());
+            log::warn!("Dependency cycle detected: {}", cycle.join(" -> "));
+            self.plan.cycles.push(cycle);
+            return;
+        }
+        if self.scoop_dir.join("apps").join(name).is_dir() {
+            self.resolved.insert(key);
+            self.plan.already_installed.push(name.to_string());
+            return;
+        }
+
+        let manifest =
+            utils::locate_package_manifest(self.scoop_dir, name, bucket.map(str::to_string))
+                .and_then(|(path, bucket)| Ok((read_manifest_json(&path)?, bucket)));
+        let (manifest, bucket) = match manifest {
+            Ok(found) => found,
+            Err(e) => {
+                log::debug!("No manifest for dependency '{}': {}", name, e);
+                self.resolved.insert(key);
+                self.plan.missing.push(name.to_string());
+                return;
+            }
+        };
+
+        self.stack.push(name.to_string());
+        for dependency in manifest_dependencies(&manifest) {
+            let (dep_bucket, dep_name) = split_package_reference(&dependency);
+            self.visit(dep_name, dep_bucket);
+        }
+        self.stack.pop();
+
+        self.resolved.insert(key);
+        self.plan.packages.push(InstallPlanEntry {
+            name: name.to_string(),
+            bucket,
+            version: manifest
+                .get("version")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+        });
+    }
+}
+
+fn resolve_install_plan(scoop_dir: &Path, name: &str, bucket: Option<&str>) -> InstallPlan {
+    let mut resolver = InstallPlanResolver {
+        scoop_dir,
+        plan: InstallPlan::default(),
+        stack: Vec::new(),
+        resolved: HashSet::new(),
+    };
+    resolver.visit(name, bucket);
+    resolver.plan
+}
+
+/// Estimated space an install needs against the free space on the Scoop volume.
+#[derive(Serialize, Debug, Clone)]
+pub struct DiskSpaceCheck {
+    /// Estimated bytes, or 0 when the download size is unknown.
+    pub required_estimate: u64,
+    pub available: u64,
+    pub sufficient: bool,
+}
+
+fn estimate_install_space(download_size: u64) -> u64 {
+    download_size.saturating_mul(EXTRACTION_SPACE_FACTOR)
+}
+
+/// Estimates the space needed to install a package from the `Content-Length`
+/// of its downloads and compares it with the free space on the Scoop volume.
+///
+/// The estimate is rough. When the size can't be determined, e.g. in offline
+/// mode or when the server omits it, the check passes with an estimate of 0.
+#[tauri::command]
+pub async fn check_space_for_install<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+    name: String,
+) -> Result<DiskSpaceCheck, AppError> {
+    let scoop_dir = state.scoop_path();
+    let available =
+        crate::commands::scoop_migration::available_space(&scoop_dir).ok_or_else(|| {
+            AppError::Io("Could not determine free space on the Scoop volume".to_string())
+        })?;
+
+    let (bucket, name) = split_package_reference(name.trim());
+    let (manifest_path, _) =
+        utils::locate_package_manifest(&scoop_dir, name, bucket.map(str::to_string))
+            .map_err(AppError::NotFound)?;
+    let manifest = read_manifest_json(&manifest_path).map_err(AppError::Parse)?;
+    let urls = crate::commands::package_hash::manifest_arch_field(&manifest, "url");
+
+    let download_size = if crate::commands::network::is_offline_mode(&app) {
+        None
+    } else {
+        let client = reqwest::Client::builder()
+            .timeout(DOWNLOAD_SIZE_TIMEOUT)
+            .user_agent("Pailer")
+            .build()
+            .map_err(|e| AppError::Network(e.to_string()))?;
+        crate::commands::updates::total_download_size(&client, &urls).await
+    };
+
+    let required_estimate = download_size.map(estimate_install_space).unwrap_or(0);
+    log::debug!(
+        "Space check for '{}': ~{} bytes needed, {} bytes available",
+        name,
+        required_estimate,
+        available
+    );
+
+    Ok(DiskSpaceCheck {
+        required_estimate,
+        available,
+        sufficient: available >= required_estimate,
+    })
+}
+
+/// Previews what installing a package would pull in by following the `depends`
+/// field of the manifests in local buckets. Installed packages are skipped and
+/// cycles are reported instead of followed.
+#[tauri::command]
+pub fn get_install_plan(
+    state: State<'_, AppState>,
+    name: String,
+    bucket: Option<String>,
+) -> Result<InstallPlan, String> {
+    let (reference_bucket, name) = split_package_reference(name.trim());
+    if name.is_empty() {
+        return Err("A package name is required.".to_string());
+    }
+    let bucket = bucket
+        .as_deref()
+        .map(str::trim)
+        .filter(|b| !b.is_empty() && !b.eq_ignore_ascii_case("none"))
+        .or(reference_bucket);
+
+    let plan = resolve_install_plan(&state.scoop_path(), name, bucket);
+    if plan.packages.is_empty() && plan.already_installed.is_empty() {
+        return Err(format!("Package '{}' not found.", name));
+    }
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_install_command, resolve_install_plan};
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn write_manifest(scoop_dir: &std::path::Path, name: &str, depends: &[&str]) {
+        let bucket = scoop_dir.join("buckets").join("main").join("bucket");
+        fs::create_dir_all(&bucket).unwrap();
+        let manifest = serde_json::json!({ "version": "1.0.0", "depends": depends });
+        fs::write(bucket.join(format!("{}.json", name)), manifest.to_string()).unwrap();
+    }
+
+    #[test]
+    fn orders_dependencies_before_package_and_reports_cycles() {
+        let temp_dir = tempdir().unwrap();
+        let scoop_dir = temp_dir.path();
+        write_manifest(scoop_dir, "app", &["lib", "main/tool"]);
+        write_manifest(scoop_dir, "lib", &["tool", "app"]);
+        write_manifest(scoop_dir, "tool", &["installed"]);
+        fs::create_dir_all(scoop_dir.join("apps").join("installed")).unwrap();
+
+        let plan = resolve_install_plan(scoop_dir, "app", None);
+
+        let order: Vec<&str> = plan.packages.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(order, vec!["tool", "lib", "app"]);
+        assert_eq!(plan.already_installed, vec!["installed".to_string()]);
+        assert_eq!(
+            plan.cycles,
+            vec![vec![
+                "app".to_string(),
+                "lib".to_string(),
+                "app".to_string()
+            ]]
+        );
+    }
+
+    #[test]
+    fn qualifies_install_command_with_bucket_and_version() {
+        assert_eq!(
+            format_install_command("git", None, None),
+            "scoop install git"
+        );
+        assert_eq!(
+            format_install_command("git", Some("main"), None),
+            "scoop install main/git"
+        );
+        assert_eq!(
+            format_install_command("nodejs", Some("main"), Some("20.11.0")),
+            "scoop install main/nodejs@20.11.0"
+        );
+    }
+}
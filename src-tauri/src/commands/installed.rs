@@ -1,12 +1,15 @@
 //! Command for fetching all installed Scoop packages from the filesystem.
-use crate::models::{parse_notes_field, InstallManifest, PackageManifest, ScoopPackage};
+use crate::models::{
+    parse_notes_field, InstallManifest, PackageHealth, PackageManifest, PackageScope, ScoopPackage,
+};
 use crate::state::{AppState, InstalledPackagesCache};
 use chrono::{DateTime, Utc};
 use rayon::prelude::*;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::UNIX_EPOCH;
-use tauri::{AppHandle, Runtime, State};
+use tauri::{AppHandle, Emitter, Runtime, State, Window};
+use tauri_plugin_opener::OpenerExt;
 
 /// Helper to get modification time of a path (file or directory) in milliseconds.
 fn get_path_modification_time(path: &Path) -> u128 {
@@ -46,7 +49,18 @@ fn get_install_modification_time(install_dir: &Path) -> u128 {
 
 /// Searches for a package manifest in all bucket directories to determine the bucket.
 fn find_package_bucket(scoop_path: &Path, package_name: &str) -> Option<String> {
+    find_package_buckets(scoop_path, package_name)
+        .into_iter()
+        .next()
+}
+
+/// Returns every bucket holding a manifest for the package, sorted by name.
+///
+/// Lookups go through the filesystem, so on NTFS a manifest differing only in
+/// case matches as well.
+fn find_package_buckets(scoop_path: &Path, package_name: &str) -> Vec<String> {
     let buckets_path = scoop_path.join("buckets");
+    let mut found = Vec::new();
 
     log::trace!(
         "Searching for package bucket. Scoop path: {}, Package name: {}",
@@ -71,20 +85,22 @@ fn find_package_bucket(scoop_path: &Path, package_name: &str) -> Option<String>
                 );
                 if manifest_path.exists() {
                     log::trace!("Found package {} in bucket {}", package_name, bucket_name);
-                    return Some(bucket_name);
+                    found.push(bucket_name);
                 }
             }
         }
     }
 
-    // Fallback: check if it's in the main bucket (which might not be in buckets dir)
-    log::trace!("Package {} not found in any bucket", package_name);
-    None
+    if found.is_empty() {
+        log::trace!("Package {} not found in any bucket", package_name);
+    }
+    found.sort_by_key(|bucket| bucket.to_lowercase());
+    found
 }
 
 /// Returns the most recently updated version directory for a package when the
 /// `current` link is missing.
-fn find_latest_version_dir(package_path: &Path) -> Option<PathBuf> {
+pub(crate) fn find_latest_version_dir(package_path: &Path) -> Option<PathBuf> {
     let mut candidates: Vec<(u128, PathBuf)> = Vec::new();
 
     log::trace!(
@@ -159,6 +175,37 @@ fn locate_install_dir(package_path: &Path) -> Result<PathBuf, String> {
     }
 }
 
+/// Lists the package directories directly under an `apps` directory.
+pub(crate) fn read_app_dirs(apps_path: &Path) -> Result<Vec<PathBuf>, String> {
+    Ok(fs::read_dir(apps_path)
+        .map_err(|e| format!("Failed to read apps directory: {}", e))?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect())
+}
+
+/// Counts package directories that can't be loaded as an installation, e.g. because
+/// both the `current` link and every version directory are missing.
+pub(crate) fn count_broken_installs(scoop_path: &Path) -> usize {
+    read_app_dirs(&scoop_path.join("apps"))
+        .unwrap_or_default()
+        .iter()
+        .filter(|path| {
+            !path
+                .file_name()
+                .is_some_and(|name| name.eq_ignore_ascii_case("scoop"))
+        })
+        .filter(|path| match locate_install_dir(path) {
+            Ok(install_dir) => {
+                !install_dir.join("manifest.json").is_file()
+                    && !install_dir.join("install.json").is_file()
+            }
+            Err(_) => true,
+        })
+        .count()
+}
+
 fn compute_apps_fingerprint(app_dirs: &[PathBuf]) -> String {
     log::debug!(
         "Computing apps fingerprint for {} app directories",
@@ -238,6 +285,7 @@ fn load_manifests_with_fallback(
                 .and_then(|v| v.as_str())
                 .map(|s| s.to_string()),
             notes: parse_notes_field(&json),
+            architecture: parse_single_architecture(&json),
         }
     } else {
         // Return error if manifest doesn't exist
@@ -267,6 +315,16 @@ fn load_manifests_with_fallback(
     Ok((manifest, install_manifest))
 }
 
+/// Returns the architecture key of a manifest that declares exactly one architecture.
+fn parse_single_architecture(json: &serde_json::Value) -> Option<String> {
+    let architectures = json.get("architecture")?.as_object()?;
+    if architectures.len() == 1 {
+        architectures.keys().next().cloned()
+    } else {
+        None
+    }
+}
+
 /// Attempts to extract version information from directory structure or files.
 fn extract_version_from_directory(install_root: &Path) -> Option<String> {
     // Try to get version from parent directory name
@@ -313,18 +371,28 @@ fn extract_version_from_directory(install_root: &Path) -> Option<String> {
 }
 
 /// Validates if a string looks like a valid version string.
+///
+/// Accepts the characters Scoop permits in versions, including semver build
+/// metadata (`+`) and `~`, as well as Scoop's special `nightly` version.
 fn is_valid_version_string(s: &str) -> bool {
-    if s.is_empty() {
+    if s.is_empty() || s.eq_ignore_ascii_case("current") {
         return false;
     }
 
+    if s.eq_ignore_ascii_case("nightly") {
+        return true;
+    }
+
     // Simple validation: contains digits and no invalid characters
     let has_digit = s.chars().any(|c| c.is_ascii_digit());
     let has_invalid_chars = s
         .chars()
-        .any(|c| !c.is_ascii_alphanumeric() && c != '.' && c != '-' && c != '_');
+        .any(|c| !c.is_ascii_alphanumeric() && !matches!(c, '.' | '-' | '_' | '+' | '~'));
 
-    has_digit && !has_invalid_chars && !s.starts_with(['.', '-']) && !s.ends_with(['.', '-'])
+    has_digit
+        && !has_invalid_chars
+        && !s.starts_with(['.', '-', '+', '~'])
+        && !s.ends_with(['.', '-', '+', '~'])
 }
 
 /// Extracts package name from package directory path.
@@ -431,16 +499,26 @@ fn determine_bucket(
     package_name: &str,
 ) -> String {
     if let Some(ref bucket_name) = install_manifest.bucket {
-        // Normal bucket installation
+        // Normal bucket installation; install.json wins even if other buckets
+        // carry a manifest of the same name
         bucket_name.clone()
     } else {
         // Custom or unknown installation - try to find in buckets first
-        match find_package_bucket(scoop_path, package_name) {
-            Some(found_bucket) => {
+        match find_package_buckets(scoop_path, package_name).as_slice() {
+            [found_bucket] => {
                 log::trace!("Found package {} in bucket: {}", package_name, found_bucket);
-                found_bucket
+                found_bucket.clone()
             }
-            None => {
+            [guess, others @ ..] => {
+                log::warn!(
+                    "Package {} has no recorded bucket and matches several buckets; guessing {} over {}",
+                    package_name,
+                    guess,
+                    others.join(", ")
+                );
+                guess.clone()
+            }
+            [] => {
                 // Truly custom installation
                 log::trace!(
                     "Package {} appears to be custom installed, marking as Custom",
@@ -488,6 +566,7 @@ fn get_current_version_update_date_impl(package_dir: &Path) -> Result<String, St
 fn build_scoop_package(
     package_name: String,
     manifest: PackageManifest,
+    install_manifest: InstallManifest,
     bucket: String,
     updated_time: String,
     has_version_dirs: bool,
@@ -499,6 +578,9 @@ fn build_scoop_package(
         _ => crate::models::InstallationType::Standard,
     };
 
+    // Prefer the architecture Scoop recorded at install time over the manifest default.
+    let architecture = install_manifest.architecture.or(manifest.architecture);
+
     ScoopPackage {
         name: package_name,
         version: manifest.version,
@@ -514,6 +596,10 @@ fn build_scoop_package(
         installation_type,
         has_multiple_versions: has_version_dirs,
         local_latest_version: None,
+        architecture,
+        scope: PackageScope::User,
+        health: PackageHealth::Ok,
+        user_note: None,
     }
 }
 
@@ -584,6 +670,7 @@ fn load_package_details(package_path: &Path, scoop_path: &Path) -> Result<ScoopP
     let mut pkg = build_scoop_package(
         package_name,
         manifest,
+        install_manifest,
         bucket,
         updated_time,
         has_version_dirs,
@@ -632,6 +719,138 @@ async fn refresh_scoop_path_if_needed<R: Runtime>(
     }
 }
 
+/// Settings key that makes the scan report a missing `current` link instead of
+/// falling back to the latest version directory.
+const STRICT_CURRENT_KEY: &str = "scan.strictCurrent";
+
+fn is_strict_current_enabled<R: Runtime>(app: &AppHandle<R>) -> bool {
+    crate::commands::settings::get_config_value(app.clone(), STRICT_CURRENT_KEY.to_string())
+        .ok()
+        .flatten()
+        .and_then(|value| value.as_bool())
+        .unwrap_or(false)
+}
+
+/// Builds the entry for a package whose `current` link is missing, without
+/// reading anything from a version directory.
+fn missing_current_package(package_path: &Path) -> Option<ScoopPackage> {
+    let name = package_path.file_name()?.to_str()?;
+    if name.eq_ignore_ascii_case("scoop") || package_path.join("current").is_dir() {
+        return None;
+    }
+    Some(ScoopPackage {
+        name: name.to_string(),
+        is_installed: true,
+        is_installed_from_current_bucket: true,
+        health: PackageHealth::MissingCurrent,
+        ..Default::default()
+    })
+}
+
+/// Settings key limiting the number of threads used by the installed packages scan.
+const SCAN_THREADS_KEY: &str = "performance.scanThreads";
+
+/// Builds a dedicated thread pool when `performance.scanThreads` is set.
+///
+/// The value is clamped to `1..=available_parallelism`. Returns `None` when the
+/// setting is unset so the scan keeps using rayon's global pool.
+fn build_scan_thread_pool<R: Runtime>(
+    app: &AppHandle<R>,
+    log_prefix: &str,
+) -> Option<rayon::ThreadPool> {
+    let requested =
+        crate::commands::settings::get_config_value(app.clone(), SCAN_THREADS_KEY.to_string())
+            .ok()
+            .flatten()
+            .and_then(|value| value.as_u64())?;
+
+    let max_threads = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1);
+    let threads = (requested as usize).clamp(1, max_threads);
+
+    match rayon::ThreadPoolBuilder::new().num_threads(threads).build() {
+        Ok(pool) => {
+            log::debug!("{} [SCAN] Using {} scan thread(s)", log_prefix, threads);
+            Some(pool)
+        }
+        Err(e) => {
+            log::warn!(
+                "{} [SCAN] Failed to build scan thread pool, using global pool: {}",
+                log_prefix,
+                e
+            );
+            None
+        }
+    }
+}
+
+/// Settings key enabling global installs in the installed packages list.
+const INCLUDE_GLOBAL_KEY: &str = "scoop.includeGlobal";
+
+/// Returns the global `apps` directory when `scoop.includeGlobal` is enabled
+/// and the configured global root exists.
+fn resolve_global_apps_path<R: Runtime>(app: &AppHandle<R>, state: &AppState) -> Option<PathBuf> {
+    let include_global =
+        crate::commands::settings::get_config_value(app.clone(), INCLUDE_GLOBAL_KEY.to_string())
+            .ok()
+            .flatten()
+            .and_then(|value| value.as_bool())
+            .unwrap_or(false);
+    if !include_global {
+        return None;
+    }
+
+    let apps_path = state.global_scoop_path()?.join("apps");
+    apps_path.is_dir().then_some(apps_path)
+}
+
+/// Returns whether `package_name` is installed only in the global Scoop root,
+/// in which case Scoop must be run with `--global` to modify it.
+pub(crate) fn is_global_only_package(state: &AppState, package_name: &str) -> bool {
+    if state.scoop_path().join("apps").join(package_name).is_dir() {
+        return false;
+    }
+    state
+        .global_scoop_path()
+        .is_some_and(|root| root.join("apps").join(package_name).is_dir())
+}
+
+/// Checks whether `package_name` is a global install and, if so, warns in the
+/// operation output that modifying it needs an elevated Scoop, since the global
+/// root is not writable for regular users. Returns whether it is global.
+///
+/// `global` is the scope the caller already knows from the package list; the
+/// scope is only guessed from the app directories when it is `None`.
+pub(crate) fn check_global_package(
+    window: &Window,
+    state: &AppState,
+    package_name: &str,
+    global: Option<bool>,
+    operation_id: &str,
+) -> bool {
+    if !global.unwrap_or_else(|| is_global_only_package(state, package_name)) {
+        return false;
+    }
+
+    log::warn!(
+        "Package '{}' is installed globally; the operation requires administrator rights",
+        package_name
+    );
+    let _ = window.emit(
+        crate::commands::powershell::EVENT_OUTPUT,
+        crate::commands::powershell::StreamOutput {
+            operation_id: operation_id.to_string(),
+            line: format!(
+                "'{}' is a global install and requires administrator rights to modify.",
+                package_name
+            ),
+            source: "system".to_string(),
+        },
+    );
+    true
+}
+
 /// Internal method to perform the actual installed packages scan.
 /// Separated from the public command to support both warm-up and user-initiated refresh paths.
 async fn scan_installed_packages_internal<R: Runtime>(
@@ -647,6 +866,63 @@ async fn scan_installed_packages_internal<R: Runtime>(
 
     log::debug!("{} Starting installed packages scan", log_prefix);
 
+    // Only one scan may run at a time. A caller that had to wait for another
+    // scan reuses its result instead of walking the apps directory again.
+    let observed_generation = state.installed_scan_generation();
+    let _scan_guard = state.installed_scan_lock.lock().await;
+    let reused = if state.installed_scan_generation() != observed_generation {
+        state.installed_packages.lock().await.as_ref().map(|cache| {
+            log::debug!(
+                "{} Reusing result of concurrent scan ({} packages)",
+                log_prefix,
+                cache.packages.len()
+            );
+            cache.packages.clone()
+        })
+    } else {
+        None
+    };
+
+    let mut packages = match reused {
+        Some(packages) => packages,
+        None => scan_installed_packages_locked(app.clone(), state, log_prefix).await?,
+    };
+    // Notes are applied on read so editing one doesn't invalidate the scan cache
+    crate::commands::package_notes::apply_package_notes(&app, &mut packages);
+    Ok(packages)
+}
+
+/// Invalidates the installed and versions caches and rescans while holding the
+/// scan guard, so no other scan can repopulate them from a stale walk in between.
+pub(crate) async fn rebuild_installed_cache<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+) -> Result<Vec<ScoopPackage>, String> {
+    let _scan_guard = state.installed_scan_lock.lock().await;
+    invalidate_installed_cache(state.clone()).await;
+    scan_installed_packages_locked(app, &state, "=== INSTALLED REBUILD ===").await
+}
+
+/// Rebuilds the versions cache from the current installed packages cache.
+/// Returns the number of packages in the installed cache it was built from.
+pub(crate) async fn rebuild_package_versions_cache(state: &AppState) -> Result<usize, String> {
+    let cache = state
+        .installed_packages
+        .lock()
+        .await
+        .clone()
+        .ok_or_else(|| "Installed packages cache is empty".to_string())?;
+    update_package_versions_cache(state, &cache.packages, &cache.fingerprint).await;
+    Ok(cache.packages.len())
+}
+
+/// Scans the apps directories and refreshes the caches.
+/// The caller must hold `installed_scan_lock`.
+async fn scan_installed_packages_locked<R: Runtime>(
+    app: AppHandle<R>,
+    state: &AppState,
+    log_prefix: &str,
+) -> Result<Vec<ScoopPackage>, String> {
     // Ensure apps path exists
     let apps_path = match ensure_apps_path(app.clone(), state, log_prefix).await {
         Some(path) => path,
@@ -665,12 +941,7 @@ async fn scan_installed_packages_internal<R: Runtime>(
         apps_path.display()
     );
 
-    let app_dirs: Vec<PathBuf> = fs::read_dir(&apps_path)
-        .map_err(|e| format!("Failed to read apps directory: {}", e))?
-        .filter_map(Result::ok)
-        .map(|entry| entry.path())
-        .filter(|path| path.is_dir())
-        .collect();
+    let app_dirs = read_app_dirs(&apps_path)?;
 
     log::debug!(
         "{} Found {} app directories in apps path",
@@ -678,7 +949,35 @@ async fn scan_installed_packages_internal<R: Runtime>(
         app_dirs.len()
     );
 
-    let fingerprint = compute_apps_fingerprint(&app_dirs);
+    let global_app_dirs = match resolve_global_apps_path(&app, state) {
+        Some(global_apps_path) => {
+            let dirs = read_app_dirs(&global_apps_path).unwrap_or_else(|e| {
+                log::warn!("{} Failed to read global apps directory: {}", log_prefix, e);
+                Vec::new()
+            });
+            log::debug!(
+                "{} Found {} app directories in global apps path",
+                log_prefix,
+                dirs.len()
+            );
+            Some(dirs)
+        }
+        None => None,
+    };
+
+    let mut fingerprint = compute_apps_fingerprint(&app_dirs);
+    if let Some(global_dirs) = &global_app_dirs {
+        fingerprint = format!(
+            "{}|global:{}",
+            fingerprint,
+            compute_apps_fingerprint(global_dirs)
+        );
+    }
+    // Toggling strict mode changes the result for the same directory layout
+    let strict_current = is_strict_current_enabled(&app);
+    if strict_current {
+        fingerprint.push_str("|strict");
+    }
     log::debug!(
         "{} [FINGERPRINT] Computed (length: {} chars)",
         log_prefix,
@@ -695,45 +994,78 @@ async fn scan_installed_packages_internal<R: Runtime>(
 
     log::debug!("{} [SCAN] Starting package directory scan", log_prefix);
 
-    let packages: Vec<ScoopPackage> = app_dirs
-        .par_iter()
-        .filter_map(
-            |path| match load_package_details(path.as_path(), &scoop_path) {
-                Ok(package) => Some(package),
-                Err(e) => {
-                    let package_name = path
-                        .file_name()
-                        .and_then(|n| n.to_str())
-                        .unwrap_or("unknown");
-                    if e == "Skipping Scoop system package" {
-                        log::debug!("{} [SCAN] Skipping Scoop system package", log_prefix);
-                    } else if e.contains("'current' directory not found")
-                        && e.contains("no version directories available")
-                    {
+    // Global packages resolve their bucket against the user root, which holds the buckets
+    let scan_dirs: Vec<(PathBuf, PackageScope)> = app_dirs
+        .into_iter()
+        .map(|path| (path, PackageScope::User))
+        .chain(
+            global_app_dirs
+                .into_iter()
+                .flatten()
+                .map(|path| (path, PackageScope::Global)),
+        )
+        .collect();
+
+    let scan_pool = build_scan_thread_pool(&app, log_prefix);
+    let scan = || -> Vec<ScoopPackage> {
+        scan_dirs
+            .par_iter()
+            .filter_map(|(path, scope)| {
+                if strict_current {
+                    if let Some(mut package) = missing_current_package(path) {
                         log::debug!(
-                            "{} [SCAN] Skipping package '{}': {}",
+                            "{} [SCAN] 'current' missing for '{}'; reporting as broken",
                             log_prefix,
-                            package_name,
-                            e
-                        );
-                    } else {
-                        log::warn!(
-                            "{} [SCAN] Skipping package '{}': {}",
-                            log_prefix,
-                            package_name,
-                            e
+                            package.name
                         );
+                        package.scope = *scope;
+                        return Some(package);
                     }
-                    None
                 }
-            },
-        )
-        .collect();
+                match load_package_details(path.as_path(), &scoop_path) {
+                    Ok(mut package) => {
+                        package.scope = *scope;
+                        Some(package)
+                    }
+                    Err(e) => {
+                        let package_name = path
+                            .file_name()
+                            .and_then(|n| n.to_str())
+                            .unwrap_or("unknown");
+                        if e == "Skipping Scoop system package" {
+                            log::debug!("{} [SCAN] Skipping Scoop system package", log_prefix);
+                        } else if e.contains("'current' directory not found")
+                            && e.contains("no version directories available")
+                        {
+                            log::debug!(
+                                "{} [SCAN] Skipping package '{}': {}",
+                                log_prefix,
+                                package_name,
+                                e
+                            );
+                        } else {
+                            log::warn!(
+                                "{} [SCAN] Skipping package '{}': {}",
+                                log_prefix,
+                                package_name,
+                                e
+                            );
+                        }
+                        None
+                    }
+                }
+            })
+            .collect()
+    };
+    let packages = match scan_pool {
+        Some(pool) => pool.install(scan),
+        None => scan(),
+    };
 
     log::debug!(
         "{} [SCAN] ✓ Completed scan: {} directories processed, {} valid packages found",
         log_prefix,
-        app_dirs.len(),
+        scan_dirs.len(),
         packages.len()
     );
 
@@ -743,6 +1075,8 @@ async fn scan_installed_packages_internal<R: Runtime>(
     // Also update package versions cache to maintain consistency
     update_package_versions_cache(state, &packages, &fingerprint).await;
 
+    state.mark_installed_scan_completed();
+
     log::debug!(
         "{} ✓ Returning {} installed packages",
         log_prefix,
@@ -782,13 +1116,14 @@ pub async fn get_installed_packages_full<R: Runtime>(
 ) -> Result<Vec<ScoopPackage>, String> {
     log::debug!("=== INSTALLED SCAN === get_installed_packages_full called");
 
-    if let Some(cached_packages) =
+    if let Some(mut cached_packages) =
         get_recent_cached_packages(&state, "=== INSTALLED SCAN ===").await
     {
         log::debug!(
             "=== INSTALLED SCAN === get_installed_packages_full completed, result: Ok({})",
             cached_packages.len()
         );
+        crate::commands::package_notes::apply_package_notes(&app, &mut cached_packages);
         return Ok(cached_packages);
     }
 
@@ -801,6 +1136,154 @@ pub async fn get_installed_packages_full<R: Runtime>(
     result
 }
 
+/// Returns installed packages whose source matches the given bucket name.
+///
+/// Passing `"Custom"` returns packages whose bucket could not be determined.
+/// Results come from the installed packages cache and only trigger a scan when
+/// the cache is missing or stale.
+#[tauri::command]
+pub async fn get_installed_packages_by_source<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+    source: String,
+) -> Result<Vec<ScoopPackage>, String> {
+    let packages = get_installed_packages_full(app, state).await?;
+    let source = source.trim();
+
+    Ok(packages
+        .into_iter()
+        .filter(|package| package.source.eq_ignore_ascii_case(source))
+        .collect())
+}
+
+/// Returns the installed packages sorted by their `updated` time, newest first.
+///
+/// Packages without a readable timestamp come last. Results come from the
+/// installed packages cache like `get_installed_packages_by_source`.
+#[tauri::command]
+pub async fn get_installed_sorted_by_date<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+) -> Result<Vec<ScoopPackage>, String> {
+    let mut packages = get_installed_packages_full(app, state).await?;
+    packages.sort_by_cached_key(|package| {
+        std::cmp::Reverse(DateTime::parse_from_rfc3339(&package.updated).ok())
+    });
+    Ok(packages)
+}
+
+/// Installed packages of one bucket with their combined size on disk.
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct InstalledBucketGroup {
+    pub bucket: String,
+    pub count: usize,
+    pub total_size_bytes: u64,
+    pub packages: Vec<ScoopPackage>,
+}
+
+/// Package directory sizes keyed by `scope/name`, valid for one apps fingerprint.
+static PACKAGE_SIZE_CACHE: once_cell::sync::Lazy<
+    std::sync::Mutex<Option<(String, std::collections::HashMap<String, u64>)>>,
+> = once_cell::sync::Lazy::new(|| std::sync::Mutex::new(None));
+
+/// Forgets the measured package sizes. Returns the number of sizes dropped.
+pub(crate) fn clear_package_size_cache() -> usize {
+    PACKAGE_SIZE_CACHE
+        .lock()
+        .ok()
+        .and_then(|mut cache| cache.take())
+        .map_or(0, |(_, sizes)| sizes.len())
+}
+
+/// Groups the installed packages by bucket, largest group first.
+///
+/// Built from the installed packages cache. Package sizes are measured once per
+/// apps fingerprint, so repeated calls don't walk the app directories again.
+#[tauri::command]
+pub async fn get_installed_grouped_by_bucket<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+) -> Result<Vec<InstalledBucketGroup>, String> {
+    let packages = get_installed_packages_full(app, state.clone()).await?;
+    let fingerprint = state
+        .installed_packages
+        .lock()
+        .await
+        .as_ref()
+        .map(|cache| cache.fingerprint.clone())
+        .unwrap_or_default();
+
+    let scoop_path = state.scoop_path();
+    let global_root = state.global_scoop_path();
+    let size_key =
+        |package: &ScoopPackage| format!("{:?}/{}", package.scope, package.name.to_lowercase());
+
+    let cached_sizes = PACKAGE_SIZE_CACHE
+        .lock()
+        .ok()
+        .and_then(|cache| cache.clone())
+        .filter(|(cached_fingerprint, _)| {
+            !fingerprint.is_empty() && *cached_fingerprint == fingerprint
+        })
+        .map(|(_, sizes)| sizes);
+    let sizes = match cached_sizes {
+        Some(sizes) => sizes,
+        None => {
+            let targets: Vec<(String, PathBuf)> = packages
+                .iter()
+                .filter_map(|package| {
+                    let root = match package.scope {
+                        PackageScope::User => &scoop_path,
+                        PackageScope::Global => global_root.as_ref()?,
+                    };
+                    Some((size_key(package), root.join("apps").join(&package.name)))
+                })
+                .collect();
+            let sizes: std::collections::HashMap<String, u64> =
+                tokio::task::spawn_blocking(move || {
+                    targets
+                        .into_par_iter()
+                        .map(|(key, path)| (key, crate::utils::directory_size(&path)))
+                        .collect()
+                })
+                .await
+                .map_err(|e| e.to_string())?;
+            if let Ok(mut cache) = PACKAGE_SIZE_CACHE.lock() {
+                *cache = Some((fingerprint, sizes.clone()));
+            }
+            sizes
+        }
+    };
+
+    let mut groups: Vec<InstalledBucketGroup> = Vec::new();
+    for package in packages {
+        let size = sizes.get(&size_key(&package)).copied().unwrap_or(0);
+        match groups
+            .iter_mut()
+            .find(|group| group.bucket.eq_ignore_ascii_case(&package.source))
+        {
+            Some(group) => {
+                group.count += 1;
+                group.total_size_bytes += size;
+                group.packages.push(package);
+            }
+            None => groups.push(InstalledBucketGroup {
+                bucket: package.source.clone(),
+                count: 1,
+                total_size_bytes: size,
+                packages: vec![package],
+            }),
+        }
+    }
+    groups.sort_by(|a, b| {
+        b.total_size_bytes
+            .cmp(&a.total_size_bytes)
+            .then_with(|| a.bucket.cmp(&b.bucket))
+    });
+
+    Ok(groups)
+}
+
 /// Invalidates the cached list of installed packages in AppState.
 /// This should be called after operations that change the installed packages,
 /// such as installing or uninstalling a package.
@@ -813,12 +1296,27 @@ pub async fn invalidate_installed_cache(state: State<'_, AppState>) {
     let mut versions_guard = state.package_versions.lock().await;
     *versions_guard = None;
 
+    // Update counts are derived from the installed set as well
+    *state.update_check.lock().await = None;
+
     log::debug!(
         "=== INSTALLED CACHE === Cache invalidated (was_cached: {}). Also invalidated versions cache.",
         was_cached
     );
 }
 
+/// Settings key for the installed refresh debounce window in milliseconds.
+const REFRESH_DEBOUNCE_KEY: &str = "performance.refreshDebounceMs";
+const DEFAULT_REFRESH_DEBOUNCE_MS: u64 = 1000;
+
+fn resolve_refresh_debounce_ms<R: Runtime>(app: &AppHandle<R>) -> u64 {
+    crate::commands::settings::get_config_value(app.clone(), REFRESH_DEBOUNCE_KEY.to_string())
+        .ok()
+        .flatten()
+        .and_then(|value| value.as_u64())
+        .unwrap_or(DEFAULT_REFRESH_DEBOUNCE_MS)
+}
+
 /// Forces a refresh of the installed packages by invalidating cache and refetching.
 /// Debounces rapid consecutive calls to prevent unnecessary scans.
 #[tauri::command]
@@ -830,15 +1328,23 @@ pub async fn refresh_installed_packages<R: Runtime>(
     log::debug!("=== INSTALLED REFRESH === refresh_installed_packages called");
 
     // Check if we should debounce this refresh call
-    if !force.unwrap_or(false) && state.should_debounce_refresh() {
+    let debounce_ms = resolve_refresh_debounce_ms(&app);
+    if !force.unwrap_or(false) && state.should_debounce_refresh(debounce_ms) {
         log::debug!(
-            "=== INSTALLED REFRESH === Debouncing refresh (less than 1 second since last refresh)"
+            "=== INSTALLED REFRESH === Debouncing refresh (less than {}ms since last refresh)",
+            debounce_ms
         );
         // Return cached results without rescanning
-        let cache_guard = state.installed_packages.lock().await;
-        if let Some(cache) = cache_guard.as_ref() {
+        let cached_packages = state
+            .installed_packages
+            .lock()
+            .await
+            .as_ref()
+            .map(|cache| cache.packages.clone());
+        if let Some(mut packages) = cached_packages {
             log::debug!("=== INSTALLED REFRESH === Returning cached packages due to debounce");
-            return Ok(cache.packages.clone());
+            crate::commands::package_notes::apply_package_notes(&app, &mut packages);
+            return Ok(packages);
         }
     }
 
@@ -856,6 +1362,92 @@ pub async fn refresh_installed_packages<R: Runtime>(
     result
 }
 
+/// Lists installed packages whose recorded bucket no longer exists locally and
+/// that no remaining bucket provides, so they will never receive updates.
+///
+/// Custom installs, which never had a bucket, are not included.
+#[tauri::command]
+pub async fn find_unsourced_packages<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+) -> Result<Vec<ScoopPackage>, String> {
+    let packages = get_installed_packages_full(app, state.clone()).await?;
+    let scoop_path = state.scoop_path();
+    let buckets_path = scoop_path.join("buckets");
+
+    let unsourced: Vec<ScoopPackage> = packages
+        .into_iter()
+        .filter(|package| {
+            !package.source.is_empty()
+                && package.source != "Custom"
+                && !buckets_path.join(&package.source).is_dir()
+                && find_package_bucket(&scoop_path, &package.name).is_none()
+        })
+        .collect();
+
+    log::info!(
+        "Found {} installed packages without a bucket",
+        unsourced.len()
+    );
+    Ok(unsourced)
+}
+
+/// An installed package whose manifest name is found in more than one bucket.
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct AmbiguousPackage {
+    pub name: String,
+    pub buckets: Vec<String>,
+    /// The bucket recorded in `install.json`, if any.
+    pub recorded_bucket: Option<String>,
+    /// The bucket the installed scan attributes the package to.
+    pub resolved_bucket: String,
+}
+
+/// Reports installed packages matching manifests in several buckets.
+///
+/// Packages without a recorded bucket are attributed by guessing, which may
+/// pick the wrong bucket; those with one are listed for completeness.
+#[tauri::command]
+pub async fn find_ambiguous_packages<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+) -> Result<Vec<AmbiguousPackage>, String> {
+    let packages = get_installed_packages_full(app, state.clone()).await?;
+    let scoop_path = state.scoop_path();
+    let global_root = state.global_scoop_path();
+
+    let ambiguous: Vec<AmbiguousPackage> = packages
+        .into_par_iter()
+        .filter_map(|package| {
+            let buckets = find_package_buckets(&scoop_path, &package.name);
+            if buckets.len() < 2 {
+                return None;
+            }
+            let root = match (&package.scope, &global_root) {
+                (PackageScope::Global, Some(global_root)) => global_root,
+                _ => &scoop_path,
+            };
+            let recorded_bucket = locate_install_dir(&root.join("apps").join(&package.name))
+                .ok()
+                .and_then(|dir| fs::read_to_string(dir.join("install.json")).ok())
+                .and_then(|content| serde_json::from_str::<InstallManifest>(&content).ok())
+                .and_then(|manifest| manifest.bucket);
+            Some(AmbiguousPackage {
+                name: package.name,
+                buckets,
+                recorded_bucket,
+                resolved_bucket: package.source,
+            })
+        })
+        .collect();
+
+    log::info!(
+        "Found {} installed package(s) matching several buckets",
+        ambiguous.len()
+    );
+    Ok(ambiguous)
+}
+
 /// Gets the installation path for a specific package.
 #[tauri::command]
 pub async fn get_package_path<R: Runtime>(
@@ -872,6 +1464,34 @@ pub async fn get_package_path<R: Runtime>(
     Ok(package_path.to_string_lossy().to_string())
 }
 
+/// Opens the install folder of a package in the system file manager.
+///
+/// Resolves `apps/{name}/current`, falling back to the latest version directory
+/// when the `current` link is missing.
+#[tauri::command]
+pub async fn open_package_folder<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+    package_name: String,
+) -> Result<(), String> {
+    let package_path = state.scoop_path().join("apps").join(&package_name);
+
+    if !package_path.is_dir() {
+        return Err(format!("Package '{}' is not installed", package_name));
+    }
+
+    let install_dir = locate_install_dir(&package_path)?;
+    log::info!(
+        "Opening install folder for {}: {}",
+        package_name,
+        install_dir.display()
+    );
+
+    app.opener()
+        .open_path(install_dir.to_string_lossy(), None::<&str>)
+        .map_err(|e| format!("Failed to open folder for '{}': {}", package_name, e))
+}
+
 async fn ensure_apps_path<R: Runtime>(
     app: AppHandle<R>,
     state: &AppState,
@@ -1032,3 +1652,416 @@ pub async fn get_current_version_update_date<R: Runtime>(
 
     get_current_version_update_date_impl(&package_path)
 }
+
+/// Directories and variables an installed package adds to the environment.
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+pub struct EnvContribution {
+    pub package: String,
+    pub path_additions: Vec<String>,
+    pub env_vars: std::collections::BTreeMap<String, String>,
+}
+
+/// Reads `env_add_path` and `env_set` from a manifest, including the entries
+/// of the installed architecture, resolved like Scoop does against `dir`.
+fn manifest_env_contribution(
+    manifest: &serde_json::Value,
+    architecture: &str,
+    dir: &Path,
+    persist_dir: &Path,
+) -> (Vec<String>, std::collections::BTreeMap<String, String>) {
+    let arch_manifest = manifest
+        .get("architecture")
+        .and_then(|arch| arch.get(architecture));
+    let sources = std::iter::once(manifest).chain(arch_manifest);
+
+    let mut path_additions = Vec::new();
+    let mut env_vars = std::collections::BTreeMap::new();
+    for source in sources {
+        let paths = match source.get("env_add_path") {
+            Some(serde_json::Value::String(path)) => vec![path.as_str()],
+            Some(serde_json::Value::Array(paths)) => {
+                paths.iter().filter_map(|path| path.as_str()).collect()
+            }
+            _ => vec![],
+        };
+        for path in paths {
+            let resolved = if Path::new(path).is_absolute() {
+                PathBuf::from(path)
+            } else if path == "." {
+                dir.to_path_buf()
+            } else {
+                dir.join(path)
+            };
+            path_additions.push(resolved.to_string_lossy().to_string());
+        }
+
+        if let Some(vars) = source.get("env_set").and_then(|vars| vars.as_object()) {
+            for (name, value) in vars {
+                let value = value
+                    .as_str()
+                    .map(str::to_string)
+                    .unwrap_or_else(|| value.to_string());
+                let value = value
+                    .replace("$persist_dir", &persist_dir.to_string_lossy())
+                    .replace("$dir", &dir.to_string_lossy());
+                env_vars.insert(name.clone(), value);
+            }
+        }
+    }
+    (path_additions, env_vars)
+}
+
+/// Reads the manifest of the version an installed package currently uses.
+pub(crate) fn read_installed_manifest(package_path: &Path) -> Option<serde_json::Value> {
+    let version_dir = locate_install_dir(package_path).ok()?;
+    let content = fs::read_to_string(version_dir.join("manifest.json")).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Returns what an installed package under the Scoop `root` adds to the
+/// environment, or `None` if it adds nothing.
+pub(crate) fn package_env_contribution(
+    root: &Path,
+    package_path: &Path,
+) -> Option<EnvContribution> {
+    let package = extract_package_name(package_path).ok()?;
+    let version_dir = locate_install_dir(package_path).ok()?;
+    // Scoop resolves paths against the `current` link, not the version directory
+    let install_dir = package_path.join("current");
+
+    let manifest = read_installed_manifest(package_path)?;
+    let architecture = fs::read_to_string(version_dir.join("install.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str::<InstallManifest>(&content).ok())
+        .and_then(|install| install.architecture)
+        .unwrap_or_else(|| "64bit".to_string());
+    let persist_dir = root.join("persist").join(&package);
+
+    let (path_additions, env_vars) =
+        manifest_env_contribution(&manifest, &architecture, &install_dir, &persist_dir);
+    if path_additions.is_empty() && env_vars.is_empty() {
+        return None;
+    }
+    Some(EnvContribution {
+        package,
+        path_additions,
+        env_vars,
+    })
+}
+
+/// Lists the installed packages whose manifests add to PATH or set environment
+/// variables, for auditing what Scoop injected into the environment.
+#[tauri::command]
+pub async fn list_env_contributions(
+    state: State<'_, AppState>,
+) -> Result<Vec<EnvContribution>, String> {
+    let scoop_path = state.scoop_path();
+    let mut roots = vec![scoop_path.clone()];
+    roots.extend(state.global_scoop_path());
+
+    let mut contributions: Vec<EnvContribution> = roots
+        .iter()
+        .flat_map(|root| {
+            read_app_dirs(&root.join("apps"))
+                .unwrap_or_default()
+                .into_iter()
+                .map(move |path| (root.clone(), path))
+        })
+        .par_bridge()
+        .filter(|(_, package_path)| {
+            !package_path
+                .file_name()
+                .is_some_and(|name| name.eq_ignore_ascii_case("scoop"))
+        })
+        .filter_map(|(root, package_path)| package_env_contribution(&root, &package_path))
+        .collect();
+    contributions.sort_by_key(|contribution| contribution.package.to_lowercase());
+
+    log::info!(
+        "Found {} installed package(s) contributing to the environment",
+        contributions.len()
+    );
+    Ok(contributions)
+}
+
+/// An installed package whose bucket offers a better-suited architecture.
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ArchitectureCandidate {
+    pub name: String,
+    pub installed_arch: String,
+    pub available_arch: String,
+}
+
+/// Architectures a system can run, best first.
+fn runnable_architectures(system_arch: &str) -> &'static [&'static str] {
+    match system_arch {
+        "arm64" => &["arm64", "64bit", "32bit"],
+        "64bit" => &["64bit", "32bit"],
+        _ => &["32bit"],
+    }
+}
+
+/// Returns a better architecture than `installed_arch` that the manifest
+/// declares and the system can run, if there is one.
+fn better_architecture(
+    manifest: &serde_json::Value,
+    installed_arch: &str,
+    system_arch: &str,
+) -> Option<&'static str> {
+    let offered = manifest.get("architecture")?.as_object()?;
+    let runnable = runnable_architectures(system_arch);
+    let installed_rank = runnable.iter().position(|arch| *arch == installed_arch)?;
+    runnable[..installed_rank]
+        .iter()
+        .find(|arch| offered.contains_key(**arch))
+        .copied()
+}
+
+/// Lists installed packages that run a narrower architecture than their bucket
+/// manifest offers, e.g. 32-bit builds on a 64-bit system, as reinstall candidates.
+#[tauri::command]
+pub async fn find_suboptimal_architecture<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+) -> Result<Vec<ArchitectureCandidate>, String> {
+    let packages = get_installed_packages_full(app, state.clone()).await?;
+    let scoop_path = state.scoop_path();
+    let system_arch = crate::commands::package_hash::current_architecture();
+
+    let candidates: Vec<ArchitectureCandidate> = packages
+        .into_par_iter()
+        .filter_map(|package| {
+            let installed_arch = package.architecture?;
+            let source = (package.source != "Custom").then(|| package.source.clone());
+            let (manifest_path, _) =
+                crate::utils::locate_package_manifest(&scoop_path, &package.name, source).ok()?;
+            let manifest = crate::commands::manifest::read_manifest_json(&manifest_path).ok()?;
+            let available_arch = better_architecture(&manifest, &installed_arch, system_arch)?;
+            Some(ArchitectureCandidate {
+                name: package.name,
+                installed_arch,
+                available_arch: available_arch.to_string(),
+            })
+        })
+        .collect();
+
+    log::info!(
+        "Found {} package(s) installed with a narrower architecture than available",
+        candidates.len()
+    );
+    Ok(candidates)
+}
+
+/// A package installed in more than one Scoop scope.
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct DuplicateInstall {
+    pub name: String,
+    /// Scopes the package is installed in, `"user"` and/or `"global"`.
+    pub scopes: Vec<String>,
+    /// Scope whose shims directory comes first on PATH, if both are on PATH.
+    pub path_winner: Option<String>,
+}
+
+/// Returns installed package names in an `apps` directory, keyed by lowercase name.
+fn installed_app_names(apps_path: &Path) -> std::collections::HashMap<String, String> {
+    read_app_dirs(apps_path)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|path| locate_install_dir(path).is_ok())
+        .filter_map(|path| extract_package_name(&path).ok())
+        .filter(|name| !name.eq_ignore_ascii_case("scoop"))
+        .map(|name| (name.to_lowercase(), name))
+        .collect()
+}
+
+pub(crate) fn same_dir(a: &Path, b: &Path) -> bool {
+    let normalize = |p: &Path| {
+        p.to_string_lossy()
+            .trim_end_matches(['\\', '/'])
+            .replace('/', "\\")
+            .to_lowercase()
+    };
+    normalize(a) == normalize(b)
+}
+
+/// Determines which shims directory appears first in the given PATH entries.
+pub(crate) fn shim_dir_precedence<I>(
+    path_entries: I,
+    user_shims: &Path,
+    global_shims: &Path,
+) -> Option<String>
+where
+    I: IntoIterator<Item = PathBuf>,
+{
+    let mut user_pos = None;
+    let mut global_pos = None;
+    for (index, entry) in path_entries.into_iter().enumerate() {
+        if user_pos.is_none() && same_dir(&entry, user_shims) {
+            user_pos = Some(index);
+        }
+        if global_pos.is_none() && same_dir(&entry, global_shims) {
+            global_pos = Some(index);
+        }
+    }
+
+    match (user_pos, global_pos) {
+        (Some(user), Some(global)) if user < global => Some("user".to_string()),
+        (Some(_), Some(_)) => Some("global".to_string()),
+        _ => None,
+    }
+}
+
+/// Reports packages installed in both the user and the global scope.
+#[tauri::command]
+pub async fn find_duplicate_installs(
+    state: State<'_, AppState>,
+) -> Result<Vec<DuplicateInstall>, String> {
+    let scoop_path = state.scoop_path();
+    let Some(global_root) = state.global_scoop_path() else {
+        log::debug!("No global Scoop root configured; skipping duplicate scan");
+        return Ok(vec![]);
+    };
+
+    let user_apps = installed_app_names(&scoop_path.join("apps"));
+    let global_apps = installed_app_names(&global_root.join("apps"));
+
+    let path_winner = std::env::var_os("PATH").and_then(|path| {
+        shim_dir_precedence(
+            std::env::split_paths(&path),
+            &scoop_path.join("shims"),
+            &global_root.join("shims"),
+        )
+    });
+
+    let mut duplicates: Vec<DuplicateInstall> = user_apps
+        .into_iter()
+        .filter(|(key, _)| global_apps.contains_key(key))
+        .map(|(_, name)| DuplicateInstall {
+            name,
+            scopes: vec!["user".to_string(), "global".to_string()],
+            path_winner: path_winner.clone(),
+        })
+        .collect();
+    duplicates.sort_by_key(|dup| dup.name.to_lowercase());
+
+    log::info!(
+        "Found {} package(s) installed in both user and global scope",
+        duplicates.len()
+    );
+    Ok(duplicates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        better_architecture, find_package_buckets, is_valid_version_string,
+        manifest_env_contribution, shim_dir_precedence,
+    };
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn accepts_scoop_version_strings() {
+        assert!(is_valid_version_string("1.2.3+build.5"));
+        assert!(is_valid_version_string("2023.01.1"));
+        assert!(is_valid_version_string("1.0.0-rc.1"));
+        assert!(is_valid_version_string("2.0~beta1"));
+        assert!(is_valid_version_string("nightly"));
+    }
+
+    #[test]
+    fn rejects_non_version_directory_names() {
+        assert!(!is_valid_version_string(""));
+        assert!(!is_valid_version_string("current"));
+        assert!(!is_valid_version_string("persist"));
+        assert!(!is_valid_version_string("+1.0"));
+        assert!(!is_valid_version_string("1.0 (old)"));
+    }
+
+    #[test]
+    fn shim_precedence_follows_path_order() {
+        let user = Path::new(r"C:\Users\me\scoop\shims");
+        let global = Path::new(r"C:\ProgramData\scoop\shims");
+        let path = |entries: &[&str]| entries.iter().map(PathBuf::from).collect::<Vec<_>>();
+
+        assert_eq!(
+            shim_dir_precedence(
+                path(&[r"C:\ProgramData\scoop\shims\", r"c:\users\me\scoop\shims"]),
+                user,
+                global
+            ),
+            Some("global".to_string())
+        );
+        assert_eq!(
+            shim_dir_precedence(path(&[r"C:\Users\me\scoop\shims"]), user, global),
+            None
+        );
+    }
+
+    #[test]
+    fn lists_every_bucket_with_a_matching_manifest() {
+        let scoop = tempfile::tempdir().unwrap();
+        for bucket in ["main", "extras", "versions"] {
+            std::fs::create_dir_all(scoop.path().join("buckets").join(bucket).join("bucket"))
+                .unwrap();
+        }
+        for bucket in ["main", "extras"] {
+            let manifest = scoop
+                .path()
+                .join("buckets")
+                .join(bucket)
+                .join("bucket")
+                .join("git.json");
+            std::fs::write(manifest, "{}").unwrap();
+        }
+
+        assert_eq!(
+            find_package_buckets(scoop.path(), "git"),
+            vec!["extras".to_string(), "main".to_string()]
+        );
+        assert!(find_package_buckets(scoop.path(), "nodejs").is_empty());
+    }
+
+    #[test]
+    fn resolves_env_contributions_of_the_installed_architecture() {
+        let manifest = serde_json::json!({
+            "env_add_path": "bin",
+            "env_set": { "GOROOT": "$dir", "GOPATH": "$persist_dir\\go" },
+            "architecture": {
+                "64bit": { "env_add_path": ["."] },
+                "32bit": { "env_add_path": ["bin32"] }
+            }
+        });
+        let dir = Path::new("apps/go/current");
+        let persist = Path::new("persist/go");
+
+        let (paths, vars) = manifest_env_contribution(&manifest, "64bit", dir, persist);
+
+        assert_eq!(
+            paths,
+            vec![
+                dir.join("bin").to_string_lossy().to_string(),
+                dir.to_string_lossy().to_string()
+            ]
+        );
+        assert_eq!(vars["GOROOT"], dir.to_string_lossy());
+        assert_eq!(vars["GOPATH"], format!("{}\\go", persist.display()));
+    }
+
+    #[test]
+    fn suggests_wider_architectures_the_system_can_run() {
+        let manifest = serde_json::json!({
+            "architecture": { "64bit": {}, "32bit": {} }
+        });
+
+        assert_eq!(
+            better_architecture(&manifest, "32bit", "64bit"),
+            Some("64bit")
+        );
+        assert_eq!(
+            better_architecture(&manifest, "32bit", "arm64"),
+            Some("64bit")
+        );
+        assert_eq!(better_architecture(&manifest, "64bit", "64bit"), None);
+        assert_eq!(better_architecture(&manifest, "32bit", "32bit"), None);
+    }
+}
@@ -20,6 +20,8 @@ pub struct PackageVersion {
     pub version: String,
     pub is_current: bool,
     pub install_path: String,
+    /// Size of the version directory in bytes.
+    pub size_bytes: u64,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -174,22 +176,24 @@ async fn build_versioned_package_info(
     for dir_name_str in version_dirs {
         let is_current = dir_name_str == current_version;
         let path = package_dir.join(&dir_name_str);
+        let size_bytes = {
+            let path = path.clone();
+            tokio::task::spawn_blocking(move || crate::utils::directory_size(&path))
+                .await
+                .unwrap_or(0)
+        };
         versions.push(PackageVersion {
             version: dir_name_str,
             is_current,
             install_path: path.to_string_lossy().to_string(),
+            size_bytes,
         });
     }
 
-    // Sort versions (newest first, with current version prioritized)
+    // Sort versions newest first
     versions.sort_by(|a, b| {
-        if a.is_current {
-            std::cmp::Ordering::Less
-        } else if b.is_current {
-            std::cmp::Ordering::Greater
-        } else {
-            b.version.cmp(&a.version)
-        }
+        crate::utils::compare_versions(&b.version, &a.version)
+            .then_with(|| b.version.cmp(&a.version))
     });
 
     Ok(VersionedPackageInfo {
@@ -236,7 +240,14 @@ async fn switch_package_version_inner(
 ) -> Result<String, String> {
     let scoop_path = state.scoop_path();
     let is_global = global.unwrap_or(false);
-    let apps_dir = scoop_path.join("apps");
+    let scope_root = if is_global {
+        state
+            .global_scoop_path()
+            .ok_or_else(|| "No global Scoop root is configured".to_string())?
+    } else {
+        scoop_path.clone()
+    };
+    let apps_dir = scope_root.join("apps");
 
     let package_dir = apps_dir.join(package_name);
     let target_version_dir = package_dir.join(target_version);
@@ -275,12 +286,12 @@ async fn switch_package_version_inner(
 
         if let Err(error) = fast_switch_package_version(
             &scoop_path,
+            &scope_root,
             package_name,
             &package_dir,
             &target_version_dir,
             current_manifest.as_ref(),
             &target_manifest,
-            is_global,
         ) {
             log::warn!(
                 "Fast-path switch failed for '{}' -> '{}': {}. Falling back to scoop reset.",
@@ -643,7 +654,7 @@ fn clear_link_readonly_attribute(path: &Path) -> Result<(), String> {
     }
 }
 
-fn create_directory_junction(link: &Path, target: &Path) -> Result<(), String> {
+pub(crate) fn create_directory_junction(link: &Path, target: &Path) -> Result<(), String> {
     let mut command = Command::new("cmd");
     command.args([
         "/c",
@@ -778,19 +789,17 @@ fn remove_shim(shims_dir: &Path, shim_name: &str) -> Result<(), String> {
     Ok(())
 }
 
+/// Rewrites a package's shims in `scope_root`, the user or global Scoop root it
+/// is installed in. The shim executable is copied from the user install of Scoop.
 fn refresh_package_shims(
     scoop_path: &Path,
+    scope_root: &Path,
     package_name: &str,
     current_manifest: Option<&Value>,
     target_manifest: &Value,
-    is_global: bool,
 ) -> Result<(), String> {
-    let current_dir = scoop_path.join("apps").join(package_name).join("current");
-    let shims_dir = if is_global {
-        scoop_path.join("global").join("shims")
-    } else {
-        scoop_path.join("shims")
-    };
+    let current_dir = scope_root.join("apps").join(package_name).join("current");
+    let shims_dir = scope_root.join("shims");
 
     let previous_entries = current_manifest
         .map(|manifest| collect_manifest_shim_entries(manifest, package_name))
@@ -824,20 +833,20 @@ fn refresh_package_shims(
 
 fn fast_switch_package_version(
     scoop_path: &Path,
+    scope_root: &Path,
     package_name: &str,
     package_dir: &Path,
     target_version_dir: &Path,
     current_manifest: Option<&Value>,
     target_manifest: &Value,
-    is_global: bool,
 ) -> Result<(), String> {
     update_current_junction(package_dir, target_version_dir)?;
     refresh_package_shims(
         scoop_path,
+        scope_root,
         package_name,
         current_manifest,
         target_manifest,
-        is_global,
     )
 }
 
@@ -922,7 +931,10 @@ pub async fn debug_package_structure(
     let is_global = global.unwrap_or(false);
 
     let apps_dir = if is_global {
-        scoop_path.join("apps")
+        state
+            .global_scoop_path()
+            .ok_or_else(|| "No global Scoop root is configured".to_string())?
+            .join("apps")
     } else {
         scoop_path.join("apps")
     };
@@ -987,11 +999,15 @@ pub async fn debug_package_structure(
 }
 
 /// Change the bucket of an installed package by modifying its install.json
+///
+/// The target bucket must contain a manifest for the package whose version is not older
+/// than the installed one. With `dry_run` set, only the checks run and nothing is written.
 #[tauri::command]
 pub async fn change_package_bucket(
     state: State<'_, AppState>,
     package_name: String,
     new_bucket: String,
+    dry_run: Option<bool>,
 ) -> Result<String, String> {
     let trimmed_package_name = package_name.trim();
     let trimmed_new_bucket = new_bucket.trim();
@@ -1094,6 +1110,49 @@ pub async fn change_package_bucket(
     let mut install_data: serde_json::Value = serde_json::from_str(&install_json_content)
         .map_err(|e| format!("Failed to parse install.json: {}", e))?;
 
+    // The target manifest must not be older than what is installed, otherwise the next
+    // update would silently downgrade the package
+    let target_manifest: Value = fs::read_to_string(&manifest_path)
+        .map_err(|e| format!("Failed to read target manifest: {}", e))
+        .and_then(|content| {
+            serde_json::from_str(&content)
+                .map_err(|e| format!("Failed to parse target manifest: {}", e))
+        })?;
+    let target_version = target_manifest
+        .get("version")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| {
+            format!(
+                "Manifest '{}' in bucket '{}' has no version",
+                trimmed_package_name, trimmed_new_bucket
+            )
+        })?;
+    let installed_version = read_manifest_json(&install_dir)
+        .ok()
+        .and_then(|manifest| manifest.get("version")?.as_str().map(str::to_string));
+
+    if let Some(installed_version) = installed_version.as_deref() {
+        if crate::utils::compare_versions(target_version, installed_version)
+            == std::cmp::Ordering::Less
+        {
+            return Err(format!(
+                "Bucket '{}' provides '{}' {}, which is older than the installed {}",
+                trimmed_new_bucket, trimmed_package_name, target_version, installed_version
+            ));
+        }
+    }
+
+    if dry_run.unwrap_or(false) {
+        let current_bucket = install_data
+            .get("bucket")
+            .and_then(|v| v.as_str())
+            .unwrap_or("unknown");
+        return Ok(format!(
+            "Bucket for '{}' can be changed from '{}' to '{}'",
+            trimmed_package_name, current_bucket, trimmed_new_bucket
+        ));
+    }
+
     // Update the bucket field
     if let Some(obj) = install_data.as_object_mut() {
         obj.insert(
@@ -1111,8 +1170,87 @@ pub async fn change_package_bucket(
     fs::write(&install_json_path, updated_content)
         .map_err(|e| format!("Failed to write updated install.json: {}", e))?;
 
+    // The source column comes from the installed cache
+    crate::commands::installed::invalidate_installed_cache(state.clone()).await;
+
     Ok(format!(
         "Successfully changed bucket for '{}' to '{}'",
         trimmed_package_name, trimmed_new_bucket
     ))
 }
+
+/// An `apps\<package>\current` link whose target no longer exists.
+#[derive(Serialize, Debug, Clone)]
+pub struct BrokenJunction {
+    pub package: String,
+    pub target: String,
+}
+
+/// Reports the packages whose `current` junction points at a deleted version directory.
+#[tauri::command]
+pub async fn find_broken_junctions(
+    state: State<'_, AppState>,
+) -> Result<Vec<BrokenJunction>, String> {
+    let apps_dir = state.scoop_path().join("apps");
+    let entries = match fs::read_dir(&apps_dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => {
+            return Err(format!(
+                "Failed to read apps directory '{}': {}",
+                apps_dir.display(),
+                err
+            ))
+        }
+    };
+
+    let mut broken = Vec::new();
+    for entry in entries.flatten() {
+        let current = entry.path().join("current");
+        let is_link = fs::symlink_metadata(&current)
+            .map(|metadata| metadata.file_type().is_symlink())
+            .unwrap_or(false);
+        if !is_link || current.exists() {
+            continue;
+        }
+
+        broken.push(BrokenJunction {
+            package: entry.file_name().to_string_lossy().to_string(),
+            target: fs::read_link(&current)
+                .map(|target| target.to_string_lossy().to_string())
+                .unwrap_or_default(),
+        });
+    }
+
+    broken.sort_by(|a, b| a.package.cmp(&b.package));
+    Ok(broken)
+}
+
+/// Re-links `current` of a package to its most recent valid version directory.
+#[tauri::command]
+pub async fn repair_junction(
+    state: State<'_, AppState>,
+    package: String,
+) -> Result<String, String> {
+    let package = package.trim();
+    if package.is_empty() || package.contains(['/', '\\']) || package == "." || package == ".." {
+        return Err(format!("Invalid package name '{}'", package));
+    }
+
+    let package_dir = state.scoop_path().join("apps").join(package);
+    if !package_dir.is_dir() {
+        return Err(format!("Package '{}' is not installed", package));
+    }
+
+    let target = crate::commands::installed::find_latest_version_dir(&package_dir)
+        .ok_or_else(|| format!("No valid version directory found for '{}'", package))?;
+    update_current_junction(&package_dir, &target)?;
+    crate::commands::installed::invalidate_installed_cache(state.clone()).await;
+
+    log::info!(
+        "Repaired current junction of {} -> {}",
+        package,
+        target.display()
+    );
+    Ok(target.to_string_lossy().to_string())
+}
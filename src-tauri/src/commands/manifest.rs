@@ -1,8 +1,72 @@
-//! Command for fetching the raw JSON manifest of a Scoop package.
+//! Commands for fetching and comparing the JSON manifests of Scoop packages.
 use crate::state::AppState;
 use crate::utils;
+use serde::Serialize;
+use serde_json::Value;
 use std::fs;
-use tauri::State;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Runtime, State};
+use tauri_plugin_opener::OpenerExt;
+
+/// Top-level manifest fields compared by `diff_manifest`.
+const DIFF_FIELDS: &[&str] = &[
+    "version",
+    "depends",
+    "bin",
+    "url",
+    "hash",
+    "architecture",
+    "installer",
+    "pre_install",
+    "post_install",
+];
+
+/// A single top-level field that differs between two manifests.
+#[derive(Serialize, Debug, Clone)]
+pub struct ManifestFieldChange {
+    pub field: String,
+    pub installed: Option<Value>,
+    pub bucket: Option<Value>,
+}
+
+/// Differences between an installed manifest and the current bucket manifest.
+#[derive(Serialize, Debug, Clone)]
+pub struct ManifestDiff {
+    pub name: String,
+    pub bucket: String,
+    pub installed_version: Option<String>,
+    pub bucket_version: Option<String>,
+    pub changes: Vec<ManifestFieldChange>,
+}
+
+pub(crate) fn read_manifest_json(path: &Path) -> Result<Value, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read manifest {}: {}", path.display(), e))?;
+    serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse manifest {}: {}", path.display(), e))
+}
+
+fn manifest_version(manifest: &Value) -> Option<String> {
+    manifest
+        .get("version")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+}
+
+fn diff_manifest_fields(installed: &Value, bucket: &Value) -> Vec<ManifestFieldChange> {
+    DIFF_FIELDS
+        .iter()
+        .filter_map(|field| {
+            let installed_value = installed.get(*field);
+            let bucket_value = bucket.get(*field);
+            (installed_value != bucket_value).then(|| ManifestFieldChange {
+                field: field.to_string(),
+                installed: installed_value.cloned(),
+                bucket: bucket_value.cloned(),
+            })
+        })
+        .collect()
+}
 
 /// Fetches the manifest content for a given package from a specific bucket.
 ///
@@ -35,3 +99,382 @@ pub fn get_package_manifest(
     fs::read_to_string(&manifest_path)
         .map_err(|e| format!("Failed to read manifest for {}: {}", package_name, e))
 }
+
+/// Resolves the manifest file to open for a package.
+///
+/// An explicit bucket always wins. Otherwise the installed `current/manifest.json`
+/// is preferred, falling back to the first bucket that provides the package.
+fn resolve_manifest_path(
+    scoop_dir: &Path,
+    name: &str,
+    bucket: Option<String>,
+) -> Result<PathBuf, String> {
+    let bucket = bucket.filter(|b| !b.trim().is_empty() && !b.eq_ignore_ascii_case("none"));
+
+    if bucket.is_none() {
+        let installed_manifest = scoop_dir
+            .join("apps")
+            .join(name)
+            .join("current")
+            .join("manifest.json");
+        if installed_manifest.is_file() {
+            return Ok(installed_manifest);
+        }
+    }
+
+    utils::locate_package_manifest(scoop_dir, name, bucket)
+        .map(|(path, _)| path)
+        .map_err(|e| format!("Could not locate a manifest for '{}': {}", name, e))
+}
+
+/// Opens the raw manifest of a package with the system's default editor.
+#[tauri::command]
+pub fn open_manifest_in_editor<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+    name: String,
+    bucket: Option<String>,
+) -> Result<(), String> {
+    let manifest_path = resolve_manifest_path(&state.scoop_path(), &name, bucket)?;
+    log::info!("Opening manifest for {}: {}", name, manifest_path.display());
+
+    app.opener()
+        .open_path(manifest_path.to_string_lossy(), None::<&str>)
+        .map_err(|e| format!("Failed to open manifest for '{}': {}", name, e))
+}
+
+/// Compares the installed manifest of a package against the one in its bucket.
+///
+/// Only the fields in `DIFF_FIELDS` are reported, which covers what usually
+/// makes an update risky: new dependencies, different binaries or changed
+/// install scripts.
+#[tauri::command]
+pub fn diff_manifest(state: State<'_, AppState>, name: String) -> Result<ManifestDiff, String> {
+    let scoop_dir = state.scoop_path();
+
+    let install_dir = utils::locate_current_install_dir(&scoop_dir, &name)?;
+    let installed = read_manifest_json(&install_dir.join("manifest.json"))?;
+
+    let install_bucket = utils::read_install_bucket_from_dir(&install_dir)
+        .filter(|bucket| !bucket.trim().is_empty());
+    let (bucket_manifest_path, bucket) =
+        utils::locate_package_manifest(&scoop_dir, &name, install_bucket)?;
+    if bucket_manifest_path.starts_with(scoop_dir.join("apps")) {
+        return Err(format!(
+            "No bucket manifest found for '{}' to compare against",
+            name
+        ));
+    }
+    let current = read_manifest_json(&bucket_manifest_path)?;
+
+    Ok(ManifestDiff {
+        installed_version: manifest_version(&installed),
+        bucket_version: manifest_version(&current),
+        changes: diff_manifest_fields(&installed, &current),
+        name,
+        bucket,
+    })
+}
+
+/// Architecture keys Scoop accepts in a manifest's `architecture` block.
+const ARCHITECTURE_KEYS: &[&str] = &["64bit", "32bit", "arm64"];
+
+/// How serious a manifest validation issue is.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum IssueSeverity {
+    /// Scoop would fail to install the manifest.
+    Error,
+    /// The manifest works but is incomplete or unusual.
+    Warning,
+}
+
+/// A problem found by `validate_manifest`.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ValidationIssue {
+    pub severity: IssueSeverity,
+    /// Dotted path of the offending field, e.g. `architecture.64bit.hash`.
+    pub field: String,
+    pub message: String,
+}
+
+impl ValidationIssue {
+    fn new(severity: IssueSeverity, field: &str, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            field: field.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Number of entries of a field that may be a single string or an array of strings.
+fn string_or_array_len(value: &Value) -> Option<usize> {
+    match value {
+        Value::String(_) => Some(1),
+        Value::Array(items) if items.iter().all(Value::is_string) => Some(items.len()),
+        _ => None,
+    }
+}
+
+/// Checks that the `url` and `hash` fields of `block` are well-formed and paired.
+fn validate_url_hash(block: &Value, prefix: &str, issues: &mut Vec<ValidationIssue>) {
+    let field = |name: &str| {
+        if prefix.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}.{}", prefix, name)
+        }
+    };
+
+    let urls = block.get("url").map(|url| (url, string_or_array_len(url)));
+    let hashes = block
+        .get("hash")
+        .map(|hash| (hash, string_or_array_len(hash)));
+
+    if let Some((_, None)) = urls {
+        issues.push(ValidationIssue::new(
+            IssueSeverity::Error,
+            &field("url"),
+            "must be a string or an array of strings",
+        ));
+    }
+    if let Some((_, None)) = hashes {
+        issues.push(ValidationIssue::new(
+            IssueSeverity::Error,
+            &field("hash"),
+            "must be a string or an array of strings",
+        ));
+    }
+
+    match (urls, hashes) {
+        (Some((_, Some(url_count))), Some((_, Some(hash_count)))) if url_count != hash_count => {
+            issues.push(ValidationIssue::new(
+                IssueSeverity::Error,
+                &field("hash"),
+                format!("{} url(s) but {} hash(es)", url_count, hash_count),
+            ));
+        }
+        (Some(_), None) => issues.push(ValidationIssue::new(
+            IssueSeverity::Warning,
+            &field("hash"),
+            "url has no hash; installs will need --skip-hash",
+        )),
+        (None, Some(_)) => issues.push(ValidationIssue::new(
+            IssueSeverity::Error,
+            &field("hash"),
+            "hash without url",
+        )),
+        _ => {}
+    }
+}
+
+/// Checks that every `bin` entry is shaped like one, a target optionally with an
+/// alias and arguments as `[target, alias, args]`. Whether the target exists in
+/// the downloaded files is only known after extraction and isn't checked.
+fn check_bin_entry_shape(bin: &Value, field: &str, issues: &mut Vec<ValidationIssue>) {
+    let entries = match bin {
+        Value::String(_) => vec![bin],
+        Value::Array(items) => items.iter().collect(),
+        _ => {
+            issues.push(ValidationIssue::new(
+                IssueSeverity::Error,
+                field,
+                "must be a string or an array",
+            ));
+            return;
+        }
+    };
+
+    for (index, entry) in entries.into_iter().enumerate() {
+        let target = match entry {
+            Value::Array(parts) => parts.first(),
+            other => Some(other),
+        };
+        match target.and_then(Value::as_str).map(str::trim) {
+            Some(target) if !target.is_empty() => {}
+            _ => issues.push(ValidationIssue::new(
+                IssueSeverity::Error,
+                &format!("{}[{}]", field, index),
+                "does not name an executable",
+            )),
+        }
+    }
+}
+
+/// Checks the structure of a parsed manifest, reporting missing fields, unpaired
+/// url/hash entries, malformed `bin` entries and unknown architecture blocks.
+///
+/// This works on the JSON alone; nothing is downloaded or extracted, so a
+/// manifest that passes can still fail to install.
+pub(crate) fn check_manifest_structure(manifest: &Value) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    if !manifest.is_object() {
+        issues.push(ValidationIssue::new(
+            IssueSeverity::Error,
+            "",
+            "manifest must be a JSON object",
+        ));
+        return issues;
+    }
+
+    match manifest
+        .get("version")
+        .and_then(Value::as_str)
+        .map(str::trim)
+    {
+        Some(version) if !version.is_empty() => {}
+        _ => issues.push(ValidationIssue::new(
+            IssueSeverity::Error,
+            "version",
+            "version is required",
+        )),
+    }
+    for field in ["description", "homepage", "license"] {
+        if manifest.get(field).is_none() {
+            issues.push(ValidationIssue::new(
+                IssueSeverity::Warning,
+                field,
+                format!("{} is missing", field),
+            ));
+        }
+    }
+
+    validate_url_hash(manifest, "", &mut issues);
+    if let Some(bin) = manifest.get("bin") {
+        check_bin_entry_shape(bin, "bin", &mut issues);
+    }
+
+    let mut has_url = manifest.get("url").is_some();
+    match manifest.get("architecture") {
+        None => {}
+        Some(Value::Object(blocks)) => {
+            for (arch, block) in blocks {
+                let prefix = format!("architecture.{}", arch);
+                if !ARCHITECTURE_KEYS.contains(&arch.as_str()) {
+                    issues.push(ValidationIssue::new(
+                        IssueSeverity::Error,
+                        &prefix,
+                        format!(
+                            "unknown architecture, expected one of {:?}",
+                            ARCHITECTURE_KEYS
+                        ),
+                    ));
+                }
+                if !block.is_object() {
+                    issues.push(ValidationIssue::new(
+                        IssueSeverity::Error,
+                        &prefix,
+                        "must be an object",
+                    ));
+                    continue;
+                }
+                has_url |= block.get("url").is_some();
+                validate_url_hash(block, &prefix, &mut issues);
+                if let Some(bin) = block.get("bin") {
+                    check_bin_entry_shape(bin, &format!("{}.bin", prefix), &mut issues);
+                }
+            }
+        }
+        Some(_) => issues.push(ValidationIssue::new(
+            IssueSeverity::Error,
+            "architecture",
+            "must be an object",
+        )),
+    }
+
+    if !has_url {
+        issues.push(ValidationIssue::new(
+            IssueSeverity::Error,
+            "url",
+            "no url at the top level or in any architecture block",
+        ));
+    }
+
+    issues
+}
+
+/// Checks the structure of a manifest file that is being authored and lists the
+/// problems found. An empty list means no structural problems were found; see
+/// `check_manifest_structure` for what is and isn't covered.
+#[tauri::command]
+pub fn validate_manifest(path: String) -> Result<Vec<ValidationIssue>, String> {
+    let content =
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+
+    match serde_json::from_str::<Value>(content.trim_start_matches('\u{feff}')) {
+        Ok(manifest) => Ok(check_manifest_structure(&manifest)),
+        Err(e) => Ok(vec![ValidationIssue::new(
+            IssueSeverity::Error,
+            "",
+            format!("invalid JSON: {}", e),
+        )]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn fields(issues: &[ValidationIssue], severity: IssueSeverity) -> Vec<&str> {
+        issues
+            .iter()
+            .filter(|issue| issue.severity == severity)
+            .map(|issue| issue.field.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn accepts_complete_manifest() {
+        let manifest = json!({
+            "version": "1.0.0",
+            "description": "Example",
+            "homepage": "https://example.com",
+            "license": "MIT",
+            "architecture": {
+                "64bit": { "url": "https://example.com/a.zip", "hash": "abc" }
+            },
+            "bin": ["a.exe", ["b.exe", "b", "--flag"]]
+        });
+
+        assert!(check_manifest_structure(&manifest).is_empty());
+    }
+
+    #[test]
+    fn reports_unpaired_hashes_bad_bins_and_unknown_architectures() {
+        let manifest = json!({
+            "version": "1.0.0",
+            "url": ["https://example.com/a.zip", "https://example.com/b.zip"],
+            "hash": "abc",
+            "bin": [[""], 3],
+            "architecture": { "x64": { "url": "https://example.com/c.zip" } }
+        });
+
+        let issues = check_manifest_structure(&manifest);
+
+        assert_eq!(
+            fields(&issues, IssueSeverity::Error),
+            vec!["hash", "bin[0]", "bin[1]", "architecture.x64"]
+        );
+        assert_eq!(
+            fields(&issues, IssueSeverity::Warning),
+            vec![
+                "description",
+                "homepage",
+                "license",
+                "architecture.x64.hash"
+            ]
+        );
+    }
+
+    #[test]
+    fn requires_version_and_url() {
+        let issues = check_manifest_structure(&json!({ "description": "Example" }));
+
+        assert_eq!(
+            fields(&issues, IssueSeverity::Error),
+            vec!["version", "url"]
+        );
+    }
+}
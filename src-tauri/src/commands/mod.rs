@@ -4,6 +4,7 @@ pub mod bucket;
 pub mod bucket_install;
 pub mod bucket_parser;
 pub mod bucket_search;
+pub mod cache_rebuild;
 pub mod crypto;
 pub mod debug;
 pub mod doctor;
@@ -14,12 +15,21 @@ pub mod install;
 pub mod installed;
 pub mod linker;
 pub mod manifest;
+pub mod network;
+pub mod operation_queue;
+pub mod package_hash;
 pub mod package_icon;
+pub mod package_link;
 pub mod package_mutation;
+pub mod package_notes;
+pub mod persist;
 pub mod powershell;
 pub mod process_control;
 pub mod scoop;
+pub mod scoop_alias;
+pub mod scoop_migration;
 pub mod scoop_update_runner;
+pub mod scoopfile;
 pub mod search;
 pub mod self_update;
 pub mod settings;
@@ -0,0 +1,84 @@
+//! Commands for inspecting the current network connection.
+#[cfg(windows)]
+use std::os::windows::process::CommandExt;
+use tauri::{AppHandle, Runtime};
+
+/// Setting that restricts bucket search to locally cloned buckets and skips network calls.
+pub const OFFLINE_MODE_KEY: &str = "buckets.offline";
+
+/// Returns whether offline mode is enabled in settings.
+pub fn is_offline_mode<R: Runtime>(app: &AppHandle<R>) -> bool {
+    crate::commands::settings::get_config_value(app.clone(), OFFLINE_MODE_KEY.to_string())
+        .ok()
+        .flatten()
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+/// Returns whether offline mode is enabled, so the UI can reflect it.
+#[tauri::command]
+pub fn get_offline_mode<R: Runtime>(app: AppHandle<R>) -> bool {
+    is_offline_mode(&app)
+}
+
+/// Queries the WinRT network cost API for the current internet connection profile.
+///
+/// Windows PowerShell is used on purpose: PowerShell Core cannot load WinRT types
+/// through the `ContentType=WindowsRuntime` syntax.
+#[cfg(windows)]
+async fn query_network_cost_type() -> Result<String, String> {
+    let script = "[void][Windows.Networking.Connectivity.NetworkInformation, Windows.Networking.Connectivity, ContentType=WindowsRuntime]; \
+        $profile = [Windows.Networking.Connectivity.NetworkInformation]::GetInternetConnectionProfile(); \
+        if ($null -eq $profile) { 'Unknown' } else { $profile.GetConnectionCost().NetworkCostType.ToString() }";
+
+    let mut cmd = tokio::process::Command::new("powershell");
+    cmd.args(["-NoProfile", "-Command", script])
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped());
+    // Prevents a console window from appearing on Windows.
+    cmd.creation_flags(0x0800_0000); // CREATE_NO_WINDOW
+
+    let output = cmd
+        .output()
+        .await
+        .map_err(|e| format!("Failed to query network cost: {}", e))?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Returns whether a network cost type reported by Windows is metered.
+///
+/// `Fixed` and `Variable` plans are billed by usage; `Unrestricted` and `Unknown` are not.
+#[cfg(windows)]
+fn is_metered_cost_type(cost_type: &str) -> bool {
+    cost_type.eq_ignore_ascii_case("fixed") || cost_type.eq_ignore_ascii_case("variable")
+}
+
+/// Checks whether the active internet connection is metered.
+///
+/// Returns `false` when the cost cannot be determined so callers never block on it.
+#[tauri::command]
+pub async fn is_metered_connection() -> bool {
+    #[cfg(windows)]
+    {
+        match query_network_cost_type().await {
+            Ok(cost_type) => {
+                log::debug!("Network cost type: {}", cost_type);
+                is_metered_cost_type(&cost_type)
+            }
+            Err(e) => {
+                log::warn!("Failed to determine network cost type: {}", e);
+                false
+            }
+        }
+    }
+
+    #[cfg(not(windows))]
+    {
+        false
+    }
+}
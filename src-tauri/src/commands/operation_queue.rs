@@ -0,0 +1,106 @@
+//! Serializes mutating Scoop operations so concurrent requests don't fight over Scoop's locks.
+//!
+//! Install, update, uninstall and bucket operations wait for their turn here and run one at a
+//! time in arrival order. Read-only commands such as search and info never go through the queue.
+use crate::commands::powershell::take_cancel_requested;
+use crate::error::AppError;
+use crate::state::AppState;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+use tokio::time::{sleep, Duration};
+
+pub const EVENT_QUEUED: &str = "operation-queued";
+
+/// Payload of the `operation-queued` event.
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct QueuedOperation {
+    pub operation_id: String,
+    /// Number of operations ahead of this one; `0` means it is about to start.
+    pub position: usize,
+}
+
+/// Holds an operation's slot at the front of the queue.
+///
+/// Dropping it lets the next queued operation start.
+pub struct OperationTurn<R: Runtime> {
+    app: AppHandle<R>,
+    operation_id: String,
+}
+
+impl<R: Runtime> Drop for OperationTurn<R> {
+    fn drop(&mut self) {
+        leave_queue(&self.app, &self.operation_id);
+    }
+}
+
+/// Waits until the operation reaches the front of the queue.
+///
+/// Emits `operation-queued` while the operation has to wait. Returns an error if the
+/// operation is cancelled before it gets to run; the caller must not start it then.
+pub async fn wait_for_turn<R: Runtime>(
+    app: &AppHandle<R>,
+    operation_id: &str,
+    operation_name: &str,
+) -> Result<OperationTurn<R>, AppError> {
+    let state = app.state::<AppState>();
+    let position = state.operation_queue.enqueue(operation_id);
+
+    if position > 0 {
+        log::info!(
+            "[{}] Queued behind {} operation(s): {}",
+            operation_id,
+            position,
+            operation_name
+        );
+        emit_queued(app, operation_id, position);
+    }
+
+    loop {
+        if state.operation_queue.is_next(operation_id) {
+            return Ok(OperationTurn {
+                app: app.clone(),
+                operation_id: operation_id.to_string(),
+            });
+        }
+
+        if take_cancel_requested(operation_id) {
+            log::info!("[{}] Cancelled while queued", operation_id);
+            leave_queue(app, operation_id);
+            return Err(AppError::Cancelled(format!(
+                "{} cancelled while queued",
+                operation_name
+            )));
+        }
+
+        sleep(Duration::from_millis(50)).await;
+    }
+}
+
+/// Removes an operation from the queue and re-announces the positions of those still waiting.
+fn leave_queue<R: Runtime>(app: &AppHandle<R>, operation_id: &str) {
+    let state = app.state::<AppState>();
+    if !state.operation_queue.remove(operation_id) {
+        return;
+    }
+
+    for (position, id) in state.operation_queue.snapshot().into_iter().enumerate() {
+        emit_queued(app, &id, position);
+    }
+}
+
+fn emit_queued<R: Runtime>(app: &AppHandle<R>, operation_id: &str, position: usize) {
+    let payload = QueuedOperation {
+        operation_id: operation_id.to_string(),
+        position,
+    };
+    if let Err(e) = app.emit(EVENT_QUEUED, payload) {
+        log::warn!("Failed to emit {} event: {}", EVENT_QUEUED, e);
+    }
+}
+
+/// Returns the ids of queued operations in execution order; the first one is running.
+#[tauri::command]
+pub fn get_operation_queue(state: tauri::State<'_, AppState>) -> Vec<String> {
+    state.operation_queue.snapshot()
+}
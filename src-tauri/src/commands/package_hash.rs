@@ -0,0 +1,386 @@
+//! Command for verifying cached installers against the hashes in their manifest.
+use crate::commands::manifest::read_manifest_json;
+use crate::state::AppState;
+use crate::utils;
+use md5::Md5;
+use serde::Serialize;
+use serde_json::Value;
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha512};
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+/// Result of checking one cached download against its expected hash.
+#[derive(Serialize, Debug, Clone)]
+pub struct HashFileResult {
+    pub url: String,
+    pub file: Option<String>,
+    pub algorithm: String,
+    pub expected: String,
+    pub actual: Option<String>,
+    pub matches: bool,
+    /// Why the file could not be verified, if it couldn't.
+    pub error: Option<String>,
+}
+
+/// Result of `verify_package_hash`. `matches` is true only if every file matched.
+#[derive(Serialize, Debug, Clone)]
+pub struct HashVerifyResult {
+    pub name: String,
+    pub version: String,
+    pub matches: bool,
+    pub files: Vec<HashFileResult>,
+}
+
+fn string_or_array(value: Option<&Value>) -> Vec<String> {
+    match value {
+        Some(Value::String(s)) => vec![s.clone()],
+        Some(Value::Array(items)) => items
+            .iter()
+            .filter_map(|v| v.as_str().map(str::to_string))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+pub(crate) fn current_architecture() -> &'static str {
+    match std::env::consts::ARCH {
+        "x86" => "32bit",
+        "aarch64" => "arm64",
+        _ => "64bit",
+    }
+}
+
+/// Returns a string-or-array manifest field for the current architecture,
+/// falling back to the top-level field like Scoop does.
+pub(crate) fn manifest_arch_field(manifest: &Value, name: &str) -> Vec<String> {
+    let arch = manifest
+        .get("architecture")
+        .and_then(|a| a.get(current_architecture()));
+    let arch_value = string_or_array(arch.and_then(|a| a.get(name)));
+    if arch_value.is_empty() {
+        string_or_array(manifest.get(name))
+    } else {
+        arch_value
+    }
+}
+
+/// Returns the `(url, hash)` pairs for the current architecture.
+fn manifest_downloads(manifest: &Value) -> Vec<(String, String)> {
+    manifest_arch_field(manifest, "url")
+        .into_iter()
+        .zip(manifest_arch_field(manifest, "hash"))
+        .collect()
+}
+
+/// Splits a manifest hash into its algorithm and lowercase digest.
+/// Hashes without a prefix are SHA-256.
+fn parse_manifest_hash(hash: &str) -> (String, String) {
+    match hash.split_once(':') {
+        Some((algorithm, digest)) => (algorithm.to_lowercase(), digest.trim().to_lowercase()),
+        None => ("sha256".to_string(), hash.trim().to_lowercase()),
+    }
+}
+
+/// Candidate cache file names for a download, covering both the current
+/// (`<sha256(url)[..7]><ext>`) and the legacy (underscored url) naming.
+fn cache_file_names(name: &str, version: &str, url: &str) -> Vec<String> {
+    let url_hash: String = Sha256::digest(url.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    let file_part = url.rsplit(['/', '\\']).next().unwrap_or_default();
+    let extension = file_part
+        .rfind('.')
+        .map(|idx| &file_part[idx..])
+        .unwrap_or_default();
+
+    let mut underscored = String::new();
+    let mut in_run = false;
+    for c in url.chars() {
+        if c.is_alphanumeric() || matches!(c, '_' | '.' | '-') {
+            underscored.push(c);
+            in_run = false;
+        } else if !in_run {
+            underscored.push('_');
+            in_run = true;
+        }
+    }
+
+    vec![
+        format!("{}#{}#{}{}", name, version, &url_hash[..7], extension),
+        format!("{}#{}#{}", name, version, underscored),
+    ]
+}
+
+fn verify_download(
+    cache_dir: &Path,
+    name: &str,
+    version: &str,
+    url: &str,
+    hash: &str,
+) -> HashFileResult {
+    let (algorithm, expected) = parse_manifest_hash(hash);
+    let file: Option<PathBuf> = cache_file_names(name, version, url)
+        .into_iter()
+        .map(|file_name| cache_dir.join(file_name))
+        .find(|path| path.is_file());
+
+    let mut result = HashFileResult {
+        url: url.to_string(),
+        file: file.as_ref().map(|p| p.to_string_lossy().to_string()),
+        algorithm: algorithm.clone(),
+        expected: expected.clone(),
+        actual: None,
+        matches: false,
+        error: None,
+    };
+
+    let Some(file) = file else {
+        result.error = Some("No cached download found".to_string());
+        return result;
+    };
+
+    let actual = match algorithm.as_str() {
+        "sha256" => utils::file_digest_hex::<Sha256>(&file),
+        "sha512" => utils::file_digest_hex::<Sha512>(&file),
+        "sha1" => utils::file_digest_hex::<Sha1>(&file),
+        "md5" => utils::file_digest_hex::<Md5>(&file),
+        other => Err(format!("Unsupported hash algorithm '{}'", other)),
+    };
+
+    match actual {
+        Ok(actual) => {
+            result.matches = actual == expected;
+            result.actual = Some(actual);
+        }
+        Err(e) => result.error = Some(e),
+    }
+    result
+}
+
+/// Picks the manifest describing `version`: the installed copy when present,
+/// otherwise the bucket manifest if it is for the requested version.
+fn resolve_manifest(scoop_dir: &Path, name: &str, version: Option<&str>) -> Result<Value, String> {
+    let installed_manifest = match version {
+        Some(version) => scoop_dir
+            .join("apps")
+            .join(name)
+            .join(version)
+            .join("manifest.json"),
+        None => scoop_dir
+            .join("apps")
+            .join(name)
+            .join("current")
+            .join("manifest.json"),
+    };
+    if installed_manifest.is_file() {
+        return read_manifest_json(&installed_manifest);
+    }
+
+    let (bucket_manifest, _) = utils::locate_package_manifest(scoop_dir, name, None)?;
+    let manifest = read_manifest_json(&bucket_manifest)?;
+    match version {
+        Some(version) if manifest.get("version").and_then(Value::as_str) != Some(version) => Err(
+            format!("No manifest found for {} version {}", name, version),
+        ),
+        _ => Ok(manifest),
+    }
+}
+
+/// Verifies the cached installer(s) of a package against the manifest hashes.
+///
+/// `version` defaults to the installed version, or the bucket version when the
+/// package isn't installed. Every algorithm Scoop accepts is supported:
+/// SHA-256, SHA-512, SHA-1 and MD5.
+#[tauri::command]
+pub async fn verify_package_hash(
+    state: State<'_, AppState>,
+    name: String,
+    version: Option<String>,
+) -> Result<HashVerifyResult, String> {
+    let scoop_dir = state.scoop_path();
+
+    tokio::task::spawn_blocking(move || {
+        let version = version.filter(|v| !v.trim().is_empty());
+        let manifest = resolve_manifest(&scoop_dir, &name, version.as_deref())?;
+        let version = manifest
+            .get("version")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .or(version)
+            .ok_or_else(|| format!("Manifest for '{}' has no version", name))?;
+
+        let downloads = manifest_downloads(&manifest);
+        if downloads.is_empty() {
+            return Err(format!(
+                "Manifest for '{}' declares no hashed downloads",
+                name
+            ));
+        }
+
+        let (cache_dir, _) = crate::commands::settings::resolve_scoop_cache_dir(&scoop_dir);
+        let files: Vec<HashFileResult> = downloads
+            .iter()
+            .map(|(url, hash)| verify_download(&cache_dir, &name, &version, url, hash))
+            .collect();
+
+        log::info!(
+            "Verified cached downloads for {}@{}: {}/{} matched",
+            name,
+            version,
+            files.iter().filter(|f| f.matches).count(),
+            files.len()
+        );
+
+        Ok(HashVerifyResult {
+            matches: files.iter().all(|f| f.matches),
+            name,
+            version,
+            files,
+        })
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Outcome of checking one installed package in `audit_installed_integrity`.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum IntegrityStatus {
+    Match,
+    Mismatch,
+    /// No cached installer, no hashes or an unsupported hash algorithm.
+    Unknown,
+}
+
+/// Integrity of one installed package's cached installer(s).
+#[derive(Serialize, Debug, Clone)]
+pub struct IntegrityAuditEntry {
+    pub name: String,
+    pub version: String,
+    pub status: IntegrityStatus,
+    pub files: Vec<HashFileResult>,
+}
+
+fn integrity_status(files: &[HashFileResult]) -> IntegrityStatus {
+    if files.iter().any(|f| f.actual.is_some() && !f.matches) {
+        IntegrityStatus::Mismatch
+    } else if !files.is_empty() && files.iter().all(|f| f.matches) {
+        IntegrityStatus::Match
+    } else {
+        IntegrityStatus::Unknown
+    }
+}
+
+/// Picks the hashes an installed version is checked against: the bucket
+/// manifest when it still describes that version, otherwise the copy Scoop
+/// kept at install time.
+fn audit_manifest(scoop_dir: &Path, name: &str, installed: Value, version: &str) -> Value {
+    utils::locate_package_manifest(scoop_dir, name, None)
+        .and_then(|(path, _)| read_manifest_json(&path))
+        .ok()
+        .filter(|bucket| bucket.get("version").and_then(Value::as_str) == Some(version))
+        .unwrap_or(installed)
+}
+
+/// Verifies the cached installers of every installed package against the
+/// hashes in their manifests.
+///
+/// Hashing every cached installer is slow, so this only runs when asked for.
+/// Packages whose installer is no longer cached are reported as `unknown`.
+#[tauri::command]
+pub async fn audit_installed_integrity(
+    state: State<'_, AppState>,
+) -> Result<Vec<IntegrityAuditEntry>, String> {
+    use rayon::prelude::*;
+
+    let scoop_dir = state.scoop_path();
+
+    tokio::task::spawn_blocking(move || {
+        let (cache_dir, _) = crate::commands::settings::resolve_scoop_cache_dir(&scoop_dir);
+        let app_dirs = crate::commands::installed::read_app_dirs(&scoop_dir.join("apps"))?;
+
+        let mut entries: Vec<IntegrityAuditEntry> = app_dirs
+            .par_iter()
+            .filter_map(|package_path| {
+                let name = package_path.file_name()?.to_string_lossy().to_string();
+                if name.eq_ignore_ascii_case("scoop") {
+                    return None;
+                }
+                let installed = crate::commands::installed::read_installed_manifest(package_path)?;
+                let version = installed
+                    .get("version")
+                    .and_then(Value::as_str)?
+                    .to_string();
+                let manifest = audit_manifest(&scoop_dir, &name, installed, &version);
+
+                let files: Vec<HashFileResult> = manifest_downloads(&manifest)
+                    .iter()
+                    .map(|(url, hash)| verify_download(&cache_dir, &name, &version, url, hash))
+                    .collect();
+                Some(IntegrityAuditEntry {
+                    status: integrity_status(&files),
+                    name,
+                    version,
+                    files,
+                })
+            })
+            .collect();
+        entries.sort_by_key(|entry| entry.name.to_lowercase());
+
+        let count = |status: IntegrityStatus| entries.iter().filter(|e| e.status == status).count();
+        log::info!(
+            "Integrity audit of {} packages: {} matched, {} mismatched, {} unknown",
+            entries.len(),
+            count(IntegrityStatus::Match),
+            count(IntegrityStatus::Mismatch),
+            count(IntegrityStatus::Unknown)
+        );
+        Ok(entries)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cache_file_names, verify_download};
+    use std::fs;
+
+    const URL: &str = "https://example.com/tool.zip";
+
+    fn verify(hash: &str) -> super::HashFileResult {
+        let dir = tempfile::tempdir().unwrap();
+        let file_name = &cache_file_names("tool", "1.0", URL)[0];
+        fs::write(dir.path().join(file_name), b"abc").unwrap();
+        verify_download(dir.path(), "tool", "1.0", URL, hash)
+    }
+
+    #[test]
+    fn verifies_every_scoop_hash_algorithm() {
+        for hash in [
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            "sha512:ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+             2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+            "sha1:A9993E364706816ABA3E25717850C26C9CD0D89D",
+            "md5:900150983cd24fb0d6963f7d28e17f72",
+        ] {
+            let result = verify(hash);
+            assert!(result.matches, "{} did not match: {:?}", hash, result);
+            assert!(result.error.is_none());
+        }
+    }
+
+    #[test]
+    fn reports_mismatched_md5_and_sha1() {
+        for hash in [
+            "sha1:0000000000000000000000000000000000000000",
+            "md5:00000000000000000000000000000000",
+        ] {
+            let result = verify(hash);
+            assert!(!result.matches);
+            assert!(result.actual.is_some());
+        }
+    }
+}
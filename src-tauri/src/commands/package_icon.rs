@@ -954,3 +954,169 @@ pub async fn get_installed_package_icons<R: Runtime>(
 
     Ok(result)
 }
+
+const FAVICON_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+const FAVICON_MAX_BYTES: usize = 256 * 1024;
+/// How long a domain without a usable favicon is skipped before retrying.
+const FAVICON_MISS_TTL_SECS: u64 = 24 * 60 * 60;
+
+fn unix_now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Returns whether a fetch for this domain failed within the miss TTL.
+///
+/// The marker stores the time of the miss rather than relying on its mtime,
+/// which rewriting an existing file doesn't reliably update on every platform.
+fn is_recent_favicon_miss(marker_path: &Path) -> bool {
+    fs::read_to_string(marker_path)
+        .ok()
+        .and_then(|contents| contents.trim().parse::<u64>().ok())
+        .is_some_and(|missed_at| unix_now_secs().saturating_sub(missed_at) < FAVICON_MISS_TTL_SECS)
+}
+
+fn record_favicon_miss(marker_path: &Path) {
+    if let Some(parent) = marker_path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    if let Err(e) = fs::write(marker_path, unix_now_secs().to_string()) {
+        log::debug!(
+            "Failed to record favicon miss {}: {}",
+            marker_path.display(),
+            e
+        );
+    }
+}
+
+/// Guesses the image type of a favicon from its leading bytes.
+fn sniff_favicon_mime(bytes: &[u8]) -> Option<&'static str> {
+    if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("image/png")
+    } else if bytes.starts_with(&[0, 0, 1, 0]) {
+        Some("image/x-icon")
+    } else if bytes.starts_with(b"GIF8") {
+        Some("image/gif")
+    } else if bytes.starts_with(&[0xff, 0xd8, 0xff]) {
+        Some("image/jpeg")
+    } else if bytes.trim_ascii_start().starts_with(b"<svg")
+        || bytes.trim_ascii_start().starts_with(b"<?xml")
+    {
+        Some("image/svg+xml")
+    } else {
+        None
+    }
+}
+
+fn favicon_data_url(bytes: &[u8]) -> Option<String> {
+    let mime = sniff_favicon_mime(bytes)?;
+    Some(format!(
+        "data:{};base64,{}",
+        mime,
+        BASE64_STANDARD.encode(bytes)
+    ))
+}
+
+async fn fetch_favicon(origin: &url::Url) -> Option<Vec<u8>> {
+    let favicon_url = origin.join("/favicon.ico").ok()?;
+    let client = reqwest::Client::builder()
+        .timeout(FAVICON_TIMEOUT)
+        .user_agent("Pailer")
+        .build()
+        .ok()?;
+    let mut response = client
+        .get(favicon_url.clone())
+        .send()
+        .await
+        .ok()?
+        .error_for_status()
+        .ok()?;
+    if response
+        .content_length()
+        .is_some_and(|len| len > FAVICON_MAX_BYTES as u64)
+    {
+        return None;
+    }
+
+    let mut bytes = Vec::new();
+    while let Some(chunk) = response.chunk().await.ok()? {
+        bytes.extend_from_slice(&chunk);
+        if bytes.len() > FAVICON_MAX_BYTES {
+            log::debug!("Favicon at {} exceeds the size cap", favicon_url);
+            return None;
+        }
+    }
+    Some(bytes)
+}
+
+/// Returns the favicon of a package's homepage as a data URL.
+///
+/// Favicons are cached on disk per domain, so packages sharing a homepage are
+/// fetched once. Domains without a usable favicon get a `.missing` marker and
+/// are not retried for a day. Missing homepages, network failures and offline
+/// mode all yield `None`; the package list doesn't depend on this.
+#[tauri::command]
+pub async fn get_package_icon<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+    name: String,
+) -> Result<Option<String>, String> {
+    let Ok((manifest_path, _)) =
+        crate::utils::locate_package_manifest(&state.scoop_path(), &name, None)
+    else {
+        return Ok(None);
+    };
+    let homepage = crate::commands::manifest::read_manifest_json(&manifest_path)
+        .ok()
+        .and_then(|manifest| {
+            manifest
+                .get("homepage")
+                .and_then(|homepage| homepage.as_str())
+                .map(str::to_string)
+        });
+    let Some(origin) = homepage
+        .and_then(|homepage| url::Url::parse(&homepage).ok())
+        .filter(|url| matches!(url.scheme(), "http" | "https") && url.host_str().is_some())
+    else {
+        return Ok(None);
+    };
+    let domain = origin.host_str().unwrap_or_default().to_lowercase();
+
+    let favicon_dir = get_package_icon_cache_dir(&app)?.join("favicons");
+    let cache_key = normalize_cache_key(&domain);
+    let cache_path = favicon_dir.join(&cache_key);
+    let miss_path = favicon_dir.join(format!("{}.missing", cache_key));
+    if let Ok(bytes) = fs::read(&cache_path) {
+        if let Some(data_url) = favicon_data_url(&bytes) {
+            return Ok(Some(data_url));
+        }
+    }
+    if is_recent_favicon_miss(&miss_path) {
+        return Ok(None);
+    }
+
+    if crate::commands::network::is_offline_mode(&app) {
+        return Ok(None);
+    }
+    let Some(bytes) = fetch_favicon(&origin).await else {
+        log::debug!("No favicon available for {} ({})", name, domain);
+        record_favicon_miss(&miss_path);
+        return Ok(None);
+    };
+    let Some(data_url) = favicon_data_url(&bytes) else {
+        log::debug!("Favicon of {} is not a recognized image", domain);
+        record_favicon_miss(&miss_path);
+        return Ok(None);
+    };
+
+    if let Some(parent) = cache_path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    if let Err(e) = fs::write(&cache_path, &bytes) {
+        log::warn!("Failed to cache favicon of {}: {}", domain, e);
+    }
+    let _ = fs::remove_file(&miss_path);
+    Ok(Some(data_url))
+}
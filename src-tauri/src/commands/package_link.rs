@@ -0,0 +1,211 @@
+//! Shareable `rscoop://install?...` links that open the install dialog for a package.
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+use url::Url;
+
+pub const PACKAGE_LINK_SCHEME: &str = "rscoop";
+pub const EVENT_PACKAGE_LINK: &str = "package-link-install";
+
+/// Link received on first launch, held until the frontend is ready to ask for it.
+static PENDING_PACKAGE_LINK: Lazy<Mutex<Option<PackageLink>>> = Lazy::new(|| Mutex::new(None));
+
+/// Package referenced by an install link.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PackageLink {
+    pub name: String,
+    pub bucket: Option<String>,
+}
+
+/// Returns whether a package or bucket name is safe to pass on to Scoop.
+fn is_valid_identifier(value: &str) -> bool {
+    !value.is_empty()
+        && value.len() <= 128
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '+'))
+        && !value.starts_with(['.', '-'])
+}
+
+/// Parses an `rscoop://install?bucket=..&name=..` link, rejecting anything malformed.
+pub fn parse_package_link(link: &str) -> Option<PackageLink> {
+    let url = Url::parse(link.trim()).ok()?;
+    if !url.scheme().eq_ignore_ascii_case(PACKAGE_LINK_SCHEME) || url.host_str() != Some("install")
+    {
+        return None;
+    }
+
+    let mut name = None;
+    let mut bucket = None;
+    for (key, value) in url.query_pairs() {
+        match key.as_ref() {
+            "name" => name = Some(value.trim().to_string()),
+            "bucket" => bucket = Some(value.trim().to_string()),
+            _ => {}
+        }
+    }
+
+    let name = name.filter(|name| is_valid_identifier(name))?;
+    let bucket = match bucket.filter(|bucket| !bucket.is_empty()) {
+        Some(bucket) if is_valid_identifier(&bucket) => Some(bucket),
+        Some(_) => return None,
+        None => None,
+    };
+
+    Some(PackageLink { name, bucket })
+}
+
+/// Handles the argv of a launch, focusing the window and forwarding the first valid link.
+///
+/// On the initial launch the frontend is not listening yet, so the link is kept for
+/// `take_pending_package_link` instead of being emitted. Links passed on to the running
+/// instance are only emitted, so a stale one is never picked up on a later reload.
+pub fn handle_package_link_args<R: Runtime>(
+    app: &AppHandle<R>,
+    argv: &[String],
+    initial_launch: bool,
+) {
+    let Some(link) = argv.iter().find_map(|arg| parse_package_link(arg)) else {
+        let scheme_prefix = format!("{}:", PACKAGE_LINK_SCHEME);
+        if argv
+            .iter()
+            .any(|arg| arg.to_ascii_lowercase().starts_with(&scheme_prefix))
+        {
+            log::warn!("Ignoring malformed package link in launch arguments");
+        }
+        return;
+    };
+
+    log::info!(
+        "Received package link for '{}' (bucket: {})",
+        link.name,
+        link.bucket.as_deref().unwrap_or("default")
+    );
+
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.show();
+        let _ = window.unminimize();
+        let _ = window.set_focus();
+    }
+
+    if initial_launch {
+        if let Ok(mut pending) = PENDING_PACKAGE_LINK.lock() {
+            *pending = Some(link);
+        }
+        return;
+    }
+
+    if let Err(e) = app.emit(EVENT_PACKAGE_LINK, link) {
+        log::warn!("Failed to emit {} event: {}", EVENT_PACKAGE_LINK, e);
+    }
+}
+
+/// Builds a shareable link that opens the install dialog for a package.
+#[tauri::command]
+pub fn generate_package_link(name: String, bucket: Option<String>) -> Result<String, String> {
+    let name = name.trim();
+    if !is_valid_identifier(name) {
+        return Err(format!("Invalid package name: {}", name));
+    }
+
+    let bucket = bucket
+        .as_deref()
+        .map(str::trim)
+        .filter(|bucket| !bucket.is_empty() && !bucket.eq_ignore_ascii_case("none"));
+    if let Some(bucket) = bucket {
+        if !is_valid_identifier(bucket) {
+            return Err(format!("Invalid bucket name: {}", bucket));
+        }
+    }
+
+    let mut url = Url::parse(&format!("{}://install", PACKAGE_LINK_SCHEME))
+        .map_err(|e| format!("Failed to build package link: {}", e))?;
+    {
+        let mut query = url.query_pairs_mut();
+        if let Some(bucket) = bucket {
+            query.append_pair("bucket", bucket);
+        }
+        query.append_pair("name", name);
+    }
+
+    Ok(url.to_string())
+}
+
+/// Returns and clears the link the app was launched with, if any.
+#[tauri::command]
+pub fn take_pending_package_link() -> Option<PackageLink> {
+    PENDING_PACKAGE_LINK
+        .lock()
+        .ok()
+        .and_then(|mut pending| pending.take())
+}
+
+/// Registers the `rscoop://` scheme for the current user so links launch this executable.
+#[cfg(windows)]
+pub fn register_package_link_scheme() -> Result<(), String> {
+    use winreg::{enums::HKEY_CURRENT_USER, RegKey};
+
+    let exe_path =
+        std::env::current_exe().map_err(|e| format!("Failed to resolve executable path: {}", e))?;
+    let hkcu = RegKey::predef(HKEY_CURRENT_USER);
+    let key_path = format!("Software\\Classes\\{}", PACKAGE_LINK_SCHEME);
+
+    let (scheme_key, _) = hkcu
+        .create_subkey(&key_path)
+        .map_err(|e| format!("Failed to create {}: {}", key_path, e))?;
+    scheme_key
+        .set_value("", &"URL:Pailer package link")
+        .and_then(|_| scheme_key.set_value("URL Protocol", &""))
+        .map_err(|e| format!("Failed to write {}: {}", key_path, e))?;
+
+    let (command_key, _) = scheme_key
+        .create_subkey("shell\\open\\command")
+        .map_err(|e| format!("Failed to create {} command key: {}", key_path, e))?;
+    command_key
+        .set_value("", &format!("\"{}\" \"%1\"", exe_path.display()))
+        .map_err(|e| format!("Failed to write {} command: {}", key_path, e))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_links_round_trip() {
+        let link = generate_package_link("7zip".to_string(), Some("main".to_string())).unwrap();
+        assert_eq!(link, "rscoop://install?bucket=main&name=7zip");
+        assert_eq!(
+            parse_package_link(&link),
+            Some(PackageLink {
+                name: "7zip".to_string(),
+                bucket: Some("main".to_string()),
+            })
+        );
+
+        let link = generate_package_link("git".to_string(), None).unwrap();
+        assert_eq!(
+            parse_package_link(&link),
+            Some(PackageLink {
+                name: "git".to_string(),
+                bucket: None,
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_unsafe_or_foreign_links() {
+        assert_eq!(parse_package_link("https://install?name=git"), None);
+        assert_eq!(parse_package_link("rscoop://uninstall?name=git"), None);
+        assert_eq!(parse_package_link("rscoop://install?bucket=main"), None);
+        assert_eq!(parse_package_link("rscoop://install?name=git;calc"), None);
+        assert_eq!(
+            parse_package_link("rscoop://install?name=git&bucket=..%5Cevil"),
+            None
+        );
+        assert!(generate_package_link("foo bar".to_string(), None).is_err());
+    }
+}
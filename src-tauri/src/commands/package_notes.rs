@@ -0,0 +1,93 @@
+//! Commands for the user's own notes on installed packages.
+//!
+//! Notes live in the settings store, keyed by lowercased package name, so they
+//! are independent of Scoop and survive reinstalls.
+use crate::commands::settings::{get_config_value, set_config_value};
+use crate::models::ScoopPackage;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use tauri::{AppHandle, Runtime};
+
+/// Settings store key holding the notes map.
+pub(crate) const PACKAGE_NOTES_KEY: &str = "packageNotes";
+
+fn read_notes_map<R: Runtime>(app: &AppHandle<R>) -> Map<String, Value> {
+    get_config_value(app.clone(), PACKAGE_NOTES_KEY.to_string())
+        .ok()
+        .flatten()
+        .and_then(|value| value.as_object().cloned())
+        .unwrap_or_default()
+}
+
+/// Fills in `user_note` for the packages that have a stored note.
+pub(crate) fn apply_package_notes<R: Runtime>(app: &AppHandle<R>, packages: &mut [ScoopPackage]) {
+    merge_package_notes(read_notes_map(app), packages);
+}
+
+fn merge_package_notes(notes: Map<String, Value>, packages: &mut [ScoopPackage]) {
+    let notes: HashMap<String, String> = notes
+        .into_iter()
+        .filter_map(|(name, note)| Some((name, note.as_str()?.to_string())))
+        .collect();
+    for package in packages {
+        package.user_note = notes.get(&package.name.to_lowercase()).cloned();
+    }
+}
+
+/// Stores a note for a package; an empty note removes it.
+#[tauri::command]
+pub fn set_package_note(app: AppHandle, name: String, note: String) -> Result<(), String> {
+    let name = name.trim().to_lowercase();
+    if name.is_empty() {
+        return Err("A package name is required.".to_string());
+    }
+
+    let mut notes = read_notes_map(&app);
+    let note = note.trim();
+    if note.is_empty() {
+        notes.remove(&name);
+    } else {
+        notes.insert(name, Value::String(note.to_string()));
+    }
+    set_config_value(app, PACKAGE_NOTES_KEY.to_string(), Value::Object(notes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::merge_package_notes;
+    use crate::models::ScoopPackage;
+    use serde_json::{json, Value};
+
+    fn package(name: &str) -> ScoopPackage {
+        ScoopPackage {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn rescanned_packages_get_their_notes_back() {
+        // A refresh rebuilds packages from disk, so none carry a note yet
+        let mut packages = vec![package("Git"), package("7zip")];
+        let Value::Object(notes) = json!({ "git": "pinned for work", "7zip": 1 }) else {
+            unreachable!();
+        };
+
+        merge_package_notes(notes, &mut packages);
+
+        assert_eq!(packages[0].user_note.as_deref(), Some("pinned for work"));
+        assert_eq!(packages[1].user_note, None);
+    }
+
+    #[test]
+    fn removed_notes_are_cleared() {
+        let mut packages = vec![ScoopPackage {
+            user_note: Some("stale".to_string()),
+            ..package("git")
+        }];
+
+        merge_package_notes(Default::default(), &mut packages);
+
+        assert_eq!(packages[0].user_note, None);
+    }
+}
@@ -0,0 +1,260 @@
+//! Commands for backing up and restoring a package's persisted data.
+//!
+//! Scoop keeps files that survive updates and reinstalls in `persist/<name>`,
+//! linking them into the app directory. Snapshotting that folder before a risky
+//! update gives a way back for stateful apps.
+use crate::state::AppState;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use tauri::State;
+use zip::write::SimpleFileOptions;
+
+fn persist_dir(scoop_dir: &Path, name: &str) -> Result<PathBuf, String> {
+    let name = name.trim();
+    if name.is_empty() || name.contains(['/', '\\']) || name == "." || name == ".." {
+        return Err(format!("Invalid package name '{}'", name));
+    }
+    Ok(scoop_dir.join("persist").join(name))
+}
+
+/// Adds the files below `dir` to the archive with paths relative to `root`.
+///
+/// Files are streamed into the archive, so large persisted data such as
+/// databases isn't loaded into memory.
+fn add_dir_to_zip<W: Write + std::io::Seek>(
+    zip: &mut zip::ZipWriter<W>,
+    root: &Path,
+    dir: &Path,
+    options: SimpleFileOptions,
+) -> Result<(), String> {
+    let entries =
+        fs::read_dir(dir).map_err(|e| format!("Failed to read {}: {}", dir.display(), e))?;
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let relative = path
+            .strip_prefix(root)
+            .map_err(|e| e.to_string())?
+            .to_string_lossy()
+            .replace('\\', "/");
+
+        if path.is_dir() {
+            zip.add_directory(format!("{}/", relative), options)
+                .map_err(|e| format!("Failed to add {}: {}", relative, e))?;
+            add_dir_to_zip(zip, root, &path, options)?;
+        } else {
+            let mut file = fs::File::open(&path)
+                .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+            let size = file.metadata().map(|metadata| metadata.len()).unwrap_or(0);
+            zip.start_file(
+                relative.as_str(),
+                options.large_file(size >= u64::from(u32::MAX)),
+            )
+            .map_err(|e| format!("Failed to add {}: {}", relative, e))?;
+            std::io::copy(&mut file, zip)
+                .map_err(|e| format!("Failed to write {}: {}", relative, e))?;
+        }
+    }
+    Ok(())
+}
+
+/// Resolves `path` to an absolute path without requiring it to exist, following
+/// links in the part that does.
+fn resolve_path(path: &Path) -> Result<PathBuf, String> {
+    let absolute = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        std::env::current_dir()
+            .map_err(|e| format!("Failed to resolve {}: {}", path.display(), e))?
+            .join(path)
+    };
+
+    for existing in absolute.ancestors() {
+        if let Ok(resolved) = existing.canonicalize() {
+            let rest = absolute.strip_prefix(existing).map_err(|e| e.to_string())?;
+            return Ok(resolved.join(rest));
+        }
+    }
+    Ok(absolute)
+}
+
+fn backup_dir(source: &Path, name: &str, dest_path: &Path) -> Result<u64, String> {
+    if !source.is_dir() {
+        return Err(format!("'{}' has no persisted data", name));
+    }
+
+    if dest_path.is_dir() {
+        return Err(format!(
+            "Destination is a directory: {}",
+            dest_path.display()
+        ));
+    }
+    // The archive would end up zipping itself
+    if resolve_path(dest_path)?.starts_with(resolve_path(source)?) {
+        return Err(format!(
+            "Destination {} is inside the persisted data of '{}'",
+            dest_path.display(),
+            name
+        ));
+    }
+    if let Some(parent) = dest_path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create directory {}: {}", parent.display(), e))?;
+    }
+
+    let file = fs::File::create(dest_path)
+        .map_err(|e| format!("Failed to create {}: {}", dest_path.display(), e))?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+    add_dir_to_zip(&mut zip, source, source, options)?;
+    zip.finish()
+        .map_err(|e| format!("Failed to finalize {}: {}", dest_path.display(), e))?;
+
+    let bytes = fs::metadata(dest_path).map(|m| m.len()).unwrap_or(0);
+    log::info!(
+        "Backed up persisted data of {} to {} ({} bytes)",
+        name,
+        dest_path.display(),
+        bytes
+    );
+    Ok(bytes)
+}
+
+fn restore_dir(target: &Path, name: &str, archive: &Path, overwrite: bool) -> Result<u64, String> {
+    let has_data = fs::read_dir(target)
+        .map(|mut entries| entries.next().is_some())
+        .unwrap_or(false);
+    if has_data && !overwrite {
+        return Err(format!(
+            "'{}' already has persisted data that restoring would overwrite",
+            name
+        ));
+    }
+
+    let file = fs::File::open(archive)
+        .map_err(|e| format!("Failed to open {}: {}", archive.display(), e))?;
+    let mut zip = zip::ZipArchive::new(file)
+        .map_err(|e| format!("Failed to read archive {}: {}", archive.display(), e))?;
+    let mut bytes = 0;
+    for index in 0..zip.len() {
+        if let Ok(entry) = zip.by_index(index) {
+            if entry.is_file() {
+                bytes += entry.size();
+            }
+        }
+    }
+
+    fs::create_dir_all(target)
+        .map_err(|e| format!("Failed to create {}: {}", target.display(), e))?;
+    zip.extract(target)
+        .map_err(|e| format!("Failed to extract {}: {}", archive.display(), e))?;
+
+    log::info!(
+        "Restored persisted data of {} from {} ({} bytes)",
+        name,
+        archive.display(),
+        bytes
+    );
+    Ok(bytes)
+}
+
+/// Zips `persist/<name>` to `dest` and returns the size of the archive in bytes.
+///
+/// `dest` may not lie inside `persist/<name>` itself.
+#[tauri::command]
+pub async fn backup_persisted_data(
+    state: State<'_, AppState>,
+    name: String,
+    dest: String,
+) -> Result<u64, String> {
+    let source = persist_dir(&state.scoop_path(), &name)?;
+
+    tokio::task::spawn_blocking(move || backup_dir(&source, &name, Path::new(&dest)))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Extracts an archive made by `backup_persisted_data` into `persist/<name>`
+/// and returns the number of bytes written.
+///
+/// Existing persisted data is only touched when `overwrite` is set, which the
+/// frontend passes after the user confirmed. Files are rewritten in place so
+/// the links Scoop made into the app directory keep working; files missing
+/// from the archive are left as they are.
+#[tauri::command]
+pub async fn restore_persisted_data(
+    state: State<'_, AppState>,
+    name: String,
+    archive: String,
+    overwrite: bool,
+) -> Result<u64, String> {
+    let target = persist_dir(&state.scoop_path(), &name)?;
+
+    tokio::task::spawn_blocking(move || restore_dir(&target, &name, Path::new(&archive), overwrite))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{backup_dir, persist_dir, restore_dir};
+    use std::fs;
+
+    #[test]
+    fn backup_round_trips_through_restore() {
+        let temp = tempfile::tempdir().unwrap();
+        let source = persist_dir(&temp.path().join("scoop"), "app").unwrap();
+        fs::create_dir_all(source.join("data")).unwrap();
+        fs::write(source.join("config.ini"), "theme=dark").unwrap();
+        fs::write(source.join("data").join("state.db"), "rows").unwrap();
+
+        let archive = temp.path().join("backups").join("app.zip");
+        assert!(backup_dir(&source, "app", &archive).unwrap() > 0);
+
+        let target = persist_dir(&temp.path().join("restored"), "app").unwrap();
+        assert_eq!(restore_dir(&target, "app", &archive, false).unwrap(), 14);
+        assert_eq!(
+            fs::read_to_string(target.join("config.ini")).unwrap(),
+            "theme=dark"
+        );
+        assert_eq!(
+            fs::read_to_string(target.join("data").join("state.db")).unwrap(),
+            "rows"
+        );
+
+        // Existing data is only replaced when asked to, and extra files survive
+        fs::write(target.join("config.ini"), "theme=light").unwrap();
+        fs::write(target.join("local.txt"), "kept").unwrap();
+        assert!(restore_dir(&target, "app", &archive, false).is_err());
+        assert_eq!(
+            fs::read_to_string(target.join("config.ini")).unwrap(),
+            "theme=light"
+        );
+
+        restore_dir(&target, "app", &archive, true).unwrap();
+        assert_eq!(
+            fs::read_to_string(target.join("config.ini")).unwrap(),
+            "theme=dark"
+        );
+        assert_eq!(
+            fs::read_to_string(target.join("local.txt")).unwrap(),
+            "kept"
+        );
+    }
+
+    #[test]
+    fn rejects_backup_inside_the_persisted_data() {
+        let temp = tempfile::tempdir().unwrap();
+        let source = persist_dir(temp.path(), "app").unwrap();
+        fs::create_dir_all(&source).unwrap();
+        fs::write(source.join("config.ini"), "theme=dark").unwrap();
+
+        let nested = source.join("backups").join("app.zip");
+        assert!(backup_dir(&source, "app", &nested).is_err());
+        assert!(!nested.exists());
+
+        let dotted = source.join("..").join("app").join("app.zip");
+        assert!(backup_dir(&source, "app", &dotted).is_err());
+    }
+}
@@ -1,16 +1,17 @@
+use crate::error::AppError;
 use serde::Serialize;
 #[cfg(windows)]
 use std::os::windows::process::CommandExt;
 use std::process::Stdio;
-use tauri::{Emitter, Window};
+use tauri::{AppHandle, Emitter, Runtime, Window};
 use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::process::{Child, Command};
 
 use lazy_static::lazy_static;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::RwLock;
 use std::sync::{
-    atomic::{AtomicUsize, Ordering},
+    atomic::{AtomicU64, AtomicUsize, Ordering},
     Arc,
 };
 use tokio::time::{sleep, Duration};
@@ -22,7 +23,6 @@ lazy_static! {
 
 pub const EVENT_OUTPUT: &str = "operation-output";
 pub const EVENT_FINISHED: &str = "operation-finished";
-pub const EVENT_CANCEL: &str = "cancel-operation";
 
 #[tauri::command]
 pub fn request_cancel_operation(operation_id: String) -> Result<(), String> {
@@ -39,7 +39,7 @@ pub fn request_cancel_operation(operation_id: String) -> Result<(), String> {
     }
 }
 
-fn take_cancel_requested(operation_id: &str) -> bool {
+pub(crate) fn take_cancel_requested(operation_id: &str) -> bool {
     match CANCEL_REQUESTED.write() {
         Ok(mut pending) => pending.remove(operation_id),
         Err(_) => false,
@@ -55,6 +55,24 @@ async fn wait_for_cancel_request(operation_id: String) {
     }
 }
 
+/// Resolves once no output has been seen for `timeout_secs`, or never when no
+/// timeout is configured.
+async fn wait_for_inactivity_timeout(last_output_ms: Arc<AtomicU64>, timeout_secs: Option<u64>) {
+    let Some(timeout_secs) = timeout_secs else {
+        return std::future::pending().await;
+    };
+    let timeout_ms = timeout_secs.saturating_mul(1000);
+
+    loop {
+        let idle_ms =
+            crate::state::AppState::now_ms().saturating_sub(last_output_ms.load(Ordering::Relaxed));
+        if idle_ms >= timeout_ms {
+            break;
+        }
+        sleep(Duration::from_millis(timeout_ms - idle_ms)).await;
+    }
+}
+
 /// Executes a simple PowerShell command and returns its stdout output.
 /// Used for non-streaming operations like reading/writing Scoop config.
 pub async fn run_simple_command(command_str: &str) -> Result<String, String> {
@@ -130,7 +148,6 @@ pub struct StreamOutput {
 #[serde(rename_all = "kebab-case")]
 pub enum FinalStatus {
     Success,
-    #[allow(dead_code)]
     Warning,
     Error,
     Cancelled,
@@ -171,9 +188,20 @@ pub fn resolve_powershell_exe() -> String {
     }
 }
 
+#[cfg(windows)]
+const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+#[cfg(windows)]
+const BELOW_NORMAL_PRIORITY_CLASS: u32 = 0x0000_4000;
+
 /// Creates a `tokio::process::Command` for running a PowerShell command without a visible window.
 /// Prefers PowerShell Core (pwsh) if available, falls back to Windows PowerShell.
 pub fn create_powershell_command(command_str: &str) -> Command {
+    create_powershell_command_with_priority(command_str, false)
+}
+
+/// Like `create_powershell_command`, but with `low_priority` the process runs at
+/// below-normal priority so background maintenance doesn't slow down the machine.
+pub fn create_powershell_command_with_priority(command_str: &str, low_priority: bool) -> Command {
     let ps_exe = resolve_powershell_exe();
 
     let mut cmd = Command::new(&ps_exe);
@@ -194,7 +222,13 @@ pub fn create_powershell_command(command_str: &str) -> Command {
 
     // Prevents a console window from appearing on Windows.
     #[cfg(windows)]
-    cmd.creation_flags(0x0800_0000); // CREATE_NO_WINDOW
+    cmd.creation_flags(if low_priority {
+        CREATE_NO_WINDOW | BELOW_NORMAL_PRIORITY_CLASS
+    } else {
+        CREATE_NO_WINDOW
+    });
+    #[cfg(not(windows))]
+    let _ = low_priority;
 
     cmd
 }
@@ -228,15 +262,17 @@ pub fn build_scoop_update_all_command(bypass: bool) -> String {
 
 /// Builds a single-package Scoop update command that bypasses Scoop's
 /// stale-check path for this PowerShell process only.
-pub fn build_scoop_update_bypass_command(package_name: &str, force: bool) -> String {
+pub fn build_scoop_update_bypass_command(package_name: &str, force: bool, global: bool) -> String {
     let escaped_package = quote_powershell_single(package_name);
     let force_flag = if force { " -f" } else { "" };
+    let global_flag = if global { " -g" } else { "" };
 
     format!(
-        "{} & \"$scoopRoot\\libexec\\scoop-update.ps1\" '{}'{}",
+        "{} & \"$scoopRoot\\libexec\\scoop-update.ps1\" '{}'{}{}",
         scoop_update_override_preamble(),
         escaped_package,
-        force_flag
+        force_flag,
+        global_flag
     )
 }
 
@@ -265,9 +301,74 @@ pub fn is_powershell_available() -> bool {
     cmd.status().map(|status| status.success()).unwrap_or(false)
 }
 
+/// Optional behaviour of `run_and_stream_command`.
+#[derive(Clone, Default)]
+pub struct StreamOptions {
+    /// Kills the process tree if it produces no output for this many seconds.
+    pub timeout_secs: Option<u64>,
+    /// Added to the spawned process environment. Only the variable names are
+    /// logged since values may be secrets.
+    pub env: Option<HashMap<String, String>>,
+    /// Runs the process at below-normal priority.
+    pub low_priority: bool,
+}
+
+impl StreamOptions {
+    /// Passes the configured GitHub token as `GITHUB_TOKEN`, for Scoop runs that
+    /// may call the GitHub API through checkver or autoupdate.
+    pub fn with_github_token<R: Runtime>(mut self, app: &AppHandle<R>) -> Self {
+        if let Some(token) = crate::commands::settings::read_github_token(app.clone()) {
+            self.env
+                .get_or_insert_with(HashMap::new)
+                .insert("GITHUB_TOKEN".to_string(), token);
+        }
+        self
+    }
+
+    /// Creates the PowerShell command for `command_str` with these options applied.
+    pub fn command(&self, command_str: &str, operation_id: &str) -> Command {
+        let mut command = create_powershell_command_with_priority(command_str, self.low_priority);
+        if let Some(env) = self.env.as_ref().filter(|env| !env.is_empty()) {
+            let mut names: Vec<&str> = env.keys().map(String::as_str).collect();
+            names.sort_unstable();
+            log::debug!(
+                "[{}] Setting environment variables: {}",
+                operation_id,
+                names.join(", ")
+            );
+            command.envs(env);
+        }
+        command
+    }
+}
+
+impl std::fmt::Debug for StreamOptions {
+    /// Prints only the names of `env`, like the logging in `command`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let env_names = self.env.as_ref().map(|env| {
+            let mut names: Vec<&str> = env.keys().map(String::as_str).collect();
+            names.sort_unstable();
+            names
+        });
+        f.debug_struct("StreamOptions")
+            .field("timeout_secs", &self.timeout_secs)
+            .field("env", &env_names)
+            .field("low_priority", &self.low_priority)
+            .finish()
+    }
+}
+
+/// What the output readers of a running command have seen so far.
+#[derive(Clone)]
+struct OutputStats {
+    error_count: Arc<AtomicUsize>,
+    warning_count: Arc<AtomicUsize>,
+    last_output_ms: Arc<AtomicU64>,
+}
+
 /// Spawns a task to read lines from a stream (stdout or stderr) and sends them to the frontend.
 ///
-/// It also sends any lines that indicate an error to the `error_tx` channel.
+/// It also counts the lines that indicate an error or a warning.
 use tokio::io::AsyncRead;
 
 fn spawn_output_reader(
@@ -275,8 +376,7 @@ fn spawn_output_reader(
     source: &'static str,
     window: Window,
     output_event: String,
-    error_count: Arc<AtomicUsize>,
-    warning_count: Arc<AtomicUsize>,
+    stats: OutputStats,
     operation_id: String,
 ) {
     let mut reader = BufReader::new(stream).lines();
@@ -285,11 +385,14 @@ fn spawn_output_reader(
     tokio::spawn(async move {
         while let Ok(Some(line)) = reader.next_line().await {
             log::debug!("Output line [{}]: {}", source, line);
+            stats
+                .last_output_ms
+                .store(crate::state::AppState::now_ms(), Ordering::Relaxed);
 
             if contains_error_keywords(&line) {
-                error_count.fetch_add(1, Ordering::Relaxed);
+                stats.error_count.fetch_add(1, Ordering::Relaxed);
             } else if contains_warning_keywords(&line) {
-                warning_count.fetch_add(1, Ordering::Relaxed);
+                stats.warning_count.fetch_add(1, Ordering::Relaxed);
             }
 
             let _ = window
@@ -314,16 +417,19 @@ fn spawn_output_reader(
 ///
 /// - Emits `output_event` with `StreamOutput` for each line of output.
 /// - Emits `finished_event` with `CommandResult` when command completes.
-/// - Listens for `cancel_event` to terminate the process.
+/// - Terminates the process when a cancel is requested for `operation_id`.
+/// - See `StreamOptions` for the optional inactivity timeout and environment.
+/// - Fails with `AppError::Cancelled` or `AppError::Timeout` when stopped, and
+///   `AppError::ScoopCommandFailed` when the command reports a failure.
 pub async fn run_and_stream_command(
     window: Window,
     command_str: String,
     operation_name: String,
     output_event: &str,
     finished_event: &str,
-    _cancel_event: &str,
     operation_id: String,
-) -> Result<(), String> {
+    options: StreamOptions,
+) -> Result<(), AppError> {
     log::info!("[{}] Starting: {}", operation_id, operation_name);
 
     if take_cancel_requested(&operation_id) {
@@ -331,31 +437,21 @@ pub async fn run_and_stream_command(
             "[{}] Cancellation requested before command start; skipping execution",
             operation_id
         );
-        if let Err(e) = window.emit(
+        return Err(AppError::Cancelled(emit_cancelled_before_start(
+            &window,
             finished_event,
-            CommandResult {
-                success: false,
-                operation_name: operation_name.clone(),
-                error_count: Some(0),
-                warning_count: Some(0),
-                final_status: FinalStatus::Cancelled,
-                operation_id: operation_id.clone(),
-                timestamp: std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .unwrap()
-                    .as_millis() as u64,
-            },
-        ) {
-            log::error!("Failed to emit pre-start cancellation event: {}", e);
-        }
-        return Err(format!("{} cancelled by user", operation_name));
+            &operation_name,
+            &operation_id,
+        )));
     }
 
-    let mut child = create_powershell_command(&command_str)
+    let timeout_secs = options.timeout_secs;
+    let mut child = options
+        .command(&command_str, &operation_id)
         .spawn()
         .map_err(|e| {
             log::error!("[{}] Process spawn failed: {}", operation_id, e);
-            format!("Failed to spawn command '{}': {}", command_str, e)
+            AppError::Io(format!("Failed to spawn command '{}': {}", command_str, e))
         })?;
 
     let stdout = child
@@ -367,8 +463,11 @@ pub async fn run_and_stream_command(
         .take()
         .expect("Child process did not have a handle to stderr");
 
-    let error_count = Arc::new(AtomicUsize::new(0));
-    let warning_count = Arc::new(AtomicUsize::new(0));
+    let stats = OutputStats {
+        error_count: Arc::new(AtomicUsize::new(0)),
+        warning_count: Arc::new(AtomicUsize::new(0)),
+        last_output_ms: Arc::new(AtomicU64::new(crate::state::AppState::now_ms())),
+    };
     let cancel_poll_operation_id = operation_id.clone();
 
     spawn_output_reader(
@@ -376,8 +475,7 @@ pub async fn run_and_stream_command(
         "stdout",
         window.clone(),
         output_event.to_string(),
-        error_count.clone(),
-        warning_count.clone(),
+        stats.clone(),
         operation_id.clone(),
     );
     spawn_output_reader(
@@ -385,8 +483,7 @@ pub async fn run_and_stream_command(
         "stderr",
         window.clone(),
         output_event.to_string(),
-        error_count.clone(),
-        warning_count.clone(),
+        stats.clone(),
         operation_id.clone(),
     );
 
@@ -397,13 +494,24 @@ pub async fn run_and_stream_command(
                 &operation_name,
                 &window,
                 finished_event,
-                error_count.clone(),
-                warning_count.clone(),
+                stats.error_count.clone(),
+                stats.warning_count.clone(),
                 operation_id.clone(),
             ).await
         },
         _ = wait_for_cancel_request(cancel_poll_operation_id) => {
             handle_cancellation(child, &operation_name, &window, finished_event, operation_id.clone()).await
+        },
+        _ = wait_for_inactivity_timeout(stats.last_output_ms.clone(), timeout_secs) => {
+            handle_timeout(
+                child,
+                &operation_name,
+                &window,
+                output_event,
+                finished_event,
+                timeout_secs.unwrap_or_default(),
+                operation_id.clone(),
+            ).await
         }
     };
 
@@ -422,13 +530,13 @@ async fn handle_command_completion(
     error_count: Arc<AtomicUsize>,
     warning_count: Arc<AtomicUsize>,
     operation_id: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let status = status_res.map_err(|e| {
         log::error!("[{}] Process wait failed: {}", operation_id, e);
-        format!(
+        AppError::Io(format!(
             "Failed to wait on child process for {}: {}",
             operation_name, e
-        )
+        ))
     })?;
 
     let success = status.success();
@@ -487,10 +595,76 @@ async fn handle_command_completion(
         Ok(())
     } else {
         // Use operation name for better context, frontend will handle i18n
-        Err(format!("{} failed", operation_name))
+        Err(AppError::ScoopCommandFailed(format!(
+            "{} failed",
+            operation_name
+        )))
     }
 }
 
+/// Emits a cancelled result for an operation that never started and returns the error message.
+pub fn emit_cancelled_before_start(
+    window: &Window,
+    finished_event: &str,
+    operation_name: &str,
+    operation_id: &str,
+) -> String {
+    if let Err(e) = window.emit(
+        finished_event,
+        CommandResult {
+            success: false,
+            operation_name: operation_name.to_string(),
+            error_count: Some(0),
+            warning_count: Some(0),
+            final_status: FinalStatus::Cancelled,
+            operation_id: operation_id.to_string(),
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_millis() as u64,
+        },
+    ) {
+        log::error!("Failed to emit pre-start cancellation event: {}", e);
+    }
+    format!("{} cancelled by user", operation_name)
+}
+
+/// Emits `reason` and a failed result for an operation that was refused before
+/// it started, and returns `reason`.
+pub fn emit_refused_before_start(
+    window: &Window,
+    operation_name: &str,
+    operation_id: &str,
+    reason: String,
+) -> String {
+    let _ = window.emit(
+        EVENT_OUTPUT,
+        StreamOutput {
+            operation_id: operation_id.to_string(),
+            line: format!("Error: {}", reason),
+            source: "stderr".to_string(),
+        },
+    );
+    if let Err(e) = window.emit(
+        EVENT_FINISHED,
+        CommandResult {
+            success: false,
+            operation_name: operation_name.to_string(),
+            error_count: Some(1),
+            warning_count: None,
+            final_status: FinalStatus::Error,
+            operation_id: operation_id.to_string(),
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_millis() as u64,
+        },
+    ) {
+        log::error!("Failed to emit refused operation event: {}", e);
+    }
+    reason
+}
+
 /// Handles the cancellation of the command, killing the process and emitting a cancellation message.
 async fn handle_cancellation(
     mut child: Child,
@@ -498,7 +672,7 @@ async fn handle_cancellation(
     window: &Window,
     finished_event: &str,
     operation_id: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     log::warn!("Cancelling operation: {}", operation_name);
 
     // Try to kill the process
@@ -524,12 +698,92 @@ async fn handle_cancellation(
         log::error!("Failed to emit cancellation event: {}", e);
     }
 
-    Err(format!("{} cancelled by user", operation_name))
+    Err(AppError::Cancelled(format!(
+        "{} cancelled by user",
+        operation_name
+    )))
+}
+
+/// Kills a child process together with any processes it spawned.
+///
+/// Scoop commands launch git, aria2 and installers as grandchildren, which
+/// `Child::kill` alone would leave running on Windows.
+async fn kill_process_tree(child: &mut Child) {
+    #[cfg(windows)]
+    if let Some(pid) = child.id() {
+        let mut cmd = Command::new("taskkill");
+        cmd.args(["/PID", &pid.to_string(), "/T", "/F"])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null());
+        cmd.creation_flags(0x0800_0000); // CREATE_NO_WINDOW
+        match cmd.status().await {
+            Ok(status) if status.success() => return,
+            Ok(status) => log::warn!("taskkill exited with {} for PID {}", status, pid),
+            Err(e) => log::warn!("Failed to run taskkill for PID {}: {}", pid, e),
+        }
+    }
+
+    if let Err(e) = child.kill().await {
+        log::error!("Failed to kill child process: {}", e);
+    }
+}
+
+/// Handles an inactivity timeout, killing the process tree and emitting a timeout message.
+async fn handle_timeout(
+    mut child: Child,
+    operation_name: &str,
+    window: &Window,
+    output_event: &str,
+    finished_event: &str,
+    timeout_secs: u64,
+    operation_id: String,
+) -> Result<(), AppError> {
+    log::warn!(
+        "[{}] No output for {}s, terminating: {}",
+        operation_id,
+        timeout_secs,
+        operation_name
+    );
+
+    kill_process_tree(&mut child).await;
+
+    let _ = window.emit(
+        output_event,
+        StreamOutput {
+            line: format!(
+                "Operation timed out after {} seconds without output",
+                timeout_secs
+            ),
+            source: "stderr".to_string(),
+            operation_id: operation_id.clone(),
+        },
+    );
+
+    if let Err(e) = window.emit(
+        finished_event,
+        CommandResult {
+            success: false,
+            operation_name: operation_name.to_string(),
+            error_count: Some(1),
+            warning_count: None,
+            final_status: FinalStatus::Error,
+            operation_id: operation_id,
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_millis() as u64,
+        },
+    ) {
+        log::error!("Failed to emit timeout event: {}", e);
+    }
+
+    Err(AppError::Timeout(format!("{} timed out", operation_name)))
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{contains_error_keywords, contains_warning_keywords, FinalStatus};
+    use super::{contains_error_keywords, contains_warning_keywords, FinalStatus, StreamOptions};
+    use std::collections::HashMap;
 
     fn final_status_for_completion(
         process_successful: bool,
@@ -568,4 +822,18 @@ mod tests {
         let status = final_status_for_completion(true, 1);
         assert!(matches!(status, FinalStatus::Error));
     }
+
+    #[test]
+    fn debug_output_hides_environment_values() {
+        let options = StreamOptions {
+            env: Some(HashMap::from([(
+                "GITHUB_TOKEN".to_string(),
+                "ghp_secret".to_string(),
+            )])),
+            ..Default::default()
+        };
+        let debug = format!("{:?}", options);
+        assert!(debug.contains("GITHUB_TOKEN"));
+        assert!(!debug.contains("ghp_secret"));
+    }
 }
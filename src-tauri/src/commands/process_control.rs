@@ -1,7 +1,22 @@
 use crate::state::AppState;
 use serde::Deserialize;
+use std::collections::HashSet;
 use tauri::State;
 
+/// Executables Scoop runs while installing, updating or uninstalling.
+#[cfg_attr(not(windows), allow(dead_code))]
+const SCOOP_HELPER_PROCESSES: &[&str] =
+    &["7z", "7za", "git", "aria2c", "lessmsi", "innounp", "dark"];
+
+/// A process with its parent, as listed by a process snapshot.
+#[cfg_attr(not(windows), allow(dead_code))]
+#[derive(Clone, Debug)]
+struct ProcessEntry {
+    process_id: u32,
+    parent_process_id: u32,
+    process_name: String,
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd)]
 #[serde(rename_all = "camelCase")]
 pub struct ProcessTerminationTarget {
@@ -192,6 +207,17 @@ fn list_processes_by_name(process_name: &str) -> Result<Vec<ProcessTerminationTa
 
 #[cfg(windows)]
 fn list_processes() -> Result<Vec<ProcessTerminationTarget>, String> {
+    Ok(list_process_entries()?
+        .into_iter()
+        .map(|entry| ProcessTerminationTarget {
+            process_id: entry.process_id,
+            process_name: entry.process_name,
+        })
+        .collect())
+}
+
+#[cfg(windows)]
+fn list_process_entries() -> Result<Vec<ProcessEntry>, String> {
     use std::mem::size_of;
     use windows_sys::Win32::Foundation::{CloseHandle, INVALID_HANDLE_VALUE};
     use windows_sys::Win32::System::Diagnostics::ToolHelp::{
@@ -222,8 +248,9 @@ fn list_processes() -> Result<Vec<ProcessTerminationTarget>, String> {
                 .unwrap_or(entry.szExeFile.len());
             let process_name = String::from_utf16_lossy(&entry.szExeFile[..nul_index]);
             if !process_name.is_empty() {
-                targets.push(ProcessTerminationTarget {
+                targets.push(ProcessEntry {
                     process_id: entry.th32ProcessID,
+                    parent_process_id: entry.th32ParentProcessID,
                     process_name,
                 });
             }
@@ -236,6 +263,183 @@ fn list_processes() -> Result<Vec<ProcessTerminationTarget>, String> {
     }
 }
 
+/// Returns `root` and every process below it in the process tree.
+#[cfg_attr(not(windows), allow(dead_code))]
+fn process_tree(entries: &[ProcessEntry], root: u32) -> HashSet<u32> {
+    let mut tree = HashSet::from([root]);
+    loop {
+        let before = tree.len();
+        for entry in entries {
+            // PIDs are reused, so a process can't be its own ancestor
+            if entry.process_id != entry.parent_process_id
+                && tree.contains(&entry.parent_process_id)
+            {
+                tree.insert(entry.process_id);
+            }
+        }
+        if tree.len() == before {
+            return tree;
+        }
+    }
+}
+
+#[cfg(windows)]
+fn process_image_path(process_id: u32) -> Option<std::path::PathBuf> {
+    use windows_sys::Win32::Foundation::CloseHandle;
+    use windows_sys::Win32::System::Threading::{
+        OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32,
+        PROCESS_QUERY_LIMITED_INFORMATION,
+    };
+
+    unsafe {
+        let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, process_id);
+        if handle.is_null() {
+            return None;
+        }
+        let mut buffer = [0u16; 1024];
+        let mut len = buffer.len() as u32;
+        let ok =
+            QueryFullProcessImageNameW(handle, PROCESS_NAME_WIN32, buffer.as_mut_ptr(), &mut len);
+        CloseHandle(handle);
+        (ok != 0)
+            .then(|| std::path::PathBuf::from(String::from_utf16_lossy(&buffer[..len as usize])))
+    }
+}
+
+/// Lists processes other than Pailer's own whose executable lives below `dir`,
+/// as `name (PID n)` descriptions.
+#[cfg(windows)]
+pub(crate) fn find_processes_in_directory(dir: &std::path::Path) -> Vec<String> {
+    let entries = match list_process_entries() {
+        Ok(entries) => entries,
+        Err(e) => {
+            log::warn!(
+                "Could not list processes running from {}: {}",
+                dir.display(),
+                e
+            );
+            return Vec::new();
+        }
+    };
+    let own = process_tree(&entries, std::process::id());
+    let dir = dir.to_string_lossy().to_lowercase();
+    let dir = format!("{}\\", dir.trim_end_matches(['\\', '/']));
+
+    entries
+        .iter()
+        .filter(|entry| !own.contains(&entry.process_id))
+        .filter(|entry| {
+            process_image_path(entry.process_id)
+                .is_some_and(|path| path.to_string_lossy().to_lowercase().starts_with(&dir))
+        })
+        .map(|entry| format!("{} (PID {})", entry.process_name, entry.process_id))
+        .collect()
+}
+
+#[cfg(not(windows))]
+pub(crate) fn find_processes_in_directory(_dir: &std::path::Path) -> Vec<String> {
+    Vec::new()
+}
+
+/// Lists Scoop operations started outside Pailer, e.g. in a terminal, as
+/// `name (PID n)` descriptions.
+///
+/// Pailer's own operations are ignored. Detected are PowerShell processes
+/// started for `scoop.ps1` and the helper tools running below them, so a git
+/// or 7-Zip run unrelated to Scoop is not mistaken for one. Scoop run inside
+/// an already open PowerShell session spawns no process of its own and is not
+/// detected.
+#[cfg(windows)]
+pub(crate) async fn find_conflicting_scoop_processes() -> Vec<String> {
+    let entries = match list_process_entries() {
+        Ok(entries) => entries,
+        Err(e) => {
+            log::warn!(
+                "Could not list processes to detect running Scoop operations: {}",
+                e
+            );
+            return Vec::new();
+        }
+    };
+    let own = process_tree(&entries, std::process::id());
+    let shells: Vec<&ProcessEntry> = entries
+        .iter()
+        .filter(|entry| !own.contains(&entry.process_id))
+        .filter(|entry| {
+            let name = normalize_process_name(&entry.process_name);
+            name == "pwsh" || name == "powershell"
+        })
+        .collect();
+    if shells.is_empty() {
+        return Vec::new();
+    }
+
+    // Only WMI exposes command lines, so PowerShell processes are checked in one query
+    let filter = shells
+        .iter()
+        .map(|entry| format!("ProcessId={}", entry.process_id))
+        .collect::<Vec<_>>()
+        .join(" OR ");
+    let query = format!(
+        "Get-CimInstance Win32_Process -Filter '{}' | ForEach-Object {{ \"$($_.ProcessId)`t$($_.CommandLine)\" }}",
+        filter
+    );
+    let output = match crate::commands::powershell::run_simple_command(&query).await {
+        Ok(output) => output,
+        Err(e) => {
+            log::debug!("Could not read PowerShell command lines: {}", e);
+            return Vec::new();
+        }
+    };
+
+    let mut conflicts = Vec::new();
+    let mut scoop_trees = HashSet::new();
+    for line in output.lines() {
+        let Some((pid, command_line)) = line.split_once('\t') else {
+            continue;
+        };
+        // `scoop.cmd` starts PowerShell with `-file ...\scoop.ps1`
+        if !command_line.to_lowercase().contains("scoop.ps1") {
+            continue;
+        }
+        let Some(shell) = shells
+            .iter()
+            .find(|entry| entry.process_id.to_string() == pid.trim())
+        else {
+            continue;
+        };
+        conflicts.push(format!("{} (PID {})", shell.process_name, shell.process_id));
+        scoop_trees.extend(process_tree(&entries, shell.process_id));
+    }
+
+    conflicts.extend(
+        entries
+            .iter()
+            .filter(|entry| scoop_trees.contains(&entry.process_id))
+            .filter(|entry| {
+                SCOOP_HELPER_PROCESSES
+                    .contains(&normalize_process_name(&entry.process_name).as_str())
+            })
+            .map(|entry| format!("{} (PID {})", entry.process_name, entry.process_id)),
+    );
+    conflicts
+}
+
+#[cfg(not(windows))]
+pub(crate) async fn find_conflicting_scoop_processes() -> Vec<String> {
+    Vec::new()
+}
+
+/// Returns whether a Scoop operation started outside Pailer is running.
+#[tauri::command]
+pub async fn is_scoop_operation_running() -> Result<bool, String> {
+    let conflicts = find_conflicting_scoop_processes().await;
+    if !conflicts.is_empty() {
+        log::info!("Running Scoop operation detected: {}", conflicts.join(", "));
+    }
+    Ok(!conflicts.is_empty())
+}
+
 #[cfg(windows)]
 fn request_process_close(process_id: u32) -> Result<(), String> {
     use windows_sys::Win32::Foundation::{HWND, LPARAM};
@@ -333,6 +537,29 @@ fn process_name_matches(current_name: &str, expected_name: &str) -> bool {
 mod tests {
     #[cfg(windows)]
     use super::process_name_matches;
+    use super::{process_tree, ProcessEntry};
+
+    #[test]
+    fn process_tree_follows_children_but_not_siblings() {
+        let entry = |process_id, parent_process_id| ProcessEntry {
+            process_id,
+            parent_process_id,
+            process_name: String::new(),
+        };
+        let entries = [
+            entry(10, 1),
+            entry(11, 10),
+            entry(12, 11),
+            entry(20, 1),
+            entry(0, 0),
+        ];
+
+        let tree = process_tree(&entries, 10);
+
+        assert_eq!(tree.len(), 3);
+        assert!(tree.contains(&12));
+        assert!(!tree.contains(&20));
+    }
 
     #[test]
     #[cfg(windows)]
@@ -1,5 +1,11 @@
-use super::powershell::{self, EVENT_CANCEL, EVENT_FINISHED, EVENT_OUTPUT};
-use tauri::{Emitter, Window};
+use super::operation_queue;
+use super::powershell::{self, EVENT_FINISHED, EVENT_OUTPUT};
+use crate::error::AppError;
+use tauri::{Emitter, Manager, Window};
+
+/// Seconds without output after which install and update operations are
+/// considered hung and terminated.
+const SCOOP_OPERATION_TIMEOUT_SECS: u64 = 600;
 
 /// Defines the supported Scoop operations.
 #[derive(Debug, Clone, Copy)]
@@ -46,19 +52,23 @@ pub fn generate_operation_id(op: ScoopOp, package_name: Option<&str>) -> String
 
 /// Builds a Scoop command as a string, returning an error if a required
 /// package name is missing.
+///
+/// `global` targets the global Scoop root for uninstalls and updates.
 fn build_scoop_cmd(
     op: ScoopOp,
     package: Option<&str>,
     bucket: Option<&str>,
     bypass: bool,
+    global: bool,
 ) -> Result<String, String> {
+    let global_flag = if global { " --global" } else { "" };
     let build_update_command = |pkg: &str, force: bool| {
         if bypass {
-            crate::commands::powershell::build_scoop_update_bypass_command(pkg, force)
+            crate::commands::powershell::build_scoop_update_bypass_command(pkg, force, global)
         } else if force {
-            format!("scoop update {} --force", pkg)
+            format!("scoop update {} --force{}", pkg, global_flag)
         } else {
-            format!("scoop update {}", pkg)
+            format!("scoop update {}{}", pkg, global_flag)
         }
     };
 
@@ -84,7 +94,7 @@ fn build_scoop_cmd(
         }
         ScoopOp::Uninstall => {
             let pkg = package.ok_or("A package name is required to uninstall.")?;
-            format!("scoop uninstall {}", pkg)
+            format!("scoop uninstall {}{}", pkg, global_flag)
         }
         ScoopOp::Update => {
             let pkg = package.ok_or("A package name is required to update.")?;
@@ -107,7 +117,9 @@ fn build_scoop_cmd(
 /// Executes a Scoop operation and streams the output to the frontend.
 ///
 /// This function builds the Scoop command, creates a human-friendly operation
-/// name for the UI, and then executes it using the PowerShell runner.
+/// name for the UI, waits for its turn in the operation queue, and then
+/// executes it using the PowerShell runner. Cancellation, timeouts and a
+/// conflicting Scoop process each fail with their own `AppError` kind.
 pub async fn execute_scoop(
     window: Window,
     op: ScoopOp,
@@ -115,20 +127,73 @@ pub async fn execute_scoop(
     bucket: Option<&str>,
     operation_id: String,
     bypass: bool,
-) -> Result<(), String> {
-    let cmd = build_scoop_cmd(op, package, bucket, bypass)?;
+    global: bool,
+) -> Result<(), AppError> {
+    let cmd = build_scoop_cmd(op, package, bucket, bypass, global).map_err(AppError::Parse)?;
     let op_name = generate_operation_name(op, package);
+    let timeout_secs = match op {
+        ScoopOp::Install | ScoopOp::Update | ScoopOp::UpdateForce | ScoopOp::UpdateAll => {
+            Some(SCOOP_OPERATION_TIMEOUT_SECS)
+        }
+        ScoopOp::Uninstall | ScoopOp::ClearCache => None,
+    };
+
+    let _turn =
+        match operation_queue::wait_for_turn(window.app_handle(), &operation_id, &op_name).await {
+            Ok(turn) => turn,
+            Err(_) => {
+                return Err(AppError::Cancelled(
+                    powershell::emit_cancelled_before_start(
+                        &window,
+                        EVENT_FINISHED,
+                        &op_name,
+                        &operation_id,
+                    ),
+                ))
+            }
+        };
+
+    // A Scoop run from a terminal at the same time can leave apps half-written
+    let conflicts = crate::commands::process_control::find_conflicting_scoop_processes().await;
+    if !conflicts.is_empty() {
+        log::warn!(
+            "[{}] Refusing to start while Scoop is running elsewhere: {}",
+            operation_id,
+            conflicts.join(", ")
+        );
+        return Err(AppError::Busy(powershell::emit_refused_before_start(
+            &window,
+            &op_name,
+            &operation_id,
+            format!(
+                "Another Scoop operation is running ({}). Wait for it to finish and try again.",
+                conflicts.join(", ")
+            ),
+        )));
+    }
 
     log::info!("[{}] Executing: {}", operation_id, cmd);
 
+    let options = powershell::StreamOptions {
+        timeout_secs,
+        ..Default::default()
+    };
+    // Installs and updates may run checkver/autoupdate, which call the GitHub API
+    let options = match op {
+        ScoopOp::Install | ScoopOp::Update | ScoopOp::UpdateForce | ScoopOp::UpdateAll => {
+            options.with_github_token(window.app_handle())
+        }
+        ScoopOp::Uninstall | ScoopOp::ClearCache => options,
+    };
+
     let result = powershell::run_and_stream_command(
         window,
         cmd,
         op_name,
         EVENT_OUTPUT,
         EVENT_FINISHED,
-        EVENT_CANCEL,
         operation_id.clone(),
+        options,
     )
     .await;
 
@@ -145,6 +210,7 @@ pub async fn retry_operation_elevated(
     bucket_name: Option<String>,
     force_update: Option<bool>,
     skip_pre_update_refresh: Option<bool>,
+    global: Option<bool>,
 ) -> Result<(), String> {
     let op = match operation_type.as_str() {
         "install" => ScoopOp::Install,
@@ -168,7 +234,31 @@ pub async fn retry_operation_elevated(
 
     let bypass = skip_pre_update_refresh.unwrap_or(false);
 
-    let scoop_cmd = build_scoop_cmd(op, package_name.as_deref(), bucket_name.as_deref(), bypass)?;
+    // A global package is the usual reason for retrying elevated; the scope comes
+    // from the package list and is only guessed for callers that don't send it
+    let global = match (
+        package_name.as_deref(),
+        window.try_state::<crate::state::AppState>(),
+    ) {
+        (Some(package), Some(state))
+            if matches!(
+                op,
+                ScoopOp::Uninstall | ScoopOp::Update | ScoopOp::UpdateForce
+            ) =>
+        {
+            global.unwrap_or_else(|| {
+                crate::commands::installed::is_global_only_package(&state, package)
+            })
+        }
+        _ => false,
+    };
+    let scoop_cmd = build_scoop_cmd(
+        op,
+        package_name.as_deref(),
+        bucket_name.as_deref(),
+        bypass,
+        global,
+    )?;
 
     let ps_exe = powershell::resolve_powershell_exe();
     let escaped_cmd = scoop_cmd.replace('\'', "''");
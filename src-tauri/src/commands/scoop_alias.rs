@@ -0,0 +1,228 @@
+//! Commands for managing Scoop's custom command aliases.
+//!
+//! Like `scoop alias add`, each alias is a `shims\scoop-<name>.ps1` script
+//! holding the command, registered under the `alias` key of Scoop's config.
+use crate::commands::settings::{read_scoop_config, write_scoop_config};
+use crate::state::AppState;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+/// Scoop's own subcommands, which an alias must not shadow.
+const BUILTIN_SUBCOMMANDS: &[&str] = &[
+    "alias",
+    "bucket",
+    "cache",
+    "cat",
+    "checkup",
+    "cleanup",
+    "config",
+    "create",
+    "depends",
+    "download",
+    "export",
+    "help",
+    "hold",
+    "home",
+    "import",
+    "info",
+    "install",
+    "list",
+    "prefix",
+    "reset",
+    "search",
+    "shim",
+    "status",
+    "unhold",
+    "uninstall",
+    "update",
+    "virustotal",
+    "which",
+];
+
+/// A Scoop alias and the command it runs.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ScoopAlias {
+    pub name: String,
+    pub command: String,
+    pub description: Option<String>,
+}
+
+fn validate_alias_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("Alias name cannot be empty".to_string());
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(format!(
+            "Alias name '{}' may only contain letters, digits, '-' and '_'",
+            name
+        ));
+    }
+    if BUILTIN_SUBCOMMANDS
+        .iter()
+        .any(|builtin| builtin.eq_ignore_ascii_case(name))
+    {
+        return Err(format!("'{}' is a built-in Scoop command", name));
+    }
+    Ok(())
+}
+
+fn alias_script_path(scoop_dir: &Path, script: &str) -> PathBuf {
+    scoop_dir.join("shims").join(format!("{}.ps1", script))
+}
+
+/// Splits an alias script into its `# Summary:` description and command.
+fn parse_alias_script(content: &str) -> (Option<String>, String) {
+    let mut description = None;
+    let mut command = Vec::new();
+    for line in content.lines() {
+        match line.trim_start().strip_prefix("# Summary:") {
+            Some(summary) => description = Some(summary.trim().to_string()),
+            None => command.push(line),
+        }
+    }
+    (
+        description.filter(|d| !d.is_empty()),
+        command.join("\n").trim().to_string(),
+    )
+}
+
+fn alias_map(config: &Map<String, Value>) -> Map<String, Value> {
+    config
+        .get("alias")
+        .and_then(Value::as_object)
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// Lists the aliases registered in Scoop's config.
+#[tauri::command]
+pub fn list_scoop_aliases(state: State<'_, AppState>) -> Result<Vec<ScoopAlias>, String> {
+    let scoop_dir = state.scoop_path();
+    let aliases = alias_map(&read_scoop_config()?);
+
+    let mut result: Vec<ScoopAlias> = aliases
+        .iter()
+        .map(|(name, script)| {
+            let script = script.as_str().unwrap_or_default();
+            let (description, command) = fs::read_to_string(alias_script_path(&scoop_dir, script))
+                .map(|content| parse_alias_script(&content))
+                .unwrap_or_default();
+            ScoopAlias {
+                name: name.clone(),
+                command,
+                description,
+            }
+        })
+        .collect();
+    result.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(result)
+}
+
+/// Adds an alias so `scoop <name>` runs `command`.
+#[tauri::command]
+pub fn add_scoop_alias(
+    state: State<'_, AppState>,
+    name: String,
+    command: String,
+    description: Option<String>,
+) -> Result<ScoopAlias, String> {
+    let name = name.trim().to_string();
+    let command = command.trim().to_string();
+    validate_alias_name(&name)?;
+    if command.is_empty() {
+        return Err("Alias command cannot be empty".to_string());
+    }
+
+    let mut config = read_scoop_config()?;
+    let mut aliases = alias_map(&config);
+    if aliases.contains_key(&name) {
+        return Err(format!("Alias '{}' already exists", name));
+    }
+
+    let description = description
+        .map(|d| d.trim().to_string())
+        .filter(|d| !d.is_empty());
+    let script = format!("scoop-{}", name);
+    let script_path = alias_script_path(&state.scoop_path(), &script);
+    if let Some(parent) = script_path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    fs::write(
+        &script_path,
+        format!(
+            "# Summary: {}\n{}\n",
+            description.as_deref().unwrap_or_default(),
+            command
+        ),
+    )
+    .map_err(|e| format!("Failed to write {}: {}", script_path.display(), e))?;
+
+    aliases.insert(name.clone(), Value::String(script));
+    config.insert("alias".to_string(), Value::Object(aliases));
+    write_scoop_config(&config)?;
+
+    log::info!("Added Scoop alias '{}'", name);
+    Ok(ScoopAlias {
+        name,
+        command,
+        description,
+    })
+}
+
+/// Removes an alias and its script.
+#[tauri::command]
+pub fn remove_scoop_alias(state: State<'_, AppState>, name: String) -> Result<(), String> {
+    let name = name.trim();
+    let mut config = read_scoop_config()?;
+    let mut aliases = alias_map(&config);
+    let script = aliases
+        .remove(name)
+        .ok_or_else(|| format!("Alias '{}' does not exist", name))?;
+
+    if let Some(script) = script.as_str() {
+        let script_path = alias_script_path(&state.scoop_path(), script);
+        if let Err(e) = fs::remove_file(&script_path) {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                return Err(format!("Failed to remove {}: {}", script_path.display(), e));
+            }
+        }
+    }
+
+    config.insert("alias".to_string(), Value::Object(aliases));
+    write_scoop_config(&config)?;
+
+    log::info!("Removed Scoop alias '{}'", name);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_builtin_and_malformed_names() {
+        assert!(validate_alias_name("upgrade").is_ok());
+        assert!(validate_alias_name("Install").is_err());
+        assert!(validate_alias_name("my alias").is_err());
+        assert!(validate_alias_name("").is_err());
+    }
+
+    #[test]
+    fn parses_summary_and_command_from_script() {
+        let (description, command) =
+            parse_alias_script("# Summary: Update everything\nscoop update *\n");
+        assert_eq!(description.as_deref(), Some("Update everything"));
+        assert_eq!(command, "scoop update *");
+
+        let (description, command) = parse_alias_script("# Summary: \nscoop status");
+        assert_eq!(description, None);
+        assert_eq!(command, "scoop status");
+    }
+}
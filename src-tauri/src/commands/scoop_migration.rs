@@ -0,0 +1,643 @@
+//! Commands for moving the Scoop root to another directory.
+//!
+//! Scoop stores absolute paths in several places, so a plain folder move leaves
+//! a broken install behind. A migration runs these steps in order:
+//!
+//! 1. Move the tree. On the same volume it is renamed; otherwise it is copied
+//!    and the source removed afterwards. Only write access to both locations is
+//!    needed, so this runs unelevated unless the destination is protected
+//!    (e.g. under `Program Files`); such a destination fails with access denied.
+//! 2. Recreate junctions (`current` and persisted directories) that pointed
+//!    into the old root. Directory junctions don't require elevation.
+//! 3. Rewrite the old root in the shim files under `shims`.
+//! 4. Update the user `SCOOP` and `PATH` variables in `HKCU\Environment`,
+//!    Scoop's `root_path` config and the app settings. All are per-user.
+//!
+//! Global installs under `%ProgramData%\scoop` are not moved: that needs
+//! elevation and is left to the user. Start menu shortcuts and persisted files
+//! that Scoop hard-links are refreshed by running `scoop reset *` afterwards.
+use crate::commands::settings::{self, validate_scoop_directory};
+use crate::state::AppState;
+use serde::Serialize;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use tauri::{AppHandle, State};
+
+/// One preflight check of a migration.
+#[derive(Serialize, Debug, Clone)]
+pub struct MigrationCheck {
+    pub id: String,
+    pub passed: bool,
+    pub message: String,
+}
+
+/// Dry-run result of `preview_scoop_migration`.
+#[derive(Serialize, Debug, Clone)]
+pub struct MigrationPlan {
+    pub source: String,
+    pub destination: String,
+    /// Size of the Scoop tree in bytes, not counting linked directories.
+    pub estimated_bytes: u64,
+    /// Free space on the destination volume, when it could be determined.
+    pub available_bytes: Option<u64>,
+    /// Whether the tree can be renamed in place instead of copied.
+    pub same_volume: bool,
+    pub checks: Vec<MigrationCheck>,
+    /// True when every check passed.
+    pub ready: bool,
+}
+
+/// Outcome of `migrate_scoop_directory`.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct MigrationResult {
+    pub path: String,
+    pub relinked_junctions: usize,
+    pub rewritten_shims: usize,
+    /// Steps that failed without aborting the migration.
+    pub warnings: Vec<String>,
+}
+
+fn check(id: &str, passed: bool, message: impl Into<String>) -> MigrationCheck {
+    MigrationCheck {
+        id: id.to_string(),
+        passed,
+        message: message.into(),
+    }
+}
+
+fn strip_verbatim_prefix(path: &Path) -> PathBuf {
+    let text = path.to_string_lossy();
+    match text.strip_prefix(r"\\?\") {
+        Some(stripped) => PathBuf::from(stripped),
+        None => path.to_path_buf(),
+    }
+}
+
+/// Maps `path` below `old_root` to the same location below `new_root`.
+/// Returns `None` for paths outside `old_root`.
+fn rebase_path(path: &Path, old_root: &Path, new_root: &Path) -> Option<PathBuf> {
+    let path = strip_verbatim_prefix(path);
+    let path_text = path.to_string_lossy().to_lowercase();
+    let old_text = old_root.to_string_lossy().to_lowercase();
+    let old_text = old_text.trim_end_matches(['\\', '/']);
+
+    let rest = path_text.strip_prefix(old_text)?;
+    if !rest.is_empty() && !rest.starts_with(['\\', '/']) {
+        return None;
+    }
+
+    // Slice the original text to keep the casing of the relative part
+    let original = path.to_string_lossy();
+    let relative = original
+        .get(old_text.len()..)?
+        .trim_start_matches(['\\', '/']);
+    Some(if relative.is_empty() {
+        new_root.to_path_buf()
+    } else {
+        new_root.join(relative)
+    })
+}
+
+fn is_within(path: &Path, root: &Path) -> bool {
+    rebase_path(path, root, Path::new("")).is_some()
+}
+
+fn volume_of(path: &Path) -> Option<String> {
+    match path.components().next()? {
+        Component::Prefix(prefix) => Some(prefix.as_os_str().to_string_lossy().to_lowercase()),
+        Component::RootDir => Some("/".to_string()),
+        _ => None,
+    }
+}
+
+fn nearest_existing_ancestor(path: &Path) -> Option<&Path> {
+    path.ancestors().find(|ancestor| ancestor.exists())
+}
+
+#[cfg(windows)]
+pub(crate) fn available_space(path: &Path) -> Option<u64> {
+    use std::os::windows::prelude::OsStrExt;
+    use windows_sys::Win32::Storage::FileSystem::GetDiskFreeSpaceExW;
+
+    let existing = nearest_existing_ancestor(path)?;
+    let path_ws: Vec<u16> = existing.as_os_str().encode_wide().chain(Some(0)).collect();
+    let mut available = 0u64;
+    let result = unsafe {
+        GetDiskFreeSpaceExW(
+            path_ws.as_ptr(),
+            &mut available,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+        )
+    };
+    (result != 0).then_some(available)
+}
+
+#[cfg(not(windows))]
+pub(crate) fn available_space(_path: &Path) -> Option<u64> {
+    None
+}
+
+fn build_plan(state: &AppState, new_path: &str) -> Result<MigrationPlan, String> {
+    let source = state.scoop_path();
+    let destination = PathBuf::from(new_path.trim().trim_end_matches(['\\', '/']));
+    if destination.as_os_str().is_empty() {
+        return Err("Destination path cannot be empty".to_string());
+    }
+    if !destination.is_absolute() {
+        return Err("Destination path must be absolute".to_string());
+    }
+
+    let same_volume = volume_of(&source).is_some() && volume_of(&source) == volume_of(&destination);
+    let estimated_bytes = crate::utils::directory_size(&source);
+    let available_bytes = available_space(&destination);
+    let mut checks = Vec::new();
+
+    let source_valid = validate_scoop_directory(source.to_string_lossy().to_string())
+        .map(|validation| validation.valid)
+        .unwrap_or(false);
+    checks.push(check(
+        "source_valid",
+        source_valid,
+        if source_valid {
+            format!("{} is a Scoop installation", source.display())
+        } else {
+            format!("{} is not a valid Scoop installation", source.display())
+        },
+    ));
+
+    let distinct = !is_within(&destination, &source) && !is_within(&source, &destination);
+    checks.push(check(
+        "destination_distinct",
+        distinct,
+        if distinct {
+            "Destination is outside the current Scoop root".to_string()
+        } else {
+            "Destination must not contain or be inside the current Scoop root".to_string()
+        },
+    ));
+
+    let destination_usable = if destination.exists() {
+        destination.is_dir()
+            && fs::read_dir(&destination)
+                .map(|mut entries| entries.next().is_none())
+                .unwrap_or(false)
+    } else {
+        destination.parent().is_some_and(Path::is_dir)
+    };
+    checks.push(check(
+        "destination_empty",
+        destination_usable,
+        if destination_usable {
+            "Destination is empty or can be created".to_string()
+        } else {
+            "Destination must be an empty directory or a new folder in an existing directory"
+                .to_string()
+        },
+    ));
+
+    // A rename needs no extra space; a copy needs room for the whole tree
+    let enough_space = same_volume || available_bytes.is_none_or(|free| free > estimated_bytes);
+    checks.push(check(
+        "free_space",
+        enough_space,
+        match available_bytes {
+            Some(free) if !same_volume => format!(
+                "{} bytes needed, {} bytes available on the destination volume",
+                estimated_bytes, free
+            ),
+            _ => "No additional space is needed".to_string(),
+        },
+    ));
+
+    let queue = state.operation_queue.snapshot();
+    checks.push(check(
+        "no_operations",
+        queue.is_empty(),
+        if queue.is_empty() {
+            "No Scoop operation is running".to_string()
+        } else {
+            format!("{} Scoop operation(s) are running or queued", queue.len())
+        },
+    ));
+
+    // Running apps and Scoop's helpers hold files open, so moving would fail midway
+    let running = crate::commands::process_control::find_processes_in_directory(&source);
+    checks.push(check(
+        "no_running_processes",
+        running.is_empty(),
+        if running.is_empty() {
+            "No program is running from the Scoop directory".to_string()
+        } else {
+            format!(
+                "Close the programs running from the Scoop directory first: {}",
+                running.join(", ")
+            )
+        },
+    ));
+
+    let ready = checks.iter().all(|c| c.passed);
+    Ok(MigrationPlan {
+        source: source.to_string_lossy().to_string(),
+        destination: destination.to_string_lossy().to_string(),
+        estimated_bytes,
+        available_bytes,
+        same_volume,
+        checks,
+        ready,
+    })
+}
+
+/// Recreates a link at `link` pointing to the existing `target`.
+fn create_link(link: &Path, target: &Path) -> Result<(), String> {
+    if target.is_dir() {
+        crate::commands::linker::create_directory_junction(link, target)
+    } else {
+        fs::hard_link(target, link)
+            .map_err(|e| format!("Failed to link '{}': {}", link.display(), e))
+    }
+}
+
+/// Copies `from` to `to` without following links. Directory links into
+/// `old_root` are recreated against `new_root`, other directory links keep
+/// their target, and file links are copied as regular files.
+fn copy_tree(
+    from: &Path,
+    to: &Path,
+    old_root: &Path,
+    new_root: &Path,
+    relinked: &mut usize,
+) -> Result<(), String> {
+    fs::create_dir_all(to).map_err(|e| format!("Failed to create '{}': {}", to.display(), e))?;
+    let entries =
+        fs::read_dir(from).map_err(|e| format!("Failed to read '{}': {}", from.display(), e))?;
+
+    for entry in entries.flatten() {
+        let source = entry.path();
+        let destination = to.join(entry.file_name());
+        let metadata = fs::symlink_metadata(&source)
+            .map_err(|e| format!("Failed to read '{}': {}", source.display(), e))?;
+
+        let links_to_dir = fs::metadata(&source).is_ok_and(|m| m.is_dir());
+        if metadata.file_type().is_symlink() && links_to_dir {
+            let target = fs::read_link(&source)
+                .map_err(|e| format!("Failed to read link '{}': {}", source.display(), e))?;
+            let target = match rebase_path(&target, old_root, new_root) {
+                Some(rebased) => {
+                    *relinked += 1;
+                    rebased
+                }
+                None => target,
+            };
+            // Junctions may point at directories that haven't been copied yet
+            crate::commands::linker::create_directory_junction(&destination, &target)?;
+        } else if metadata.file_type().is_symlink() && !source.exists() {
+            log::warn!("Skipping broken link {}", source.display());
+        } else if metadata.is_dir() {
+            copy_tree(&source, &destination, old_root, new_root, relinked)?;
+        } else {
+            fs::copy(&source, &destination)
+                .map_err(|e| format!("Failed to copy '{}': {}", source.display(), e))?;
+        }
+    }
+    Ok(())
+}
+
+/// Repoints links below `dir` that target `old_root` at `new_root`.
+fn relink_tree(dir: &Path, old_root: &Path, new_root: &Path) -> Result<usize, String> {
+    let entries =
+        fs::read_dir(dir).map_err(|e| format!("Failed to read '{}': {}", dir.display(), e))?;
+    let mut relinked = 0;
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(metadata) = fs::symlink_metadata(&path) else {
+            continue;
+        };
+
+        if metadata.file_type().is_symlink() {
+            let Ok(target) = fs::read_link(&path) else {
+                continue;
+            };
+            if let Some(rebased) = rebase_path(&target, old_root, new_root) {
+                fs::remove_dir(&path)
+                    .or_else(|_| fs::remove_file(&path))
+                    .map_err(|e| format!("Failed to remove link '{}': {}", path.display(), e))?;
+                create_link(&path, &rebased)?;
+                relinked += 1;
+            }
+        } else if metadata.is_dir() {
+            relinked += relink_tree(&path, old_root, new_root)?;
+        }
+    }
+    Ok(relinked)
+}
+
+/// Replaces the old root in a shim's text, in any of the spellings shims use.
+fn rewrite_shim_text(content: &str, old_root: &Path, new_root: &Path) -> Option<String> {
+    let old = old_root
+        .to_string_lossy()
+        .trim_end_matches(['\\', '/'])
+        .to_string();
+    let new = new_root
+        .to_string_lossy()
+        .trim_end_matches(['\\', '/'])
+        .to_string();
+
+    let mut rewritten = content.to_string();
+    for (from, to) in [
+        (old.replace('\\', "\\\\"), new.replace('\\', "\\\\")),
+        (old.clone(), new.clone()),
+        (old.replace('\\', "/"), new.replace('\\', "/")),
+    ] {
+        let pattern = regex::Regex::new(&format!("(?i){}", regex::escape(&from))).ok()?;
+        rewritten = pattern
+            .replace_all(&rewritten, regex::NoExpand(&to))
+            .into_owned();
+    }
+    (rewritten != content).then_some(rewritten)
+}
+
+fn rewrite_shims(shims_dir: &Path, old_root: &Path, new_root: &Path) -> usize {
+    let Ok(entries) = fs::read_dir(shims_dir) else {
+        return 0;
+    };
+
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            !path
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("exe"))
+        })
+        .filter(|path| {
+            // Binary or unreadable files are not shim scripts
+            let Ok(content) = fs::read_to_string(path) else {
+                return false;
+            };
+            match rewrite_shim_text(&content, old_root, new_root) {
+                Some(rewritten) => fs::write(path, rewritten)
+                    .map_err(|e| log::warn!("Failed to rewrite shim {}: {}", path.display(), e))
+                    .is_ok(),
+                None => false,
+            }
+        })
+        .count()
+}
+
+/// Points the user `SCOOP` variable and any `PATH` entries below the old root at the new one.
+#[cfg(windows)]
+fn update_user_environment(old_root: &Path, new_root: &Path) -> Result<(), String> {
+    use winreg::{enums::*, types::ToRegValue, RegKey};
+
+    let hkcu = RegKey::predef(HKEY_CURRENT_USER);
+    let env = hkcu
+        .open_subkey_with_flags("Environment", KEY_READ | KEY_WRITE)
+        .map_err(|e| format!("Failed to open user environment: {}", e))?;
+
+    env.set_value("SCOOP", &new_root.to_string_lossy().to_string())
+        .map_err(|e| format!("Failed to set SCOOP: {}", e))?;
+
+    if let (Ok(raw), Ok(path)) = (
+        env.get_raw_value("Path"),
+        env.get_value::<String, _>("Path"),
+    ) {
+        let updated: Vec<String> = path
+            .split(';')
+            .map(|entry| {
+                rebase_path(Path::new(entry), old_root, new_root)
+                    .map(|rebased| rebased.to_string_lossy().to_string())
+                    .unwrap_or_else(|| entry.to_string())
+            })
+            .collect();
+        let updated = updated.join(";");
+        if updated != path {
+            // Keep REG_EXPAND_SZ so entries like %USERPROFILE% still expand
+            let mut value = updated.to_reg_value();
+            value.vtype = raw.vtype;
+            env.set_raw_value("Path", &value)
+                .map_err(|e| format!("Failed to update PATH: {}", e))?;
+        }
+    }
+
+    broadcast_environment_change();
+    Ok(())
+}
+
+#[cfg(not(windows))]
+fn update_user_environment(_old_root: &Path, _new_root: &Path) -> Result<(), String> {
+    Ok(())
+}
+
+/// Tells running programs (Explorer in particular) to reload the environment.
+#[cfg(windows)]
+fn broadcast_environment_change() {
+    use windows_sys::Win32::UI::WindowsAndMessaging::{
+        SendMessageTimeoutW, HWND_BROADCAST, SMTO_ABORTIFHUNG, WM_SETTINGCHANGE,
+    };
+
+    let area: Vec<u16> = "Environment".encode_utf16().chain(Some(0)).collect();
+    unsafe {
+        SendMessageTimeoutW(
+            HWND_BROADCAST,
+            WM_SETTINGCHANGE,
+            0,
+            area.as_ptr() as isize,
+            SMTO_ABORTIFHUNG,
+            5000,
+            std::ptr::null_mut(),
+        );
+    }
+}
+
+/// Updates Scoop's `root_path` if the user configured one.
+fn update_scoop_root_config(new_root: &Path) -> Result<(), String> {
+    let Some(mut config) = settings::get_scoop_config()? else {
+        return Ok(());
+    };
+    if !config.contains_key("root_path") {
+        return Ok(());
+    }
+    config.insert(
+        "root_path".to_string(),
+        serde_json::json!(new_root.to_string_lossy()),
+    );
+    settings::update_scoop_config(serde_json::Value::Object(config))
+}
+
+/// Reports what migrating the Scoop root to `new_path` would involve without
+/// changing anything.
+#[tauri::command]
+pub fn preview_scoop_migration(
+    state: State<'_, AppState>,
+    new_path: String,
+) -> Result<MigrationPlan, String> {
+    build_plan(&state, &new_path)
+}
+
+/// Moves the Scoop root to `new_path` and repoints everything that referenced
+/// the old location. See the module docs for the individual steps.
+#[tauri::command]
+pub async fn migrate_scoop_directory(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    new_path: String,
+) -> Result<MigrationResult, String> {
+    let plan = build_plan(&state, &new_path)?;
+    if let Some(failed) = plan.checks.iter().find(|c| !c.passed) {
+        return Err(failed.message.clone());
+    }
+
+    let operation_id = format!(
+        "scoop-migration-{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0)
+    );
+    let _turn = crate::commands::operation_queue::wait_for_turn(
+        &app,
+        &operation_id,
+        "Moving Scoop directory",
+    )
+    .await?;
+
+    let source = PathBuf::from(&plan.source);
+    let destination = PathBuf::from(&plan.destination);
+    log::info!(
+        "Migrating Scoop root from {} to {} ({} bytes, same volume: {})",
+        source.display(),
+        destination.display(),
+        plan.estimated_bytes,
+        plan.same_volume
+    );
+
+    let mut result = tokio::task::spawn_blocking({
+        let source = source.clone();
+        let destination = destination.clone();
+        let same_volume = plan.same_volume;
+        move || -> Result<MigrationResult, String> {
+            let mut result = MigrationResult::default();
+
+            if same_volume {
+                if destination.exists() {
+                    fs::remove_dir(&destination).map_err(|e| {
+                        format!("Failed to prepare '{}': {}", destination.display(), e)
+                    })?;
+                }
+                fs::rename(&source, &destination)
+                    .map_err(|e| format!("Failed to move Scoop directory: {}", e))?;
+                // Scoop only places junctions below `apps`
+                result.relinked_junctions =
+                    match relink_tree(&destination.join("apps"), &source, &destination) {
+                        Ok(relinked) => relinked,
+                        Err(e) => {
+                            // Move the tree back and point its links at it again
+                            if fs::rename(&destination, &source).is_ok() {
+                                let _ = relink_tree(&source.join("apps"), &destination, &source);
+                            }
+                            return Err(e);
+                        }
+                    };
+            } else {
+                if let Err(e) = copy_tree(
+                    &source,
+                    &destination,
+                    &source,
+                    &destination,
+                    &mut result.relinked_junctions,
+                ) {
+                    // The source is untouched until the copy completes, so only
+                    // the partial copy needs to go. This doesn't follow junctions.
+                    if let Err(cleanup) = fs::remove_dir_all(&destination) {
+                        log::warn!(
+                            "Failed to remove partial copy at {}: {}",
+                            destination.display(),
+                            cleanup
+                        );
+                    }
+                    return Err(e);
+                }
+                if let Err(e) = fs::remove_dir_all(&source) {
+                    result.warnings.push(format!(
+                        "Copied successfully but failed to remove {}: {}",
+                        source.display(),
+                        e
+                    ));
+                }
+            }
+
+            result.rewritten_shims =
+                rewrite_shims(&destination.join("shims"), &source, &destination);
+            Ok(result)
+        }
+    })
+    .await
+    .map_err(|e| format!("Migration task failed: {}", e))??;
+
+    if let Err(e) = update_user_environment(&source, &destination) {
+        result.warnings.push(e);
+    }
+    if let Err(e) = update_scoop_root_config(&destination) {
+        result.warnings.push(e);
+    }
+
+    result.path = settings::set_scoop_path(app, plan.destination.clone()).await?;
+
+    log::info!(
+        "Scoop root migrated to {}: {} junctions relinked, {} shims rewritten, {} warnings",
+        result.path,
+        result.relinked_junctions,
+        result.rewritten_shims,
+        result.warnings.len()
+    );
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rebases_paths_below_the_old_root_only() {
+        let old_root = Path::new(r"C:\Users\me\scoop");
+        let new_root = Path::new(r"D:\scoop");
+
+        assert_eq!(
+            rebase_path(
+                Path::new(r"c:\users\me\scoop\persist\git\etc"),
+                old_root,
+                new_root
+            ),
+            Some(new_root.join(r"persist\git\etc"))
+        );
+        assert_eq!(
+            rebase_path(Path::new(r"\\?\C:\Users\me\scoop"), old_root, new_root),
+            Some(new_root.to_path_buf())
+        );
+        assert_eq!(
+            rebase_path(Path::new(r"C:\Users\me\scoop2\apps"), old_root, new_root),
+            None
+        );
+    }
+
+    #[test]
+    fn rewrites_every_spelling_of_the_root_in_shims() {
+        let old_root = Path::new(r"C:\Users\me\scoop");
+        let new_root = Path::new(r"D:\scoop");
+        let shim = "path = \"C:\\Users\\me\\scoop\\apps\\git\\current\\bin\\git.exe\"\n\
+                    $path = \"c:\\\\users\\\\me\\\\scoop\\\\apps\"\n\
+                    exec \"C:/Users/me/scoop/apps/git/current/bin/git.exe\"";
+
+        let rewritten = rewrite_shim_text(shim, old_root, new_root).unwrap();
+
+        assert_eq!(
+            rewritten,
+            "path = \"D:\\scoop\\apps\\git\\current\\bin\\git.exe\"\n\
+             $path = \"D:\\\\scoop\\\\apps\"\n\
+             exec \"D:/scoop/apps/git/current/bin/git.exe\""
+        );
+        assert_eq!(rewrite_shim_text("unrelated", old_root, new_root), None);
+    }
+}
@@ -18,9 +18,13 @@ impl ScoopUpdateAllOutput {
     }
 }
 
-pub async fn run_update_all_headless() -> Result<ScoopUpdateAllOutput, String> {
+/// Runs `scoop update *` without streaming. Only the priority and environment
+/// of `options` apply, since nothing watches the output for inactivity.
+pub async fn run_update_all_headless(
+    options: &powershell::StreamOptions,
+) -> Result<ScoopUpdateAllOutput, String> {
     let update_all_command = powershell::build_scoop_update_all_command(true);
-    let mut cmd = powershell::create_powershell_command(&update_all_command);
+    let mut cmd = options.command(&update_all_command, "headless-update-all");
     let mut child = cmd
         .spawn()
         .map_err(|e| format!("Failed to spawn scoop update *: {}", e))?;
@@ -0,0 +1,354 @@
+//! Commands for exporting and importing a Scoop setup in the `scoop export` format.
+use crate::commands::bucket::scan_buckets;
+use crate::commands::bucket_install::{self, BucketInstallOptions};
+use crate::commands::installed::get_installed_packages_full;
+use crate::state::AppState;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use tauri::{AppHandle, Emitter, State, Window};
+
+pub const EVENT_IMPORT_PROGRESS: &str = "import-progress";
+
+/// A bucket entry of a scoopfile.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct ScoopfileBucket {
+    pub name: String,
+    #[serde(default)]
+    pub source: String,
+}
+
+/// An app entry of a scoopfile.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct ScoopfileApp {
+    pub name: String,
+    #[serde(default)]
+    pub version: String,
+    #[serde(default)]
+    pub source: String,
+    /// Free-form flags such as `Held package` or `Global install`.
+    #[serde(default)]
+    pub info: String,
+}
+
+/// The JSON document written by `scoop export`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Scoopfile {
+    #[serde(default)]
+    pub buckets: Vec<ScoopfileBucket>,
+    #[serde(default)]
+    pub apps: Vec<ScoopfileApp>,
+}
+
+/// Emitted once per package while a scoopfile is imported.
+#[derive(Serialize, Debug, Clone)]
+pub struct ImportProgressEvent {
+    pub current: usize,
+    pub total: usize,
+    pub name: String,
+    /// `"installed"`, `"skipped"` or `"failed"`.
+    pub status: String,
+}
+
+/// A bucket or package that could not be imported.
+#[derive(Serialize, Debug, Clone)]
+pub struct ImportFailure {
+    pub name: String,
+    pub error: String,
+}
+
+/// Summary returned by `import_scoopfile`.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ImportSummary {
+    pub installed: Vec<String>,
+    /// Packages that were already installed.
+    pub skipped: Vec<String>,
+    pub failures: Vec<ImportFailure>,
+}
+
+pub(crate) fn read_scoopfile(path: &str) -> Result<Scoopfile, String> {
+    let content =
+        fs::read_to_string(path).map_err(|e| format!("Failed to read scoopfile: {}", e))?;
+    // `scoop export` writes UTF-8 with a BOM on Windows PowerShell
+    serde_json::from_str(content.trim_start_matches('\u{feff}'))
+        .map_err(|e| format!("Failed to parse scoopfile: {}", e))
+}
+
+/// Writes the installed buckets and packages to `path` and returns the path.
+///
+/// Only cached package data and local bucket metadata are used, so this returns
+/// promptly without touching the network.
+#[tauri::command]
+pub async fn export_scoopfile(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    path: String,
+) -> Result<String, String> {
+    let packages = get_installed_packages_full(app.clone(), state.clone()).await?;
+    let held: HashSet<String> = crate::commands::hold::list_held_packages(app, state.clone())
+        .await?
+        .into_iter()
+        .map(|held| held.name)
+        .collect();
+    let buckets_path = state.scoop_path().join("buckets");
+    let buckets = if buckets_path.is_dir() {
+        scan_buckets(&buckets_path)?
+    } else {
+        Vec::new()
+    };
+    let scoopfile = Scoopfile {
+        buckets: buckets
+            .into_iter()
+            .map(|bucket| ScoopfileBucket {
+                name: bucket.name,
+                source: bucket.git_url.unwrap_or_default(),
+            })
+            .collect(),
+        apps: packages
+            .into_iter()
+            .map(|package| ScoopfileApp {
+                info: if held.contains(&package.name) {
+                    "Held package".to_string()
+                } else {
+                    String::new()
+                },
+                name: package.name,
+                version: package.version,
+                source: package.source,
+            })
+            .collect(),
+    };
+
+    let json = serde_json::to_string_pretty(&scoopfile)
+        .map_err(|e| format!("Failed to serialize scoopfile: {}", e))?;
+    fs::write(&path, json).map_err(|e| format!("Failed to write scoopfile: {}", e))?;
+
+    log::info!(
+        "Exported {} buckets and {} apps to {}",
+        scoopfile.buckets.len(),
+        scoopfile.apps.len(),
+        path
+    );
+    Ok(path)
+}
+
+/// Restores the buckets and packages listed in a scoopfile.
+///
+/// Missing buckets are added first, then missing packages are installed one at a
+/// time with an `import-progress` event after each. Failures are collected in the
+/// returned summary instead of aborting the import.
+#[tauri::command]
+pub async fn import_scoopfile(
+    window: Window,
+    app: AppHandle,
+    state: State<'_, AppState>,
+    path: String,
+) -> Result<ImportSummary, String> {
+    let scoopfile = read_scoopfile(&path)?;
+    let mut summary = ImportSummary::default();
+
+    let buckets_path = state.scoop_path().join("buckets");
+    for bucket in &scoopfile.buckets {
+        if buckets_path.join(&bucket.name).is_dir() {
+            continue;
+        }
+        if bucket.source.is_empty() {
+            summary.failures.push(ImportFailure {
+                name: bucket.name.clone(),
+                error: "Bucket has no source URL".to_string(),
+            });
+            continue;
+        }
+
+        let result = bucket_install::install_bucket(
+            app.clone(),
+            BucketInstallOptions {
+                name: bucket.name.clone(),
+                url: bucket.source.clone(),
+                force: false,
+            },
+        )
+        .await;
+        match result {
+            Ok(result) if result.success => {}
+            Ok(result) => summary.failures.push(ImportFailure {
+                name: bucket.name.clone(),
+                error: result.message,
+            }),
+            Err(error) => summary.failures.push(ImportFailure {
+                name: bucket.name.clone(),
+                error: error.to_string(),
+            }),
+        }
+    }
+
+    let installed: HashSet<String> = get_installed_packages_full(app.clone(), state.clone())
+        .await?
+        .into_iter()
+        .map(|p| p.name.to_lowercase())
+        .collect();
+
+    let total = scoopfile.apps.len();
+    // Custom installs have no bucket to install from; let Scoop resolve them by name
+    for (index, entry) in scoopfile.apps.iter().enumerate() {
+        let status = if installed.contains(&entry.name.to_lowercase()) {
+            summary.skipped.push(entry.name.clone());
+            "skipped"
+        } else {
+            let result = crate::commands::install::install_package(
+                window.clone(),
+                app.clone(),
+                state.clone(),
+                entry.name.clone(),
+                if entry.source == "Custom" {
+                    String::new()
+                } else {
+                    entry.source.clone()
+                },
+                None,
+            )
+            .await;
+            match result {
+                Ok(()) => {
+                    if entry.info.contains("Held package") {
+                        if let Err(e) = crate::commands::hold::hold_package(
+                            app.clone(),
+                            state.clone(),
+                            entry.name.clone(),
+                        )
+                        .await
+                        {
+                            log::warn!("Failed to hold imported package {}: {}", entry.name, e);
+                        }
+                    }
+                    summary.installed.push(entry.name.clone());
+                    "installed"
+                }
+                Err(error) => {
+                    summary.failures.push(ImportFailure {
+                        name: entry.name.clone(),
+                        error: error.to_string(),
+                    });
+                    "failed"
+                }
+            }
+        };
+
+        let _ = app.emit(
+            EVENT_IMPORT_PROGRESS,
+            ImportProgressEvent {
+                current: index + 1,
+                total,
+                name: entry.name.clone(),
+                status: status.to_string(),
+            },
+        );
+    }
+
+    log::info!(
+        "Imported scoopfile {}: {} installed, {} skipped, {} failed",
+        path,
+        summary.installed.len(),
+        summary.skipped.len(),
+        summary.failures.len()
+    );
+    Ok(summary)
+}
+
+/// A package present in both scoopfiles with different versions.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ScoopfileVersionDiff {
+    pub name: String,
+    pub version_a: String,
+    pub version_b: String,
+}
+
+/// Differences between the apps of two scoopfiles.
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct ScoopfileDiff {
+    pub only_in_a: Vec<String>,
+    pub only_in_b: Vec<String>,
+    pub version_diffs: Vec<ScoopfileVersionDiff>,
+}
+
+/// Compares the apps of two scoopfiles by case-insensitive name.
+fn diff_scoopfile_apps(a: &Scoopfile, b: &Scoopfile) -> ScoopfileDiff {
+    let index = |file: &Scoopfile| -> BTreeMap<String, ScoopfileApp> {
+        file.apps
+            .iter()
+            .map(|app| (app.name.to_lowercase(), app.clone()))
+            .collect()
+    };
+    let apps_a = index(a);
+    let apps_b = index(b);
+
+    let mut diff = ScoopfileDiff::default();
+    for (key, app_a) in &apps_a {
+        match apps_b.get(key) {
+            None => diff.only_in_a.push(app_a.name.clone()),
+            Some(app_b) if app_a.version != app_b.version => {
+                diff.version_diffs.push(ScoopfileVersionDiff {
+                    name: app_a.name.clone(),
+                    version_a: app_a.version.clone(),
+                    version_b: app_b.version.clone(),
+                })
+            }
+            Some(_) => {}
+        }
+    }
+    diff.only_in_b = apps_b
+        .iter()
+        .filter(|(key, _)| !apps_a.contains_key(*key))
+        .map(|(_, app)| app.name.clone())
+        .collect();
+
+    diff
+}
+
+/// Compares two scoopfiles, e.g. the export of an old and a new machine.
+#[tauri::command]
+pub fn diff_scoopfiles(a: String, b: String) -> Result<ScoopfileDiff, String> {
+    let file_a = read_scoopfile(&a)?;
+    let file_b = read_scoopfile(&b)?;
+    Ok(diff_scoopfile_apps(&file_a, &file_b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scoopfile(apps: &[(&str, &str)]) -> Scoopfile {
+        Scoopfile {
+            buckets: vec![],
+            apps: apps
+                .iter()
+                .map(|(name, version)| ScoopfileApp {
+                    name: name.to_string(),
+                    version: version.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn reports_unique_apps_and_version_mismatches() {
+        let a = scoopfile(&[("git", "2.40.0"), ("7zip", "23.01"), ("nodejs", "20.0.0")]);
+        let b = scoopfile(&[("Git", "2.41.0"), ("7zip", "23.01"), ("python", "3.12.0")]);
+
+        let diff = diff_scoopfile_apps(&a, &b);
+
+        assert_eq!(diff.only_in_a, vec!["nodejs".to_string()]);
+        assert_eq!(diff.only_in_b, vec!["python".to_string()]);
+        assert_eq!(
+            diff.version_diffs,
+            vec![ScoopfileVersionDiff {
+                name: "git".to_string(),
+                version_a: "2.40.0".to_string(),
+                version_b: "2.41.0".to_string(),
+            }]
+        );
+    }
+}
@@ -12,10 +12,13 @@ use std::future::Future;
 use std::path::{Path, PathBuf};
 use std::pin::Pin;
 use std::sync::Arc;
-use tauri::Manager;
+use tauri::{Emitter, Manager};
 use tauri_plugin_store::StoreExt;
 use tokio::sync::{Mutex, Notify};
 
+pub const EVENT_SEARCH_RESULT: &str = "search-result";
+pub const EVENT_SEARCH_COMPLETE: &str = "search-complete";
+
 type ManifestCache = Arc<Vec<CachedManifest>>;
 type ManifestBucketCache = HashMap<String, Arc<Vec<CachedManifest>>>;
 
@@ -25,6 +28,22 @@ struct ManifestCacheSnapshot {
     flat: ManifestCache,
 }
 
+/// Emitted by `search_scoop_streaming` for each bucket with matches.
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct SearchResultEvent {
+    pub search_id: String,
+    pub bucket: String,
+    pub packages: Vec<ScoopPackage>,
+}
+
+/// Emitted by `search_scoop_streaming` once every bucket has been scanned.
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct SearchCompleteEvent {
+    pub search_id: String,
+    pub total: usize,
+    pub is_cold: bool,
+}
+
 #[derive(serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PackageBucketContext {
@@ -32,6 +51,68 @@ pub struct PackageBucketContext {
     pub candidate_buckets: Vec<String>,
 }
 
+/// A package whose manifest exists in more than one bucket.
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BucketConflict {
+    pub name: String,
+    pub buckets: Vec<String>,
+    /// The bucket the installed copy tracks, if the package is installed.
+    pub installed_bucket: Option<String>,
+}
+
+/// Groups the manifests of all buckets by package name and keeps the names
+/// found in more than one bucket. Buckets are listed in alphabetical order.
+fn collect_bucket_conflicts(buckets_dir: &Path) -> Result<Vec<BucketConflict>, String> {
+    if !buckets_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let entries = std::fs::read_dir(buckets_dir).map_err(|e| {
+        format!(
+            "Failed to read buckets directory '{}': {}",
+            buckets_dir.display(),
+            e
+        )
+    })?;
+
+    let mut by_name: std::collections::BTreeMap<String, BucketConflict> = Default::default();
+    for entry in entries.flatten() {
+        let bucket_path = entry.path();
+        let Some(bucket_name) = bucket_path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .map(str::to_string)
+        else {
+            continue;
+        };
+
+        for manifest_path in find_manifests_in_bucket(bucket_path) {
+            let Some(name) = manifest_path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            by_name
+                .entry(name.to_lowercase())
+                .or_insert_with(|| BucketConflict {
+                    name: name.to_string(),
+                    buckets: Vec::new(),
+                    installed_bucket: None,
+                })
+                .buckets
+                .push(bucket_name.clone());
+        }
+    }
+
+    Ok(by_name
+        .into_values()
+        .filter(|conflict| conflict.buckets.len() > 1)
+        .map(|mut conflict| {
+            conflict.buckets.sort();
+            conflict
+        })
+        .collect())
+}
+
 fn collect_candidate_buckets(
     buckets_dir: &Path,
     normalized_name: &str,
@@ -239,6 +320,25 @@ fn extract_bin_values(value: &Value, output: &mut Vec<String>) {
     }
 }
 
+/// Reduces a `bin` entry or user query to a comparable binary name: the file name without
+/// its directory and without a trailing `.exe`.
+fn normalize_binary_name(value: &str) -> String {
+    let file_name = value.rsplit(['/', '\\']).next().unwrap_or(value);
+    let normalized = normalize_search_text(file_name);
+    match normalized.strip_suffix(".exe") {
+        Some(stem) => stem.to_string(),
+        None => normalized,
+    }
+}
+
+/// Returns whether a manifest's `bin` entries provide the given normalized binary name.
+fn manifest_provides_binary(manifest: &CachedManifest, binary: &str) -> bool {
+    manifest
+        .normalized_bins
+        .iter()
+        .any(|value| normalize_binary_name(value) == binary)
+}
+
 fn parse_cached_manifest(path: &Path) -> Option<CachedManifest> {
     let file_name = path.file_stem().and_then(|s| s.to_str())?.to_string();
     let content = std::fs::read_to_string(path).ok()?;
@@ -337,7 +437,8 @@ fn flatten_manifest_cache(buckets: &ManifestBucketCache) -> ManifestCache {
     Arc::new(flat)
 }
 
-async fn populate_manifest_cache(scoop_path: &Path) -> Result<ManifestCacheSnapshot, String> {
+/// Lists the bucket directories under the Scoop root, sorted by name.
+async fn list_bucket_dirs(scoop_path: &Path) -> Result<Vec<PathBuf>, String> {
     let buckets_path = scoop_path.join("buckets");
     if !tokio::fs::try_exists(&buckets_path).await.unwrap_or(false) {
         return Err("Scoop buckets directory not found".to_string());
@@ -346,25 +447,65 @@ async fn populate_manifest_cache(scoop_path: &Path) -> Result<ManifestCacheSnaps
     let mut read_dir = tokio::fs::read_dir(&buckets_path)
         .await
         .map_err(|e| format!("Failed to read buckets directory: {}", e))?;
-    let mut manifest_paths = Vec::new();
+    let mut bucket_dirs = Vec::new();
 
     while let Ok(Some(entry)) = read_dir.next_entry().await {
         if entry.path().is_dir() {
-            manifest_paths.extend(find_manifests_in_bucket(entry.path()));
+            bucket_dirs.push(entry.path());
         }
     }
 
+    bucket_dirs.sort();
+    Ok(bucket_dirs)
+}
+
+async fn populate_manifest_cache(scoop_path: &Path) -> Result<ManifestCacheSnapshot, String> {
+    let bucket_dirs = list_bucket_dirs(scoop_path).await?;
     tokio::task::spawn_blocking(move || {
-        let manifests = manifest_paths
-            .par_iter()
-            .filter_map(|path| parse_cached_manifest(path))
-            .collect::<Vec<_>>();
-        build_manifest_cache_snapshot(manifests)
+        build_manifest_cache_snapshot_from_bucket_paths(bucket_dirs)
     })
     .await
     .map_err(|e| e.to_string())
 }
 
+/// Takes over populating the cold manifest cache, unless it is already warm or
+/// another caller is populating it. Returns the notify and generation to hand
+/// to [`release_manifest_population`].
+async fn claim_manifest_population() -> Option<(Arc<Notify>, u64)> {
+    let mut state = MANIFEST_CACHE_STATE.lock().await;
+    if state.cache.is_some() || state.populating.is_some() {
+        return None;
+    }
+    let notify = Arc::new(Notify::new());
+    state.populating = Some(notify.clone());
+    Some((notify, state.generation))
+}
+
+/// Ends a population claimed with [`claim_manifest_population`], storing the
+/// loaded buckets unless the cache was invalidated in the meantime.
+async fn release_manifest_population(
+    claim: Option<(Arc<Notify>, u64)>,
+    buckets: Option<ManifestBucketCache>,
+) {
+    let Some((notify, generation)) = claim else {
+        return;
+    };
+
+    let mut state = MANIFEST_CACHE_STATE.lock().await;
+    let is_active_population = state
+        .populating
+        .as_ref()
+        .is_some_and(|active| Arc::ptr_eq(active, &notify));
+    if is_active_population {
+        if let Some(buckets) = buckets.filter(|_| state.generation == generation) {
+            state.cache = Some(flatten_manifest_cache(&buckets));
+            state.buckets = buckets;
+        }
+        state.populating = None;
+    }
+    notify.notify_waiters();
+}
+
 async fn get_or_populate_manifests_singleflight<F, Fut>(
     populate: F,
 ) -> Result<(ManifestCache, bool), String>
@@ -469,6 +610,59 @@ fn match_query(value: &str, query: &SearchQuery) -> bool {
     }
 }
 
+/// Returns the package for a manifest that matches the query by name or binary.
+fn match_manifest(manifest: &CachedManifest, query: &SearchQuery) -> Option<ScoopPackage> {
+    let match_source = if match_query(&manifest.normalized_name, query) {
+        MatchSource::Name
+    } else if manifest
+        .normalized_bins
+        .iter()
+        .any(|value| match_query(value, query))
+    {
+        MatchSource::Binary
+    } else {
+        return None;
+    };
+
+    let mut pkg = manifest.package.clone();
+    pkg.match_source = match_source;
+    Some(pkg)
+}
+
+/// Maps the lowercased names of installed packages to their bucket.
+async fn installed_sources<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+) -> Option<HashMap<String, String>> {
+    let state = app.state::<AppState>();
+    let installed_pkgs = get_installed_packages_full(app.clone(), state).await.ok()?;
+    Some(
+        installed_pkgs
+            .into_iter()
+            .map(|p| (p.name.to_lowercase(), p.source))
+            .collect(),
+    )
+}
+
+fn apply_installed_sources(installed_map: &HashMap<String, String>, packages: &mut [ScoopPackage]) {
+    for pkg in packages.iter_mut() {
+        if let Some(installed_source) = installed_map.get(&pkg.name.to_lowercase()) {
+            pkg.is_installed = true;
+            pkg.is_installed_from_current_bucket =
+                installed_source.eq_ignore_ascii_case(&pkg.source);
+        }
+    }
+}
+
+/// Flags the packages that are already installed, and whether from the same bucket.
+async fn mark_installed_packages<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    packages: &mut [ScoopPackage],
+) {
+    if let Some(installed_map) = installed_sources(app).await {
+        apply_installed_sources(&installed_map, packages);
+    }
+}
+
 /// Searches for Scoop packages based on a search term.
 #[tauri::command]
 pub async fn search_scoop<R: tauri::Runtime>(
@@ -505,49 +699,13 @@ pub async fn search_scoop<R: tauri::Runtime>(
     let mut packages: Vec<ScoopPackage> = tokio::task::spawn_blocking(move || {
         manifest_cache
             .par_iter()
-            .filter_map(|manifest| {
-                let name_matches = match_query(&manifest.normalized_name, &query);
-                let match_source = if name_matches {
-                    MatchSource::Name
-                } else if manifest
-                    .normalized_bins
-                    .iter()
-                    .any(|value| match_query(value, &query))
-                {
-                    MatchSource::Binary
-                } else {
-                    MatchSource::None
-                };
-
-                if match_source == MatchSource::None {
-                    return None;
-                }
-
-                let mut pkg = manifest.package.clone();
-                pkg.match_source = match_source;
-                Some(pkg)
-            })
+            .filter_map(|manifest| match_manifest(manifest, &query))
             .collect()
     })
     .await
     .map_err(|e| e.to_string())?;
 
-    // Determine which packages are already installed
-    let state = app.state::<AppState>();
-    if let Ok(installed_pkgs) = get_installed_packages_full(app.clone(), state).await {
-        let installed_map: HashMap<String, String> = installed_pkgs
-            .into_iter()
-            .map(|p| (p.name.to_lowercase(), p.source))
-            .collect();
-
-        for pkg in &mut packages {
-            if let Some(installed_source) = installed_map.get(&pkg.name.to_lowercase()) {
-                pkg.is_installed = true;
-                pkg.is_installed_from_current_bucket =
-                    installed_source.eq_ignore_ascii_case(&pkg.source);
-            }
-        }
-    }
+    mark_installed_packages(&app, &mut packages).await;
 
     let total_time = search_start.elapsed();
     log::info!(
@@ -560,6 +718,216 @@ pub async fn search_scoop<R: tauri::Runtime>(
     Ok(SearchResult { packages, is_cold })
 }
 
+/// Emits the results of one `search_scoop_streaming` call, tagged with its id.
+struct SearchResultEmitter<R: tauri::Runtime> {
+    app: tauri::AppHandle<R>,
+    search_id: String,
+    /// Installed packages lookup, only awaited once the first matches are ready.
+    installed_task: Option<tauri::async_runtime::JoinHandle<HashMap<String, String>>>,
+    installed_map: HashMap<String, String>,
+    total: usize,
+}
+
+impl<R: tauri::Runtime> SearchResultEmitter<R> {
+    fn new(app: tauri::AppHandle<R>, search_id: String) -> Self {
+        let installed_app = app.clone();
+        let installed_task = tauri::async_runtime::spawn(async move {
+            installed_sources(&installed_app).await.unwrap_or_default()
+        });
+        Self {
+            app,
+            search_id,
+            installed_task: Some(installed_task),
+            installed_map: HashMap::new(),
+            total: 0,
+        }
+    }
+
+    async fn emit_bucket(&mut self, bucket: String, mut packages: Vec<ScoopPackage>) {
+        if packages.is_empty() {
+            return;
+        }
+        if let Some(task) = self.installed_task.take() {
+            self.installed_map = task.await.unwrap_or_default();
+        }
+        apply_installed_sources(&self.installed_map, &mut packages);
+        self.total += packages.len();
+        let _ = self.app.emit(
+            EVENT_SEARCH_RESULT,
+            SearchResultEvent {
+                search_id: self.search_id.clone(),
+                bucket,
+                packages,
+            },
+        );
+    }
+
+    fn emit_complete(&self, is_cold: bool) {
+        let _ = self.app.emit(
+            EVENT_SEARCH_COMPLETE,
+            SearchCompleteEvent {
+                search_id: self.search_id.clone(),
+                total: self.total,
+                is_cold,
+            },
+        );
+    }
+}
+
+/// Searches the warm manifest cache. The flat cache is ordered by bucket, so
+/// each chunk is one bucket's manifests.
+async fn stream_cached_manifests<R: tauri::Runtime>(
+    manifests: ManifestCache,
+    query: &Arc<SearchQuery>,
+    emitter: &mut SearchResultEmitter<R>,
+) -> Result<(), String> {
+    let mut start = 0;
+    while start < manifests.len() {
+        let bucket = manifests[start].package.source.clone();
+        let end = manifests[start..]
+            .iter()
+            .position(|manifest| manifest.package.source != bucket)
+            .map_or(manifests.len(), |offset| start + offset);
+
+        let chunk = manifests.clone();
+        let chunk_query = query.clone();
+        let packages: Vec<ScoopPackage> = tokio::task::spawn_blocking(move || {
+            chunk[start..end]
+                .par_iter()
+                .filter_map(|manifest| match_manifest(manifest, &chunk_query))
+                .collect()
+        })
+        .await
+        .map_err(|e| e.to_string())?;
+        start = end;
+
+        emitter.emit_bucket(bucket, packages).await;
+    }
+    Ok(())
+}
+
+/// Loads and searches one bucket directory at a time, so a cold search emits
+/// each bucket's matches as soon as that bucket is parsed. Returns the loaded
+/// buckets for the manifest cache.
+async fn stream_bucket_dirs<R: tauri::Runtime>(
+    scoop_path: &Path,
+    query: &Arc<SearchQuery>,
+    emitter: &mut SearchResultEmitter<R>,
+) -> Result<ManifestBucketCache, String> {
+    let mut buckets = ManifestBucketCache::new();
+    for bucket_path in list_bucket_dirs(scoop_path).await? {
+        let bucket = bucket_path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let bucket_query = query.clone();
+        let (snapshot, packages) = tokio::task::spawn_blocking(move || {
+            let snapshot = build_manifest_cache_snapshot_from_bucket_paths(vec![bucket_path]);
+            let packages: Vec<ScoopPackage> = snapshot
+                .flat
+                .par_iter()
+                .filter_map(|manifest| match_manifest(manifest, &bucket_query))
+                .collect();
+            (snapshot, packages)
+        })
+        .await
+        .map_err(|e| e.to_string())?;
+
+        buckets.extend(snapshot.buckets);
+        emitter.emit_bucket(bucket, packages).await;
+    }
+    Ok(buckets)
+}
+
+/// Like `search_scoop`, but emits a `search-result` event for each bucket as
+/// its matches are found and a final `search-complete` event with the total,
+/// so the first results show up before every bucket has been scanned.
+///
+/// Every event carries `search_id`, so the frontend can drop events from a
+/// search it has already replaced.
+#[tauri::command]
+pub async fn search_scoop_streaming<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    query: String,
+    search_id: String,
+) -> Result<(), String> {
+    if query.is_empty() {
+        let _ = app.emit(
+            EVENT_SEARCH_COMPLETE,
+            SearchCompleteEvent {
+                search_id,
+                total: 0,
+                is_cold: false,
+            },
+        );
+        return Ok(());
+    }
+
+    let parsed = Arc::new(parse_search_query(&query)?);
+    let mut emitter = SearchResultEmitter::new(app.clone(), search_id);
+
+    let cached = MANIFEST_CACHE_STATE.lock().await.cache.clone();
+    let is_cold = match cached {
+        Some(manifests) => {
+            stream_cached_manifests(manifests, &parsed, &mut emitter).await?;
+            false
+        }
+        None => {
+            // Fill the cache from this scan, unless another caller already is
+            let claim = claim_manifest_population().await;
+            let scoop_path = app.state::<AppState>().scoop_path();
+            let scanned = stream_bucket_dirs(&scoop_path, &parsed, &mut emitter).await;
+            release_manifest_population(claim, scanned.as_ref().ok().cloned()).await;
+            scanned?;
+            true
+        }
+    };
+
+    log::info!(
+        "search_scoop_streaming: Found {} packages matching '{}'",
+        emitter.total,
+        query
+    );
+    emitter.emit_complete(is_cold);
+    Ok(())
+}
+
+/// Finds the packages whose manifest `bin` entries provide the given binary.
+///
+/// Matching is case-insensitive and ignores a `.exe` suffix on either side, so `rg`
+/// and `RG.exe` both find the package shipping `rg.exe`.
+#[tauri::command]
+pub async fn find_by_binary<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    binary: String,
+) -> Result<Vec<ScoopPackage>, String> {
+    let binary = normalize_binary_name(&binary);
+    if binary.is_empty() {
+        return Ok(vec![]);
+    }
+
+    log::info!("find_by_binary: Looking up packages providing '{}'", binary);
+
+    let (manifests, _) = get_manifests(app.clone()).await?;
+    let mut packages: Vec<ScoopPackage> = tokio::task::spawn_blocking(move || {
+        manifests
+            .par_iter()
+            .filter(|manifest| manifest_provides_binary(manifest, &binary))
+            .map(|manifest| {
+                let mut pkg = manifest.package.clone();
+                pkg.match_source = MatchSource::Binary;
+                pkg
+            })
+            .collect()
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+
+    mark_installed_packages(&app, &mut packages).await;
+
+    Ok(packages)
+}
+
 /// Returns the current installed bucket and exact candidate buckets for a package.
 /// This is intentionally scoped to the change-bucket flow and avoids warming the
 /// global search manifest cache for a single-package lookup.
@@ -596,6 +964,90 @@ pub async fn get_package_buckets<R: tauri::Runtime>(
     })
 }
 
+/// Lists packages defined in more than one bucket, which can make updates
+/// switch between buckets. Pinning a bucket with `change_package_bucket`
+/// resolves this for installed packages.
+#[tauri::command]
+pub async fn find_bucket_conflicts<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Vec<BucketConflict>, String> {
+    let scoop_path = app.state::<AppState>().scoop_path();
+
+    tokio::task::spawn_blocking(move || {
+        let mut conflicts = collect_bucket_conflicts(&scoop_path.join("buckets"))?;
+        for conflict in &mut conflicts {
+            conflict.installed_bucket =
+                utils::get_installed_package_bucket(&scoop_path, &conflict.name);
+        }
+
+        log::info!(
+            "Found {} packages defined in multiple buckets",
+            conflicts.len()
+        );
+        Ok(conflicts)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Returns whether a manifest file stem is `name` itself or a versioned copy of
+/// it such as `name-1.2.3` or `name311`, as kept in the `versions` bucket.
+fn is_versioned_manifest_of(stem: &str, name: &str) -> bool {
+    let stem = stem.to_ascii_lowercase();
+    let Some(rest) = stem.strip_prefix(&name.to_ascii_lowercase()) else {
+        return false;
+    };
+    let rest = rest.strip_prefix('-').unwrap_or(rest);
+    rest.is_empty()
+        || (rest.starts_with(|c: char| c.is_ascii_digit())
+            && rest.chars().all(|c| c.is_ascii_digit() || c == '.'))
+}
+
+/// Collects the versions of all manifests for `name` across the buckets, newest first.
+fn collect_available_versions(buckets_dir: &Path, name: &str) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(buckets_dir) else {
+        return Vec::new();
+    };
+
+    let mut versions: Vec<String> = entries
+        .flatten()
+        .flat_map(|entry| find_manifests_in_bucket(entry.path()))
+        .filter(|path| {
+            path.file_stem()
+                .and_then(|stem| stem.to_str())
+                .is_some_and(|stem| is_versioned_manifest_of(stem, name))
+        })
+        .filter_map(|path| {
+            let content = fs::read_to_string(&path).ok()?;
+            let json: Value = serde_json::from_str(content.trim_start_matches('\u{feff}')).ok()?;
+            json.get("version")?.as_str().map(str::to_string)
+        })
+        .collect();
+
+    versions.sort_by(|a, b| utils::compare_versions(b, a));
+    versions.dedup();
+    versions
+}
+
+/// Lists the versions of a package that can be installed from the local buckets,
+/// including historical ones from the `versions` bucket. Installed versions are
+/// listed by `get_package_versions` instead.
+#[tauri::command]
+pub async fn get_available_versions<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    name: String,
+) -> Result<Vec<String>, String> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err("A package name is required.".to_string());
+    }
+    let buckets_dir = app.state::<AppState>().scoop_path().join("buckets");
+
+    tokio::task::spawn_blocking(move || collect_available_versions(&buckets_dir, &name))
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[cfg(test)]
 mod search_tests;
 
@@ -639,6 +1091,19 @@ pub async fn warm_manifest_cache<R: tauri::Runtime>(
     }
 }
 
+/// Drops the manifest cache and loads it again, regardless of the prebuild setting.
+/// Returns the number of manifests loaded.
+pub(crate) async fn rebuild_manifest_cache<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<usize, String> {
+    invalidate_manifest_cache_with_scope(ManifestCacheInvalidation::All {
+        reason: "cache rebuild",
+    })
+    .await;
+    let (manifests, _) = get_manifests(app).await?;
+    Ok(manifests.len())
+}
+
 fn build_manifest_cache_snapshot_from_bucket_paths(
     bucket_paths: Vec<PathBuf>,
 ) -> ManifestCacheSnapshot {
@@ -1,6 +1,8 @@
 use super::{
-    collect_candidate_buckets, get_or_populate_manifests_singleflight, invalidate_manifest_cache,
-    normalize_bucket_scope, CachedManifest, ManifestCacheInvalidation, ManifestCacheSnapshot,
+    collect_available_versions, collect_bucket_conflicts, collect_candidate_buckets,
+    get_or_populate_manifests_singleflight, invalidate_manifest_cache, manifest_provides_binary,
+    normalize_binary_name, normalize_bucket_scope, CachedManifest, ManifestCacheInvalidation,
+    ManifestCacheSnapshot,
 };
 use crate::models::{MatchSource, ScoopPackage};
 use once_cell::sync::Lazy;
@@ -30,6 +32,10 @@ fn manifest(bucket: &str, name: &str) -> CachedManifest {
             installation_type: Default::default(),
             has_multiple_versions: false,
             local_latest_version: None,
+            architecture: None,
+            scope: Default::default(),
+            health: Default::default(),
+            user_note: None,
         },
         normalized_name: name.to_string(),
         normalized_bins: vec![],
@@ -88,6 +94,58 @@ fn collects_candidate_buckets_from_root_and_nested_bucket_dirs() {
     assert_eq!(candidates, vec!["alpha".to_string(), "beta".to_string()]);
 }
 
+#[test]
+fn lists_versions_from_versioned_manifests_newest_first() {
+    let temp_dir = tempdir().expect("temp dir");
+    let buckets_dir = temp_dir.path().join("buckets");
+    for (bucket, name, version) in [
+        ("main", "python.json", "3.12.1"),
+        ("versions", "python311.json", "3.11.7"),
+        ("versions", "python-3.8.10.json", "3.8.10"),
+        ("versions", "python-lts.json", "3.10.0"),
+        ("extras", "pythonnet.json", "1.0.0"),
+    ] {
+        let dir = buckets_dir.join(bucket).join("bucket");
+        fs::create_dir_all(&dir).expect("bucket dir");
+        fs::write(dir.join(name), format!(r#"{{"version": "{}"}}"#, version))
+            .expect("write manifest");
+    }
+
+    assert_eq!(
+        collect_available_versions(&buckets_dir, "python"),
+        vec!["3.12.1", "3.11.7", "3.8.10"]
+    );
+}
+
+#[test]
+fn reports_packages_defined_in_several_buckets() {
+    let temp_dir = tempdir().expect("temp dir");
+    let buckets_dir = temp_dir.path().join("buckets");
+    for (bucket, name) in [
+        ("versions", "git.json"),
+        ("main", "git.json"),
+        ("main", "7zip.json"),
+        ("extras", "Git.json"),
+    ] {
+        let dir = buckets_dir.join(bucket).join("bucket");
+        fs::create_dir_all(&dir).expect("bucket dir");
+        fs::write(dir.join(name), "{}").expect("write manifest");
+    }
+
+    let conflicts = collect_bucket_conflicts(&buckets_dir).expect("collect conflicts");
+
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].name.to_lowercase(), "git");
+    assert_eq!(
+        conflicts[0].buckets,
+        vec![
+            "extras".to_string(),
+            "main".to_string(),
+            "versions".to_string()
+        ]
+    );
+}
+
 #[test]
 fn bucket_scope_logs_are_stable_and_deduplicated() {
     let bucket_names = normalize_bucket_scope(["main", " extras ", "main", ""]);
@@ -349,3 +407,21 @@ async fn bucket_scope_refresh_replaces_only_changed_bucket() {
     assert_eq!(package_names, vec!["alpha", "beta-new"]);
     invalidate_manifest_cache().await;
 }
+
+#[test]
+fn binary_lookup_ignores_case_directories_and_exe_suffix() {
+    let mut ripgrep = manifest("main", "ripgrep");
+    ripgrep.normalized_bins = vec!["rg.exe".to_string()];
+    let mut aliased = manifest("extras", "tool");
+    aliased.normalized_bins = vec!["bin\\tool-cli.exe".to_string(), "tl".to_string()];
+
+    let provides = |manifest: &CachedManifest, query: &str| {
+        manifest_provides_binary(manifest, &normalize_binary_name(query))
+    };
+
+    assert!(provides(&ripgrep, "rg"));
+    assert!(provides(&ripgrep, "RG.EXE"));
+    assert!(provides(&aliased, "tool-cli"));
+    assert!(provides(&aliased, "tl.exe"));
+    assert!(!provides(&ripgrep, "rga"));
+}
@@ -218,3 +218,59 @@ pub async fn update_pailer_self<R: Runtime>(app: AppHandle<R>) -> Result<String,
 pub fn can_self_update() -> bool {
     utils::is_scoop_installation()
 }
+
+/// An available Pailer update, or how Pailer is updated when Scoop manages it.
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct AppUpdateInfo {
+    /// The newer version; `None` when Scoop manages the install.
+    pub version: Option<String>,
+    pub notes: Option<String>,
+    pub scoop_managed: bool,
+}
+
+/// Checks whether a newer Pailer is available without installing it.
+///
+/// Standalone installs query the updater plugin and return `None` when up to
+/// date. Scoop installs don't register the updater, so they get a note that
+/// updates come through `scoop update` instead.
+#[tauri::command]
+pub async fn check_app_update<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<Option<AppUpdateInfo>, String> {
+    if utils::is_scoop_managed_executable() {
+        return Ok(Some(AppUpdateInfo {
+            version: None,
+            notes: Some(
+                "Pailer is installed with Scoop; update it with `scoop update pailer`.".to_string(),
+            ),
+            scoop_managed: true,
+        }));
+    }
+
+    #[cfg(windows)]
+    {
+        use tauri_plugin_updater::UpdaterExt;
+
+        let update = app
+            .updater()
+            .map_err(|e| format!("Failed to initialize updater: {}", e))?
+            .check()
+            .await
+            .map_err(|e| format!("Failed to check for updates: {}", e))?;
+
+        Ok(update.map(|update| {
+            log::info!("Pailer update available: {}", update.version);
+            AppUpdateInfo {
+                version: Some(update.version),
+                notes: update.body,
+                scoop_managed: false,
+            }
+        }))
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = app;
+        Ok(None)
+    }
+}
@@ -18,20 +18,37 @@ const STORE_PATH: &str = "settings.json";
 /// Legacy store file name (for migration)
 const LEGACY_STORE_PATH: &str = "core.json";
 
-/// Migrates data from legacy store.json to core.json if needed.
-/// Returns true if migration was performed.
-fn migrate_from_legacy_store<R: Runtime>(app: &AppHandle<R>) -> bool {
+/// Outcome of migrating the legacy store file.
+#[derive(serde::Serialize, Debug, Clone, Default)]
+pub struct StoreMigrationStatus {
+    /// Whether the legacy store was copied to the current store file.
+    pub migrated: bool,
+    pub legacy_existed: bool,
+    pub error: Option<String>,
+}
+
+/// Migrates data from the legacy core.json to settings.json if needed.
+fn migrate_from_legacy_store<R: Runtime>(app: &AppHandle<R>) -> StoreMigrationStatus {
     let app_data_dir = match app.path().app_data_dir() {
         Ok(dir) => dir,
-        Err(_) => return false,
+        Err(e) => {
+            return StoreMigrationStatus {
+                error: Some(format!("Failed to resolve app data directory: {}", e)),
+                ..Default::default()
+            }
+        }
     };
 
     let legacy_path = app_data_dir.join(LEGACY_STORE_PATH);
     let new_path = app_data_dir.join(STORE_PATH);
+    let mut status = StoreMigrationStatus {
+        legacy_existed: legacy_path.exists(),
+        ..Default::default()
+    };
 
     // Skip if new store already exists or legacy doesn't exist
-    if new_path.exists() || !legacy_path.exists() {
-        return false;
+    if new_path.exists() || !status.legacy_existed {
+        return status;
     }
 
     log::info!(
@@ -40,22 +57,39 @@ fn migrate_from_legacy_store<R: Runtime>(app: &AppHandle<R>) -> bool {
         STORE_PATH
     );
 
-    // Read legacy store content
-    if let Ok(content) = fs::read_to_string(&legacy_path) {
-        // Write to new location
-        if fs::write(&new_path, &content).is_ok() {
-            // Optionally remove legacy file after successful migration
-            // Keep it for now as a backup
+    // The legacy file is kept as a backup
+    match fs::read_to_string(&legacy_path).and_then(|content| fs::write(&new_path, content)) {
+        Ok(()) => {
             log::info!(
                 "Successfully migrated {} to {}",
                 LEGACY_STORE_PATH,
                 STORE_PATH
             );
-            return true;
+            status.migrated = true;
+        }
+        Err(e) => {
+            status.error = Some(format!(
+                "Failed to migrate {} to {}: {}",
+                LEGACY_STORE_PATH, STORE_PATH, e
+            ));
         }
     }
 
-    false
+    status
+}
+
+/// Runs the legacy store migration before a store access, warning when it fails.
+fn ensure_store_migrated<R: Runtime>(app: &AppHandle<R>) {
+    if let Some(error) = migrate_from_legacy_store(app).error {
+        log::warn!("{}", error);
+    }
+}
+
+/// Runs the legacy store migration explicitly and reports what happened, for
+/// diagnosing settings that appear to have been reset.
+#[tauri::command]
+pub fn migrate_settings_store(app: AppHandle) -> Result<StoreMigrationStatus, String> {
+    Ok(migrate_from_legacy_store(&app))
 }
 
 /// A helper function to reduce boilerplate when performing a write operation on the store.
@@ -66,7 +100,7 @@ where
     F: FnOnce(&Store<R>) -> T,
 {
     // Attempt migration from legacy store if needed
-    migrate_from_legacy_store(&app);
+    ensure_store_migrated(&app);
 
     let store = app
         .store(PathBuf::from(STORE_PATH))
@@ -82,7 +116,7 @@ where
     F: FnOnce(&Store<R>) -> T,
 {
     // Attempt migration from legacy store if needed
-    migrate_from_legacy_store(&app);
+    ensure_store_migrated(&app);
 
     let store = app
         .store(PathBuf::from(STORE_PATH))
@@ -93,7 +127,7 @@ where
 /// Reads the Scoop configuration file and returns its contents as a JSON map.
 ///
 /// If the file doesn't exist, it returns an empty map.
-fn read_scoop_config() -> Result<Map<String, Value>, String> {
+pub(crate) fn read_scoop_config() -> Result<Map<String, Value>, String> {
     let path = get_scoop_config_path()?;
     if !path.exists() {
         return Ok(Map::new());
@@ -107,7 +141,7 @@ fn read_scoop_config() -> Result<Map<String, Value>, String> {
 /// Writes the given JSON map to the Scoop configuration file.
 ///
 /// This will create the directory and file if they don't exist.
-fn write_scoop_config(config: &Map<String, Value>) -> Result<(), String> {
+pub(crate) fn write_scoop_config(config: &Map<String, Value>) -> Result<(), String> {
     let path = get_scoop_config_path()?;
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)
@@ -209,6 +243,51 @@ pub async fn set_scoop_path<R: Runtime>(app: AppHandle<R>, path: String) -> Resu
     Ok(normalized_path)
 }
 
+/// Settings key holding a custom global Scoop root.
+pub(crate) const GLOBAL_SCOOP_PATH_KEY: &str = "scoop.globalPath";
+
+/// Returns the configured global Scoop root, falling back to `%ProgramData%\scoop`.
+pub(crate) fn resolve_global_scoop_path<R: Runtime>(app: AppHandle<R>) -> Option<PathBuf> {
+    get_config_value(app, GLOBAL_SCOOP_PATH_KEY.to_string())
+        .ok()
+        .flatten()
+        .and_then(|value| value.as_str().map(str::trim).map(String::from))
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+        .or_else(crate::state::default_global_scoop_path)
+}
+
+/// Gets the global Scoop root used for global installs.
+#[tauri::command]
+pub fn get_global_scoop_path(state: tauri::State<'_, crate::state::AppState>) -> Option<String> {
+    state
+        .global_scoop_path()
+        .map(|path| path.to_string_lossy().to_string())
+}
+
+/// Validates and applies a global Scoop root. The frontend persists the value
+/// under `scoop.globalPath`.
+#[tauri::command]
+pub async fn set_global_scoop_path<R: Runtime>(
+    app: AppHandle<R>,
+    path: String,
+) -> Result<String, String> {
+    let normalized_path = normalize_scoop_path_input(&path)?;
+    let validation = validate_scoop_directory(normalized_path.clone())?;
+    // A global root only holds apps and shims; buckets and cache stay in the user root
+    if !validation.valid && !PathBuf::from(&normalized_path).join("apps").is_dir() {
+        return Err(validation.message);
+    }
+
+    if let Some(state) = app.try_state::<crate::state::AppState>() {
+        state
+            .set_global_scoop_path(Some(PathBuf::from(&normalized_path)))
+            .await;
+    }
+
+    Ok(normalized_path)
+}
+
 /// Validation result for Scoop directory checking
 #[derive(serde::Serialize)]
 pub struct ValidationResult {
@@ -491,6 +570,412 @@ pub fn set_virustotal_api_key(key: String) -> Result<(), String> {
     write_scoop_config(&config)
 }
 
+/// Where the effective Scoop cache directory comes from.
+#[derive(serde::Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ScoopCacheSource {
+    Env,
+    Config,
+    Default,
+}
+
+/// The effective Scoop cache directory.
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ScoopCacheDir {
+    pub path: String,
+    pub source: ScoopCacheSource,
+}
+
+fn resolve_cache_dir_from(
+    env_value: Option<String>,
+    config_value: Option<String>,
+    scoop_path: &std::path::Path,
+) -> (PathBuf, ScoopCacheSource) {
+    let non_empty = |value: Option<String>| {
+        value
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+    if let Some(path) = non_empty(env_value) {
+        (PathBuf::from(path), ScoopCacheSource::Env)
+    } else if let Some(path) = non_empty(config_value) {
+        (PathBuf::from(path), ScoopCacheSource::Config)
+    } else {
+        (scoop_path.join("cache"), ScoopCacheSource::Default)
+    }
+}
+
+/// Resolves the cache directory the way Scoop does: `SCOOP_CACHE`, then the
+/// `cache_path` config, then `<scoop>\cache`.
+pub(crate) fn resolve_scoop_cache_dir(scoop_path: &std::path::Path) -> (PathBuf, ScoopCacheSource) {
+    let config_value = read_scoop_config().ok().and_then(|config| {
+        config
+            .get("cache_path")
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+    });
+    resolve_cache_dir_from(std::env::var("SCOOP_CACHE").ok(), config_value, scoop_path)
+}
+
+/// Gets the effective Scoop cache directory and where it is configured.
+#[tauri::command]
+pub fn get_scoop_cache_dir(
+    state: tauri::State<'_, crate::state::AppState>,
+) -> Result<ScoopCacheDir, String> {
+    let (path, source) = resolve_scoop_cache_dir(&state.scoop_path());
+    Ok(ScoopCacheDir {
+        path: path.to_string_lossy().to_string(),
+        source,
+    })
+}
+
+fn ensure_writable_dir(path: &std::path::Path) -> Result<(), String> {
+    if !path.is_dir() {
+        return Err(format!("{} is not an existing directory", path.display()));
+    }
+    let probe = path.join(".pailer-write-test");
+    fs::write(&probe, b"")
+        .and_then(|_| fs::remove_file(&probe))
+        .map_err(|e| format!("{} is not writable: {}", path.display(), e))
+}
+
+/// Moves the files of `from` into `to` and returns the bytes moved. Files that
+/// already exist in `to` are left in `from`.
+fn move_cache_files(from: &std::path::Path, to: &std::path::Path) -> Result<u64, String> {
+    let Ok(entries) = fs::read_dir(from) else {
+        return Ok(0);
+    };
+
+    let mut moved = 0;
+    for entry in entries.flatten() {
+        let source = entry.path();
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if !metadata.is_file() {
+            continue;
+        }
+        let target = to.join(entry.file_name());
+        if target.exists() {
+            log::debug!("Keeping {}: already in new cache", source.display());
+            continue;
+        }
+
+        // Renames fail across volumes, so fall back to copying
+        if fs::rename(&source, &target).is_err() {
+            fs::copy(&source, &target)
+                .and_then(|_| fs::remove_file(&source))
+                .map_err(|e| format!("Failed to move {}: {}", source.display(), e))?;
+        }
+        moved += metadata.len();
+    }
+    Ok(moved)
+}
+
+/// Sets Scoop's `cache_path` config and returns the bytes moved.
+///
+/// Existing downloads stay where they are unless `migrate` is set, in which case
+/// the move waits for queued Scoop operations so none downloads into the old
+/// cache meanwhile. Fails when `SCOOP_CACHE` is set, since it takes precedence
+/// over the config.
+#[tauri::command]
+pub async fn set_scoop_cache_dir<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, crate::state::AppState>,
+    path: String,
+    migrate: bool,
+) -> Result<u64, String> {
+    let scoop_path = state.scoop_path();
+    let (current, source) = resolve_scoop_cache_dir(&scoop_path);
+    if source == ScoopCacheSource::Env {
+        return Err(
+            "The SCOOP_CACHE environment variable overrides the cache_path config".to_string(),
+        );
+    }
+
+    let path = path.trim().trim_end_matches(['\\', '/']).to_string();
+    let new_dir = PathBuf::from(&path);
+    ensure_writable_dir(&new_dir)?;
+
+    let migrate = migrate && current != new_dir;
+    let _turn = if migrate {
+        let operation_id = format!(
+            "migrate-scoop-cache-{}",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_millis())
+                .unwrap_or(0)
+        );
+        Some(
+            crate::commands::operation_queue::wait_for_turn(
+                &app,
+                &operation_id,
+                "Moving Scoop cache",
+            )
+            .await?,
+        )
+    } else {
+        None
+    };
+
+    let mut config = read_scoop_config()?;
+    if new_dir == scoop_path.join("cache") {
+        config.remove("cache_path");
+    } else {
+        config.insert("cache_path".to_string(), serde_json::json!(path));
+    }
+    write_scoop_config(&config)?;
+
+    let moved = if migrate {
+        let target = new_dir.clone();
+        tauri::async_runtime::spawn_blocking(move || move_cache_files(&current, &target))
+            .await
+            .map_err(|e| format!("Failed to join cache move task: {}", e))??
+    } else {
+        0
+    };
+    log::info!(
+        "Scoop cache directory set to {} ({} bytes moved)",
+        new_dir.display(),
+        moved
+    );
+    Ok(moved)
+}
+
+/// Settings key holding the encrypted GitHub token for Scoop operations.
+///
+/// It lives in the app store rather than Scoop's `config.json`, because Scoop
+/// would read an encrypted `gh_token` there as the token itself.
+const GITHUB_TOKEN_KEY: &str = "scoop.githubToken";
+
+/// Returns the decrypted GitHub token, if one is configured.
+pub(crate) fn read_github_token<R: Runtime>(app: AppHandle<R>) -> Option<String> {
+    let encrypted = get_config_value(app, GITHUB_TOKEN_KEY.to_string())
+        .ok()
+        .flatten()?
+        .as_str()?
+        .to_string();
+    match crypto::decrypt_api_key(&encrypted) {
+        Ok(token) if !token.trim().is_empty() => Some(token.trim().to_string()),
+        Ok(_) => None,
+        Err(e) => {
+            log::warn!("Failed to decrypt GitHub token: {}", e);
+            None
+        }
+    }
+}
+
+/// Gets the GitHub token passed to installs and updates as `GITHUB_TOKEN`.
+#[tauri::command]
+pub fn get_github_token<R: Runtime>(app: AppHandle<R>) -> Result<Option<String>, String> {
+    Ok(read_github_token(app))
+}
+
+/// Stores the GitHub token encrypted. An empty string removes it.
+#[tauri::command]
+pub fn set_github_token(app: AppHandle<tauri::Wry>, token: String) -> Result<(), String> {
+    let token = token.trim();
+    let value = if token.is_empty() {
+        Value::Null
+    } else {
+        serde_json::json!(crypto::encrypt_api_key(token)?)
+    };
+    set_config_value(app, GITHUB_TOKEN_KEY.to_string(), value)
+}
+
+/// Marks a file written by `export_app_settings`.
+const APP_SETTINGS_EXPORT_FORMAT: &str = "pailer-settings";
+/// Store keys holding preferences that carry over to another machine. Keys
+/// recording machine state, like the last bucket auto-update or the packages a
+/// bucket hold placed on this machine, are left out.
+const EXPORTED_STORE_KEYS: &[&str] = &[
+    "settings",
+    "buckets.autoUpdateInterval",
+    "buckets.autoUpdatePackagesEnabled",
+    "buckets.silentUpdateEnabled",
+    "settings.window.trayAppsEnabled",
+    "tray.appsList",
+    "window.closeToTray",
+    crate::commands::package_notes::PACKAGE_NOTES_KEY,
+    crate::commands::virustotal::SCAN_PROVIDER_KEY,
+];
+/// Fields of the frontend settings object that are preferences. The others,
+/// such as the Scoop path, describe this machine.
+const EXPORTED_SETTINGS_FIELDS: &[&str] = &[
+    "automation",
+    "buckets",
+    "cleanup",
+    "debug",
+    "defaultLaunchPage",
+    "language",
+    "notifications",
+    "performance",
+    "powershell",
+    "scan",
+    "scheduler",
+    "scoop",
+    "search",
+    "theme",
+    "trayAppsList",
+    "update",
+    "virustotal",
+    "window",
+];
+
+/// Keeps only the preference fields of the frontend settings object.
+fn retain_exported_settings(settings: &mut Value) {
+    let Some(settings) = settings.as_object_mut() else {
+        return;
+    };
+    settings.retain(|field, _| EXPORTED_SETTINGS_FIELDS.contains(&field.as_str()));
+
+    // The global Scoop root is a path on this machine, like the Scoop path
+    if let Some((group, field)) = GLOBAL_SCOOP_PATH_KEY.split_once('.') {
+        if let Some(group) = settings.get_mut(group).and_then(Value::as_object_mut) {
+            group.remove(field);
+        }
+    }
+}
+
+/// Drops the scan provider's API key, which is encrypted for this Windows account.
+fn strip_scan_provider_key(config: &mut Value) {
+    if let Some(config) = config.as_object_mut() {
+        config.remove("api_key");
+    }
+}
+
+/// Drops secrets nested in the frontend settings object.
+fn strip_sensitive_settings(settings: &mut Value) {
+    if let Some(virustotal) = settings
+        .get_mut("virustotal")
+        .and_then(Value::as_object_mut)
+    {
+        virustotal.remove("apiKey");
+    }
+}
+
+/// Serializes the app's own settings as JSON for moving them to another machine.
+///
+/// Secrets are left out unless `include_sensitive` is set. The GitHub token is
+/// then exported in plain text, since its encryption is tied to this Windows
+/// account and would be unreadable elsewhere.
+#[tauri::command]
+pub fn export_app_settings<R: Runtime>(
+    app: AppHandle<R>,
+    include_sensitive: Option<bool>,
+) -> Result<String, String> {
+    let include_sensitive = include_sensitive.unwrap_or(false);
+    let entries = with_store_get(app.clone(), |store| store.entries())?;
+
+    let mut values = Map::new();
+    for (key, mut value) in entries {
+        if !EXPORTED_STORE_KEYS.contains(&key.as_str()) {
+            continue;
+        }
+        if key == "settings" {
+            retain_exported_settings(&mut value);
+            if !include_sensitive {
+                strip_sensitive_settings(&mut value);
+            }
+        }
+        if key == crate::commands::virustotal::SCAN_PROVIDER_KEY {
+            strip_scan_provider_key(&mut value);
+        }
+        values.insert(key, value);
+    }
+    if include_sensitive {
+        if let Some(token) = read_github_token(app) {
+            values.insert(GITHUB_TOKEN_KEY.to_string(), Value::String(token));
+        }
+    }
+
+    let export = serde_json::json!({
+        "format": APP_SETTINGS_EXPORT_FORMAT,
+        "version": 1,
+        "values": values,
+    });
+    serde_json::to_string_pretty(&export)
+        .map_err(|e| format!("Failed to serialize settings: {}", e))
+}
+
+/// Merges settings exported by `export_app_settings` into the store and
+/// returns the number of keys imported.
+///
+/// Imported keys replace the current ones, except for the frontend settings
+/// object, which is merged group by group. Only preference keys are imported,
+/// and secrets only when `include_sensitive` is set.
+#[tauri::command]
+pub fn import_app_settings(
+    app: AppHandle<tauri::Wry>,
+    json: String,
+    include_sensitive: Option<bool>,
+) -> Result<usize, String> {
+    let include_sensitive = include_sensitive.unwrap_or(false);
+    let export: Value =
+        serde_json::from_str(json.trim()).map_err(|e| format!("Invalid settings file: {}", e))?;
+    if export.get("format").and_then(Value::as_str) != Some(APP_SETTINGS_EXPORT_FORMAT) {
+        return Err("Not a Pailer settings export".to_string());
+    }
+    let values = export
+        .get("values")
+        .and_then(Value::as_object)
+        .ok_or("Settings export has no values")?;
+
+    let mut imported = 0;
+    for (key, value) in values {
+        if key == GITHUB_TOKEN_KEY {
+            if include_sensitive {
+                let token = value.as_str().ok_or("GitHub token must be a string")?;
+                set_github_token(app.clone(), token.to_string())?;
+                imported += 1;
+            }
+            continue;
+        }
+        if !EXPORTED_STORE_KEYS.contains(&key.as_str()) {
+            log::debug!("Skipping non-preference settings key '{}' on import", key);
+            continue;
+        }
+
+        let value = if key == "settings" {
+            let mut incoming = value.clone();
+            retain_exported_settings(&mut incoming);
+            if !include_sensitive {
+                strip_sensitive_settings(&mut incoming);
+            }
+            let Value::Object(incoming_groups) = incoming else {
+                return Err("The settings value must be an object".to_string());
+            };
+
+            let mut merged = get_config_value(app.clone(), "settings".to_string())?
+                .and_then(|current| current.as_object().cloned())
+                .unwrap_or_default();
+            for (group, incoming_value) in incoming_groups {
+                match (merged.get_mut(&group), incoming_value) {
+                    (Some(Value::Object(current)), Value::Object(incoming)) => {
+                        current.extend(incoming);
+                    }
+                    (_, incoming_value) => {
+                        merged.insert(group, incoming_value);
+                    }
+                }
+            }
+            Value::Object(merged)
+        } else if key == crate::commands::virustotal::SCAN_PROVIDER_KEY {
+            let mut incoming = value.clone();
+            strip_scan_provider_key(&mut incoming);
+            incoming
+        } else {
+            value.clone()
+        };
+
+        set_config_value(app.clone(), key.clone(), value)?;
+        imported += 1;
+    }
+
+    log::info!("Imported {} app settings keys", imported);
+    Ok(imported)
+}
+
 /// Gets the proxy setting from Scoop's `config.json`.
 #[tauri::command]
 pub fn get_scoop_proxy() -> Result<Option<String>, String> {
@@ -514,6 +999,180 @@ pub fn set_scoop_proxy(proxy: String) -> Result<(), String> {
     write_scoop_config(&config)
 }
 
+/// Scoop's `config.json` key for the preferred manifest architecture.
+const DEFAULT_ARCHITECTURE_KEY: &str = "default_architecture";
+/// Older Scoop releases used a hyphenated key, which Scoop still migrates.
+const LEGACY_DEFAULT_ARCHITECTURE_KEY: &str = "default-architecture";
+const VALID_ARCHITECTURES: &[&str] = &["64bit", "32bit", "arm64"];
+
+/// Gets the default architecture from Scoop's `config.json`.
+#[tauri::command]
+pub fn get_default_architecture() -> Result<Option<String>, String> {
+    let config = read_scoop_config()?;
+    Ok(config
+        .get(DEFAULT_ARCHITECTURE_KEY)
+        .or_else(|| config.get(LEGACY_DEFAULT_ARCHITECTURE_KEY))
+        .and_then(|v| v.as_str().map(String::from)))
+}
+
+/// Sets the default architecture in Scoop's `config.json`.
+///
+/// Only `64bit`, `32bit` and `arm64` are accepted.
+#[tauri::command]
+pub fn set_default_architecture(architecture: String) -> Result<(), String> {
+    let architecture = architecture.trim().to_lowercase();
+    if !VALID_ARCHITECTURES.contains(&architecture.as_str()) {
+        return Err(format!(
+            "Invalid architecture '{}'. Expected one of: {}",
+            architecture,
+            VALID_ARCHITECTURES.join(", ")
+        ));
+    }
+
+    let mut config = read_scoop_config()?;
+    config.remove(LEGACY_DEFAULT_ARCHITECTURE_KEY);
+    config.insert(
+        DEFAULT_ARCHITECTURE_KEY.to_string(),
+        serde_json::json!(architecture),
+    );
+    write_scoop_config(&config)
+}
+
+/// Boolean keys of Scoop's `config.json` the settings UI may toggle, with the
+/// value Scoop assumes when a key is unset.
+const SCOOP_CONFIG_BOOLS: &[(&str, bool)] = &[
+    ("aria2-enabled", true),
+    ("aria2-warning-enabled", true),
+    ("autostash_on_conflict", false),
+    ("debug", false),
+    ("force_update", false),
+    ("ignore_running_processes", false),
+    ("no_junction", false),
+    ("show_manifest", false),
+    ("show_update_log", true),
+    ("use_external_7zip", false),
+    ("use_lessmsi", false),
+    ("use_sqlite_cache", false),
+];
+
+fn scoop_config_bool_default(key: &str) -> Result<bool, String> {
+    SCOOP_CONFIG_BOOLS
+        .iter()
+        .find(|(known, _)| *known == key)
+        .map(|(_, default)| *default)
+        .ok_or_else(|| format!("'{}' is not a supported boolean Scoop setting", key))
+}
+
+/// Gets a boolean from Scoop's `config.json`, or Scoop's default when unset.
+///
+/// Only keys in a known allowlist are accepted.
+#[tauri::command]
+pub fn get_scoop_config_bool(key: String) -> Result<bool, String> {
+    let default = scoop_config_bool_default(&key)?;
+    let config = read_scoop_config()?;
+    // `scoop config <key> true` stores a string on older Scoop versions
+    Ok(match config.get(&key) {
+        Some(Value::Bool(value)) => *value,
+        Some(Value::String(value)) => value.trim().eq_ignore_ascii_case("true"),
+        _ => default,
+    })
+}
+
+/// Sets a boolean in Scoop's `config.json`.
+///
+/// Only keys in a known allowlist are accepted, so this can't be used to
+/// write arbitrary config values.
+#[tauri::command]
+pub fn set_scoop_config_bool(key: String, value: bool) -> Result<(), String> {
+    scoop_config_bool_default(&key)?;
+    let mut config = read_scoop_config()?;
+    config.insert(key.clone(), Value::Bool(value));
+    write_scoop_config(&config)?;
+    log::info!("Set Scoop config {} to {}", key, value);
+    Ok(())
+}
+
+/// Scoop's `config.json` keys for the repository and branch Scoop updates itself from.
+const SCOOP_REPO_KEY: &str = "scoop_repo";
+const SCOOP_BRANCH_KEY: &str = "scoop_branch";
+const DEFAULT_SCOOP_REPO: &str = "https://github.com/ScoopInstaller/Scoop";
+const DEFAULT_SCOOP_BRANCH: &str = "master";
+const SCOOP_CORE_SOURCE_NOTE: &str =
+    "Scoop switches to the new source on its next update. Run `scoop update` to apply it now.";
+
+/// The repository and branch Scoop's own code is pulled from.
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ScoopCoreSource {
+    pub repo: String,
+    pub branch: String,
+    /// Whether both values are Scoop's official defaults.
+    pub is_default: bool,
+}
+
+fn scoop_core_source_from(config: &Map<String, Value>) -> ScoopCoreSource {
+    let value = |key: &str, default: &str| {
+        config
+            .get(key)
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .unwrap_or(default)
+            .to_string()
+    };
+    let repo = value(SCOOP_REPO_KEY, DEFAULT_SCOOP_REPO);
+    let branch = value(SCOOP_BRANCH_KEY, DEFAULT_SCOOP_BRANCH);
+    let is_default =
+        repo.trim_end_matches(".git") == DEFAULT_SCOOP_REPO && branch == DEFAULT_SCOOP_BRANCH;
+
+    ScoopCoreSource {
+        repo,
+        branch,
+        is_default,
+    }
+}
+
+/// Gets the repository and branch Scoop updates itself from.
+#[tauri::command]
+pub fn get_scoop_core_source() -> Result<ScoopCoreSource, String> {
+    Ok(scoop_core_source_from(&read_scoop_config()?))
+}
+
+/// Points Scoop's self-update at another repository and branch, e.g. a fork.
+///
+/// Returns a note for the user, since Scoop only picks this up on its next update.
+#[tauri::command]
+pub fn set_scoop_core_source(repo: String, branch: String) -> Result<String, String> {
+    let repo = crate::utils::validate_and_normalize_url(repo.trim())?;
+    let branch = branch.trim();
+    if branch.is_empty()
+        || branch.contains(char::is_whitespace)
+        || branch.contains("..")
+        || branch.starts_with('-')
+    {
+        return Err(format!("Invalid branch name '{}'", branch));
+    }
+
+    let mut config = read_scoop_config()?;
+    config.insert(SCOOP_REPO_KEY.to_string(), serde_json::json!(repo));
+    config.insert(SCOOP_BRANCH_KEY.to_string(), serde_json::json!(branch));
+    write_scoop_config(&config)?;
+
+    log::info!("Set Scoop core source to {} ({})", repo, branch);
+    Ok(SCOOP_CORE_SOURCE_NOTE.to_string())
+}
+
+/// Restores Scoop's official repository and branch for its self-update.
+#[tauri::command]
+pub fn reset_scoop_core_source() -> Result<String, String> {
+    let mut config = read_scoop_config()?;
+    config.remove(SCOOP_REPO_KEY);
+    config.remove(SCOOP_BRANCH_KEY);
+    write_scoop_config(&config)?;
+
+    log::info!("Reset Scoop core source to the official defaults");
+    Ok(SCOOP_CORE_SOURCE_NOTE.to_string())
+}
+
 /// Executes an arbitrary Scoop command
 #[tauri::command]
 pub async fn run_scoop_command(
@@ -537,10 +1196,11 @@ pub async fn run_scoop_command(
         command.clone(),
         crate::commands::powershell::EVENT_OUTPUT,
         crate::commands::powershell::EVENT_FINISHED,
-        crate::commands::powershell::EVENT_CANCEL,
         resolved_operation_id,
+        crate::commands::powershell::StreamOptions::default(),
     )
     .await
+    .map_err(String::from)
 }
 
 /// Gets the path to the Scoop configuration directory.
@@ -576,10 +1236,11 @@ pub async fn run_powershell_command(
         command.clone(),
         crate::commands::powershell::EVENT_OUTPUT,
         crate::commands::powershell::EVENT_FINISHED,
-        crate::commands::powershell::EVENT_CANCEL,
         resolved_operation_id,
+        crate::commands::powershell::StreamOptions::default(),
     )
     .await
+    .map_err(String::from)
 }
 
 /// Sets the preferred PowerShell executable
@@ -6,7 +6,7 @@ use crate::models::{AppStatusInfo, ScoopPackage as InstalledPackage, ScoopStatus
 use crate::state::AppState;
 use crate::utils::locate_package_manifest;
 use git2::Repository;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -263,6 +263,7 @@ pub async fn check_scoop_status<R: Runtime>(
         crate::commands::hold::list_held_packages(app, state.clone())
             .await?
             .into_iter()
+            .map(|held| held.name)
             .collect();
 
     let mut apps_with_issues = Vec::new();
@@ -291,3 +292,79 @@ pub async fn check_scoop_status<R: Runtime>(
         is_everything_ok,
     })
 }
+
+/// One-call rollup of the Scoop installation for dashboard views.
+#[derive(Serialize, Debug, Clone)]
+pub struct ScoopHealthSummary {
+    pub installed_count: usize,
+    pub held_count: usize,
+    /// From the last update check, `None` if no check has run yet.
+    pub updates_available: Option<usize>,
+    pub buckets_count: usize,
+    pub cache_size_bytes: u64,
+    pub broken_install_count: usize,
+    /// Unix timestamp in seconds of the last scheduled bucket update.
+    pub last_auto_update_ts: Option<u64>,
+}
+
+/// Summarizes the whole Scoop installation.
+///
+/// Composes already cached sources where possible: the installed packages cache and
+/// the last update check result are reused rather than triggering a new check.
+#[tauri::command]
+pub async fn get_scoop_health_summary<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+) -> Result<ScoopHealthSummary, String> {
+    let installed_count = get_installed_packages_full(app.clone(), state.clone())
+        .await?
+        .len();
+    let held_count = crate::commands::hold::list_held_packages(app.clone(), state.clone())
+        .await?
+        .len();
+    let updates_available = state
+        .update_check
+        .lock()
+        .await
+        .as_ref()
+        .map(|cache| cache.updatable_count);
+
+    let scoop_path = state.scoop_path();
+    let (buckets_count, cache_size_bytes, broken_install_count) =
+        tokio::task::spawn_blocking(move || {
+            let buckets_count = fs::read_dir(scoop_path.join("buckets"))
+                .map(|entries| {
+                    entries
+                        .filter_map(Result::ok)
+                        .filter(|entry| entry.path().is_dir())
+                        .count()
+                })
+                .unwrap_or(0);
+            (
+                buckets_count,
+                crate::utils::directory_size(
+                    &crate::commands::settings::resolve_scoop_cache_dir(&scoop_path).0,
+                ),
+                crate::commands::installed::count_broken_installs(&scoop_path),
+            )
+        })
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let last_auto_update_ts =
+        crate::commands::settings::get_config_value(app, "buckets.lastAutoUpdateTs".to_string())
+            .ok()
+            .flatten()
+            .and_then(|v| v.as_u64())
+            .filter(|ts| *ts > 0);
+
+    Ok(ScoopHealthSummary {
+        installed_count,
+        held_count,
+        updates_available,
+        buckets_count,
+        cache_size_bytes,
+        broken_install_count,
+        last_auto_update_ts,
+    })
+}
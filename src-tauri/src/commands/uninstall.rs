@@ -1,8 +1,18 @@
 //! Commands for uninstalling packages and clearing the cache.
 use crate::commands::auto_cleanup::trigger_auto_cleanup;
+use crate::commands::doctor::shim::package_shim_names;
+use crate::commands::install::manifest_dependencies;
+use crate::commands::installed::{
+    check_global_package, package_env_contribution, read_app_dirs, read_installed_manifest,
+    EnvContribution,
+};
 use crate::commands::package_mutation::{finalize_single_package_mutation, PackageMutationKind};
 use crate::commands::scoop::{self, generate_operation_id, ScoopOp};
+use crate::error::AppError;
 use crate::state::AppState;
+use crate::utils::directory_size;
+use serde::Serialize;
+use std::collections::HashSet;
 use tauri::{AppHandle, State, Window};
 
 /// Uninstalls a Scoop package.
@@ -17,6 +27,7 @@ use tauri::{AppHandle, State, Window};
 /// * `package_name` - The name of package to uninstall.
 /// * `bucket` - The bucket package belongs to (for logging purposes).
 /// * `operation_id` - Optional operation ID for tracking.
+/// * `global` - Whether the package is a global install; guessed when omitted.
 #[tauri::command]
 pub async fn uninstall_package(
     window: Window,
@@ -25,10 +36,12 @@ pub async fn uninstall_package(
     package_name: String,
     bucket: String,
     operation_id: Option<String>,
-) -> Result<(), String> {
+    global: Option<bool>,
+) -> Result<(), AppError> {
     let event_window = window.clone();
     let operation_id = operation_id
         .unwrap_or_else(|| generate_operation_id(ScoopOp::Uninstall, Some(&package_name)));
+    let global = check_global_package(&window, &state, &package_name, global, &operation_id);
 
     execute_package_operation(
         window,
@@ -36,6 +49,7 @@ pub async fn uninstall_package(
         &package_name,
         Some(&bucket),
         Some(operation_id.clone()),
+        global,
     )
     .await?;
     let bucket_opt =
@@ -56,6 +70,110 @@ pub async fn uninstall_package(
     Ok(())
 }
 
+/// Everything uninstalling a package would take away, as reported by `uninstall_preview`.
+#[derive(Serialize, Debug, Clone)]
+pub struct UninstallPlan {
+    pub name: String,
+    /// `"user"` or `"global"`.
+    pub scope: String,
+    pub install_dir: String,
+    pub install_size_bytes: u64,
+    pub shims: Vec<String>,
+    pub env: Option<EnvContribution>,
+    /// Only removed when uninstalling with purge.
+    pub persist_dir: Option<String>,
+    pub persist_size_bytes: u64,
+    /// Installed dependencies no other installed package depends on.
+    pub orphan_dependencies: Vec<String>,
+}
+
+/// Strips a `bucket/` prefix from a `depends` entry.
+fn dependency_name(dependency: &str) -> String {
+    dependency
+        .rsplit('/')
+        .next()
+        .unwrap_or(dependency)
+        .to_lowercase()
+}
+
+/// Reports what uninstalling a package would remove without touching anything.
+#[tauri::command]
+pub async fn uninstall_preview(
+    state: State<'_, AppState>,
+    name: String,
+) -> Result<UninstallPlan, AppError> {
+    let name = name.trim().to_string();
+    if name.is_empty() || name.contains(['/', '\\']) || name == "." || name == ".." {
+        return Err(AppError::NotFound(format!(
+            "Invalid package name '{}'",
+            name
+        )));
+    }
+
+    let scoop_path = state.scoop_path();
+    let mut roots = vec![("user", scoop_path.clone())];
+    roots.extend(state.global_scoop_path().map(|root| ("global", root)));
+
+    tokio::task::spawn_blocking(move || {
+        let (scope, root) = roots
+            .iter()
+            .find(|(_, root)| root.join("apps").join(&name).is_dir())
+            .cloned()
+            .ok_or_else(|| AppError::NotFound(format!("Package '{}' is not installed", name)))?;
+        let package_path = root.join("apps").join(&name);
+        let persist_path = root.join("persist").join(&name);
+
+        // Dependents are looked up across both scopes, since either can satisfy a dependency
+        let mut installed = HashSet::new();
+        let mut still_needed = HashSet::new();
+        let mut own_dependencies = Vec::new();
+        for (_, scope_root) in &roots {
+            for path in read_app_dirs(&scope_root.join("apps")).unwrap_or_default() {
+                let Some(dir_name) = path.file_name().map(|n| n.to_string_lossy().to_lowercase())
+                else {
+                    continue;
+                };
+                installed.insert(dir_name.clone());
+                let dependencies = read_installed_manifest(&path)
+                    .map(|manifest| manifest_dependencies(&manifest))
+                    .unwrap_or_default();
+                if scope_root == &root && dir_name == name.to_lowercase() {
+                    own_dependencies = dependencies;
+                } else {
+                    still_needed.extend(dependencies.iter().map(|dep| dependency_name(dep)));
+                }
+            }
+        }
+        let mut orphan_dependencies: Vec<String> = own_dependencies
+            .iter()
+            .map(|dep| dependency_name(dep))
+            .filter(|dep| installed.contains(dep) && !still_needed.contains(dep))
+            .collect();
+        orphan_dependencies.sort();
+        orphan_dependencies.dedup();
+
+        let persist_dir = persist_path
+            .is_dir()
+            .then(|| persist_path.to_string_lossy().to_string());
+        Ok(UninstallPlan {
+            scope: scope.to_string(),
+            install_dir: package_path.to_string_lossy().to_string(),
+            install_size_bytes: directory_size(&package_path),
+            shims: package_shim_names(&root.join("shims"), &name),
+            env: package_env_contribution(&root, &package_path),
+            persist_size_bytes: persist_dir
+                .as_ref()
+                .map(|_| directory_size(&persist_path))
+                .unwrap_or(0),
+            persist_dir,
+            orphan_dependencies,
+            name,
+        })
+    })
+    .await
+    .map_err(|e| AppError::Io(e.to_string()))?
+}
+
 /// Clears the cache for a Scoop package.
 ///
 /// Note: The `bucket` parameter is not used by the underlying `scoop cache rm` command
@@ -76,13 +194,14 @@ pub async fn clear_package_cache(
     package_name: String,
     bucket: String,
     operation_id: Option<String>,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     execute_package_operation(
         window,
         ScoopOp::ClearCache,
         &package_name,
         Some(&bucket),
         operation_id,
+        false,
     )
     .await?;
 
@@ -102,7 +221,8 @@ async fn execute_package_operation(
     package: &str,
     bucket: Option<&str>,
     operation_id: Option<String>,
-) -> Result<(), String> {
+    global: bool,
+) -> Result<(), AppError> {
     log::info!(
         "Executing {} for package '{}' from bucket '{}'",
         match op {
@@ -121,5 +241,14 @@ async fn execute_package_operation(
     let operation_id = operation_id.unwrap_or_else(|| generate_operation_id(op, Some(package)));
 
     // Pass the bucket option along; `execute_scoop` will handle whether it's used.
-    scoop::execute_scoop(window, op, Some(package), bucket, operation_id, false).await
+    scoop::execute_scoop(
+        window,
+        op,
+        Some(package),
+        bucket,
+        operation_id,
+        false,
+        global,
+    )
+    .await
 }
@@ -1,9 +1,10 @@
 use crate::commands::auto_cleanup::trigger_auto_cleanup;
-use crate::commands::installed::invalidate_installed_cache;
+use crate::commands::installed::{check_global_package, invalidate_installed_cache};
 use crate::commands::package_mutation::{
     emit_installed_packages_changed, finalize_single_package_mutation, PackageMutationKind,
 };
 use crate::commands::scoop::{self, generate_operation_id, ScoopOp};
+use crate::error::AppError;
 use crate::state::AppState;
 use tauri::{AppHandle, State, Window};
 
@@ -20,7 +21,8 @@ pub async fn update_package(
     force: Option<bool>,
     operation_id: Option<String>,
     skip_pre_update_refresh: Option<bool>,
-) -> Result<(), String> {
+    global: Option<bool>,
+) -> Result<(), AppError> {
     log::info!("Updating package '{}'", package_name);
     let event_window = window.clone();
 
@@ -32,6 +34,7 @@ pub async fn update_package(
 
     let operation_id =
         operation_id.unwrap_or_else(|| generate_operation_id(ScoopOp::Update, Some(&package_name)));
+    let global = check_global_package(&window, &state, &package_name, global, &operation_id);
 
     let update_result = scoop::execute_scoop(
         window,
@@ -40,6 +43,7 @@ pub async fn update_package(
         None,
         operation_id.clone(),
         skip_pre_update_refresh.unwrap_or(false),
+        global,
     )
     .await;
 
@@ -79,7 +83,7 @@ pub async fn update_all_packages(
     app: AppHandle,
     state: State<'_, AppState>,
     operation_id: Option<String>,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     log::info!("Updating all packages (manual)");
 
     // Use the provided operation_id or generate a new one
@@ -94,6 +98,7 @@ pub async fn update_all_packages(
         None,
         operation_id.clone(),
         false,
+        false,
     )
     .await;
 
@@ -145,6 +150,32 @@ pub async fn update_all_packages_headless(
     .flatten()
     .and_then(|v| v.as_bool())
     .unwrap_or(true);
+    let low_priority = settings::get_config_value(app.clone(), "scheduler.lowPriority".to_string())
+        .ok()
+        .flatten()
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+
+    // Wait behind any install/update the user started so the two don't collide
+    let turn = crate::commands::operation_queue::wait_for_turn(
+        &app,
+        &tray_migration_op_id,
+        "Updating all packages",
+    )
+    .await?;
+
+    // A Scoop run from a terminal at the same time can leave apps half-written
+    let conflicts = crate::commands::process_control::find_conflicting_scoop_processes().await;
+    if !conflicts.is_empty() {
+        log::warn!(
+            "(Headless) Skipping update while Scoop is running elsewhere: {}",
+            conflicts.join(", ")
+        );
+        return Err(format!(
+            "Another Scoop operation is running ({})",
+            conflicts.join(", ")
+        ));
+    }
 
     if tray_auto_enabled {
         let prepare_args = TrayMigrationPrepareArgs {
@@ -162,7 +193,12 @@ pub async fn update_all_packages_headless(
         }
     }
 
-    let update_result = match scoop_update_runner::run_update_all_headless().await {
+    let options = crate::commands::powershell::StreamOptions {
+        low_priority,
+        ..Default::default()
+    }
+    .with_github_token(&app);
+    let update_result = match scoop_update_runner::run_update_all_headless(&options).await {
         Ok(output) => output,
         Err(err) => {
             if tray_auto_enabled {
@@ -210,7 +246,9 @@ pub async fn update_all_packages_headless(
     invalidate_installed_cache(state.clone()).await;
     emit_installed_packages_changed(&app, "update-all", None);
 
-    // Trigger auto cleanup after successful headless update
+    // Trigger auto cleanup after successful headless update; it takes its own
+    // queue turn, so give up ours first
+    drop(turn);
     trigger_auto_cleanup(app, state).await;
     log::info!("Headless package update completed successfully");
     Ok(result)
@@ -1,15 +1,24 @@
 //! Command for checking for available updates for installed Scoop packages.
 use crate::commands::installed::get_installed_packages_full;
 use crate::models::ScoopPackage as InstalledPackage;
-use crate::state::AppState;
-use crate::utils::locate_package_manifest;
+use crate::state::{AppState, UpdateCheckCache};
+use crate::utils::{compare_versions, locate_package_manifest};
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
 use tauri::{AppHandle, Runtime, State};
 
+/// How long an update check result is reused by `get_update_count`.
+const UPDATE_COUNT_CACHE_TTL_MS: u64 = 2 * 60 * 1000;
+/// Per-request timeout when probing download sizes.
+const DOWNLOAD_SIZE_TIMEOUT: Duration = Duration::from_secs(5);
+/// Maximum number of download size probes in flight at once.
+const DOWNLOAD_SIZE_CONCURRENCY: usize = 8;
+
 /// Represents a package that has a newer version available.
 #[derive(Serialize, Debug)]
 pub struct UpdatablePackage {
@@ -28,7 +37,7 @@ struct Manifest {
 ///
 /// Returns `Ok(Some(UpdatablePackage))` if an update is found, `Ok(None)` if the package
 /// is up-to-date, and `Err` if any error occurs during the process.
-fn check_package_for_update(
+fn find_package_update(
     scoop_dir: &Path,
     package: &InstalledPackage,
 ) -> Result<Option<UpdatablePackage>, String> {
@@ -50,7 +59,7 @@ fn check_package_for_update(
         .as_ref()
         .unwrap_or(&package.version);
 
-    if version_to_compare != &manifest.version {
+    if compare_versions(version_to_compare, &manifest.version).is_lt() {
         Ok(Some(UpdatablePackage {
             name: package.name.clone(),
             current: package.version.clone(),
@@ -61,6 +70,49 @@ fn check_package_for_update(
     }
 }
 
+/// Installed and latest version of a single package with an update available.
+#[derive(Serialize, Debug)]
+pub struct PackageUpdateInfo {
+    pub current: String,
+    pub latest: String,
+}
+
+/// Checks a single installed package for an available update.
+///
+/// Returns `None` when the package is up to date or on hold, so the detail view can
+/// show an indicator without running a full `check_for_updates`.
+#[tauri::command]
+pub async fn check_package_for_update<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+    name: String,
+) -> Result<Option<PackageUpdateInfo>, String> {
+    let installed_packages = get_installed_packages_full(app.clone(), state.clone()).await?;
+    let package = installed_packages
+        .into_iter()
+        .find(|p| p.name.eq_ignore_ascii_case(&name))
+        .ok_or_else(|| format!("Package '{}' is not installed", name))?;
+
+    let is_held = crate::commands::hold::list_held_packages(app, state.clone())
+        .await?
+        .iter()
+        .any(|held| held.name.eq_ignore_ascii_case(&package.name));
+    if is_held {
+        log::debug!("Package '{}' is held, not reporting updates", package.name);
+        return Ok(None);
+    }
+
+    let scoop_path = state.scoop_path();
+    let update = tokio::task::spawn_blocking(move || find_package_update(&scoop_path, &package))
+        .await
+        .map_err(|e| e.to_string())??;
+
+    Ok(update.map(|updatable| PackageUpdateInfo {
+        current: updatable.current,
+        latest: updatable.available,
+    }))
+}
+
 /// Checks all installed packages for available updates.
 ///
 /// This command scans the filesystem, compares installed versions with the latest
@@ -81,6 +133,7 @@ pub async fn check_for_updates<R: Runtime>(
         crate::commands::hold::list_held_packages(app, state.clone())
             .await?
             .into_iter()
+            .map(|held| held.name)
             .collect();
 
     // Check for updates in parallel.
@@ -93,7 +146,7 @@ pub async fn check_for_updates<R: Runtime>(
             .par_iter()
             .filter(|p| !held_packages_clone.contains(&p.name)) // Exclude held packages
             .filter_map(|package| {
-                match check_package_for_update(&scoop_path_clone, package) {
+                match find_package_update(&scoop_path_clone, package) {
                     Ok(Some(updatable)) => Some(updatable),
                     Ok(None) => None, // Package is up-to-date
                     Err(e) => {
@@ -112,5 +165,149 @@ pub async fn check_for_updates<R: Runtime>(
     .map_err(|e| e.to_string())?;
 
     log::debug!("Found {} updatable packages", updatable_packages.len());
+
+    *state.update_check.lock().await = Some(UpdateCheckCache {
+        updatable_count: updatable_packages.len(),
+        checked_at_ms: AppState::now_ms(),
+    });
+
     Ok(updatable_packages)
 }
+
+/// An updatable package with the size of the downloads its update needs.
+#[derive(Serialize, Debug)]
+pub struct UpdatablePackageWithSize {
+    #[serde(flatten)]
+    pub package: UpdatablePackage,
+    /// Total bytes to download, or `None` if any download's size is unknown.
+    pub download_size: Option<u64>,
+}
+
+/// Returns the download URLs of the bucket manifest a package updates from.
+fn pending_download_urls(scoop_dir: &Path, name: &str, source: &str) -> Vec<String> {
+    let Ok((manifest_path, _)) = locate_package_manifest(scoop_dir, name, Some(source.to_string()))
+    else {
+        return Vec::new();
+    };
+    crate::commands::manifest::read_manifest_json(&manifest_path)
+        .map(|manifest| crate::commands::package_hash::manifest_arch_field(&manifest, "url"))
+        .unwrap_or_default()
+}
+
+/// Reads the size of a download from a `HEAD` request's `Content-Length`.
+async fn probe_download_size(client: &reqwest::Client, url: &str) -> Option<u64> {
+    let response = client.head(url).send().await.ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+    response
+        .headers()
+        .get(reqwest::header::CONTENT_LENGTH)?
+        .to_str()
+        .ok()?
+        .parse()
+        .ok()
+}
+
+pub(crate) async fn total_download_size(client: &reqwest::Client, urls: &[String]) -> Option<u64> {
+    if urls.is_empty() {
+        return None;
+    }
+    let mut total = 0;
+    for url in urls {
+        total += probe_download_size(client, url).await?;
+    }
+    Some(total)
+}
+
+/// Like `check_for_updates`, but also reports how much each update downloads.
+///
+/// Sizes come from `HEAD` requests against the manifest URLs, run in parallel
+/// with a short timeout; a package whose size can't be determined gets `None`.
+/// In offline mode no requests are made and every size is `None`.
+#[tauri::command]
+pub async fn check_for_updates_with_sizes<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+) -> Result<Vec<UpdatablePackageWithSize>, String> {
+    let sources: HashMap<String, String> = get_installed_packages_full(app.clone(), state.clone())
+        .await?
+        .into_iter()
+        .map(|package| (package.name, package.source))
+        .collect();
+    let scoop_path = state.scoop_path();
+    let offline = crate::commands::network::is_offline_mode(&app);
+    let updatable = check_for_updates(app, state).await?;
+
+    if offline {
+        log::debug!("Offline mode is on, skipping download size probes");
+        return Ok(updatable
+            .into_iter()
+            .map(|package| UpdatablePackageWithSize {
+                package,
+                download_size: None,
+            })
+            .collect());
+    }
+
+    let client = reqwest::Client::builder()
+        .timeout(DOWNLOAD_SIZE_TIMEOUT)
+        .user_agent("Pailer")
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+    let semaphore = Arc::new(tokio::sync::Semaphore::new(DOWNLOAD_SIZE_CONCURRENCY));
+    let mut probes = tokio::task::JoinSet::new();
+
+    for (index, package) in updatable.iter().enumerate() {
+        let source = sources.get(&package.name).cloned().unwrap_or_default();
+        let urls = pending_download_urls(&scoop_path, &package.name, &source);
+        let client = client.clone();
+        let semaphore = semaphore.clone();
+        probes.spawn(async move {
+            let _permit = semaphore.acquire_owned().await.ok()?;
+            total_download_size(&client, &urls)
+                .await
+                .map(|size| (index, size))
+        });
+    }
+
+    let mut sizes = vec![None; updatable.len()];
+    while let Some(probe) = probes.join_next().await {
+        if let Ok(Some((index, size))) = probe {
+            sizes[index] = Some(size);
+        }
+    }
+
+    log::debug!(
+        "Resolved download sizes for {}/{} updatable packages",
+        sizes.iter().filter(|size| size.is_some()).count(),
+        sizes.len()
+    );
+
+    Ok(updatable
+        .into_iter()
+        .zip(sizes)
+        .map(|(package, download_size)| UpdatablePackageWithSize {
+            package,
+            download_size,
+        })
+        .collect())
+}
+
+/// Returns the number of installed, non-held packages with an update available.
+///
+/// Reuses the last `check_for_updates` result while it is fresh, so frequent callers
+/// such as the tray don't trigger a full rescan each time.
+#[tauri::command]
+pub async fn get_update_count<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+) -> Result<usize, String> {
+    if let Some(cache) = state.update_check.lock().await.as_ref() {
+        if AppState::now_ms().saturating_sub(cache.checked_at_ms) < UPDATE_COUNT_CACHE_TTL_MS {
+            return Ok(cache.updatable_count);
+        }
+    }
+
+    Ok(check_for_updates(app, state).await?.len())
+}
@@ -1,7 +1,153 @@
-use crate::commands::powershell;
-use serde::Serialize;
-use tauri::{AppHandle, Emitter, Window};
-use tokio::io::{AsyncBufReadExt, BufReader};
+use crate::commands::manifest::read_manifest_json;
+use crate::commands::package_hash::manifest_arch_field;
+use crate::commands::settings::{
+    get_config_value, get_virustotal_api_key, set_config_value, set_virustotal_api_key,
+};
+use crate::commands::{crypto, powershell};
+use crate::error::AppError;
+use crate::state::AppState;
+use crate::utils::locate_package_manifest;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter, Manager, Window};
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+use tokio::task::JoinHandle;
+use tokio::time::{sleep, Duration};
+
+/// Maximum number of times a rate-limited scan is retried.
+const MAX_RATE_LIMIT_RETRIES: u32 = 3;
+/// Delay before the first retry; doubled for every following attempt.
+const INITIAL_BACKOFF_SECS: u64 = 15;
+/// Upper bound for a single retry delay, including server-provided ones.
+const MAX_BACKOFF_SECS: u64 = 120;
+
+static RETRY_AFTER_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)retry[- ]after\D{0,4}(\d+)").unwrap());
+
+/// Store key holding the scan provider configuration.
+pub(crate) const SCAN_PROVIDER_KEY: &str = "scanProvider";
+/// MetaDefender Cloud, used when no on-premises server is configured.
+const DEFAULT_METADEFENDER_URL: &str = "https://api.metadefender.com/v4";
+/// Per-request timeout for MetaDefender hash lookups.
+const METADEFENDER_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// The service packages are scanned with.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ScanProvider {
+    /// Public VirusTotal through `scoop virustotal`.
+    #[default]
+    VirusTotal,
+    /// MetaDefender Cloud or an on-premises MetaDefender Core server.
+    MetaDefender,
+}
+
+/// Which provider `scan_package` uses and how to reach it.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ScanProviderConfig {
+    #[serde(default)]
+    pub provider: ScanProvider,
+    /// MetaDefender server URL; unset uses MetaDefender Cloud. VirusTotal is
+    /// always reached through `scoop virustotal` and doesn't take one.
+    #[serde(default)]
+    pub base_url: Option<String>,
+    /// For VirusTotal this is the key `scoop virustotal` reads from Scoop's config.
+    #[serde(default)]
+    pub api_key: Option<String>,
+}
+
+/// Reads the provider configuration with the API key decrypted.
+fn read_scan_provider<R: tauri::Runtime>(app: &AppHandle<R>) -> ScanProviderConfig {
+    let mut config: ScanProviderConfig =
+        get_config_value(app.clone(), SCAN_PROVIDER_KEY.to_string())
+            .ok()
+            .flatten()
+            .and_then(|value| serde_json::from_value(value).ok())
+            .unwrap_or_default();
+
+    config.api_key = match config.provider {
+        ScanProvider::VirusTotal => get_virustotal_api_key().ok().flatten(),
+        ScanProvider::MetaDefender => config.api_key.and_then(|encrypted| {
+            crypto::decrypt_api_key(&encrypted)
+                .map_err(|e| log::warn!("Failed to decrypt scan provider API key: {}", e))
+                .ok()
+        }),
+    };
+    config
+}
+
+/// Returns the scan provider configuration, VirusTotal unless changed.
+#[tauri::command]
+pub fn get_scan_provider(app: AppHandle) -> ScanProviderConfig {
+    read_scan_provider(&app)
+}
+
+/// Replaces the scan provider configuration.
+///
+/// The whole configuration is validated first and then written as a single
+/// store value, so scans never see a provider paired with another one's URL.
+/// A server URL is rejected for VirusTotal, which has no use for it.
+/// A VirusTotal key is stored in Scoop's config, where `scoop virustotal` reads it;
+/// if the store write then fails, the previous key is put back.
+#[tauri::command]
+pub fn set_scan_provider(app: AppHandle, config: ScanProviderConfig) -> Result<(), String> {
+    let base_url = config
+        .base_url
+        .map(|url| url.trim().trim_end_matches('/').to_string())
+        .filter(|url| !url.is_empty());
+    if let Some(url) = &base_url {
+        if config.provider == ScanProvider::VirusTotal {
+            return Err("VirusTotal does not support a custom server URL".to_string());
+        }
+        if !url.starts_with("https://") && !url.starts_with("http://") {
+            return Err(format!("'{}' is not an http(s) URL", url));
+        }
+    }
+    let api_key = config
+        .api_key
+        .map(|key| key.trim().to_string())
+        .filter(|key| !key.is_empty());
+
+    let mut previous_vt_key = None;
+    let stored = match config.provider {
+        ScanProvider::VirusTotal => {
+            if let Some(key) = api_key {
+                previous_vt_key = Some(get_virustotal_api_key()?.unwrap_or_default());
+                set_virustotal_api_key(key)?;
+            }
+            ScanProviderConfig {
+                provider: ScanProvider::VirusTotal,
+                base_url: None,
+                api_key: None,
+            }
+        }
+        ScanProvider::MetaDefender => ScanProviderConfig {
+            provider: ScanProvider::MetaDefender,
+            base_url,
+            api_key: api_key
+                .map(|key| crypto::encrypt_api_key(&key))
+                .transpose()?,
+        },
+    };
+
+    let written = serde_json::to_value(&stored)
+        .map_err(|e| format!("Failed to serialize scan provider: {}", e))
+        .and_then(|value| set_config_value(app, SCAN_PROVIDER_KEY.to_string(), value));
+    if let Err(e) = written {
+        if let Some(previous) = previous_vt_key {
+            if let Err(restore_err) = set_virustotal_api_key(previous) {
+                log::error!("Failed to restore VirusTotal API key: {}", restore_err);
+            }
+        }
+        return Err(e);
+    }
+    log::info!("Scan provider set to {:?}", stored.provider);
+    Ok(())
+}
 
 /// Generate operation name for VirusTotal scanning
 fn generate_virustotal_operation_name(package_name: &str) -> String {
@@ -23,38 +169,95 @@ pub struct CommandResult {
     pub timestamp: u64,
 }
 
-/// Scans a package using `scoop virustotal` and emits the results.
+/// How a completed scan turned out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScanOutcome {
+    /// Every file was scanned and none was flagged.
+    Clean,
+    /// A file was flagged or the scan could not be completed.
+    Failed,
+    /// Nothing was flagged, but not every file could be checked.
+    Inconclusive,
+}
+
+/// Outcome of a single `scoop virustotal` invocation.
+struct ScanAttempt {
+    exit_code: i32,
+    rate_limited: bool,
+    retry_after_secs: Option<u64>,
+}
+
+/// Returns whether an output line reports that VirusTotal rejected the request with HTTP 429.
+fn is_rate_limit_line(line: &str) -> bool {
+    let lower = line.to_lowercase();
+    lower.contains("(429)") || lower.contains("too many requests") || lower.contains("rate limit")
+}
+
+/// Extracts a `Retry-After` delay in seconds from an output line, if present.
+fn parse_retry_after_secs(line: &str) -> Option<u64> {
+    RETRY_AFTER_RE
+        .captures(line)
+        .and_then(|captures| captures[1].parse().ok())
+}
+
+/// Computes the delay before the given retry attempt (starting at 0).
 ///
-/// This command streams its output to the frontend and emits a `operation-finished`
-/// event with a `CommandResult` payload upon completion.
-#[tauri::command]
-pub async fn scan_package(
-    window: Window,
-    _app: AppHandle,
-    package_name: String,
-    bucket: String,
-) -> Result<(), String> {
-    // Generate consistent operation ID at the beginning
-    let operation_id = format!(
-        "virustotal-{}-{}",
-        package_name,
-        std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_millis()
-    );
+/// A server-provided `Retry-After` takes precedence over exponential backoff.
+fn backoff_delay_secs(attempt: u32, retry_after_secs: Option<u64>) -> u64 {
+    retry_after_secs
+        .unwrap_or_else(|| INITIAL_BACKOFF_SECS.saturating_mul(1 << attempt.min(16)))
+        .min(MAX_BACKOFF_SECS)
+}
 
-    // The `bucket` parameter may be an empty string or the literal "None"
-    // if the user does not specify a bucket.
-    let command_str = if bucket.is_empty() || bucket.eq_ignore_ascii_case("none") {
-        format!("scoop virustotal {}", package_name)
-    } else {
-        format!("scoop virustotal {}/{}", bucket, package_name)
-    };
+fn emit_scan_line(window: &Window, operation_id: &str, line: String, source: &str) {
+    if let Err(e) = window.emit(
+        "operation-output",
+        powershell::StreamOutput {
+            line,
+            source: source.to_string(),
+            operation_id: operation_id.to_string(),
+        },
+    ) {
+        log::error!("Failed to emit {} event: {}", source, e);
+    }
+}
 
-    log::info!("Executing VirusTotal scan: {}", &command_str);
+/// Forwards one output stream of the scan to the frontend while recording
+/// any rate limiting it reports.
+fn spawn_scan_reader(
+    stream: impl AsyncRead + Unpin + Send + 'static,
+    source: &'static str,
+    window: Window,
+    operation_id: String,
+    rate_limited: Arc<AtomicBool>,
+    retry_after_secs: Arc<AtomicU64>,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut reader = BufReader::new(stream).lines();
+        while let Ok(Some(line)) = reader.next_line().await {
+            if source == "stderr" {
+                log::error!("virustotal stderr: {}", &line);
+            } else {
+                log::info!("virustotal stdout: {}", &line);
+            }
+            if is_rate_limit_line(&line) {
+                rate_limited.store(true, Ordering::Relaxed);
+            }
+            if let Some(secs) = parse_retry_after_secs(&line) {
+                retry_after_secs.store(secs, Ordering::Relaxed);
+            }
+            emit_scan_line(&window, &operation_id, line, source);
+        }
+    })
+}
 
-    let mut child = powershell::create_powershell_command(&command_str)
+/// Runs `scoop virustotal` once, forwarding its output and watching for rate limiting.
+async fn run_scan_attempt(
+    window: &Window,
+    command_str: &str,
+    operation_id: &str,
+) -> Result<ScanAttempt, String> {
+    let mut child = powershell::create_powershell_command(command_str)
         .spawn()
         .map_err(|e| format!("Failed to spawn 'scoop virustotal': {}", e))?;
 
@@ -72,119 +275,403 @@ pub async fn scan_package(
         .take()
         .ok_or("Child process did not have a handle to stderr")?;
 
-    let mut stdout_reader = BufReader::new(stdout).lines();
-    let mut stderr_reader = BufReader::new(stderr).lines();
+    let rate_limited = Arc::new(AtomicBool::new(false));
+    let retry_after_secs = Arc::new(AtomicU64::new(0));
 
     // Spawn tasks to forward output to the frontend.
-    let window_clone = window.clone();
-    let operation_id_clone = operation_id.clone(); // Use the consistent operation_id
-    tokio::spawn(async move {
-        while let Ok(Some(line)) = stdout_reader.next_line().await {
-            log::info!("virustotal stdout: {}", &line);
-            if let Err(e) = window_clone.emit(
-                "operation-output",
-                powershell::StreamOutput {
-                    line,
-                    source: "stdout".to_string(),
-                    operation_id: operation_id_clone.clone(),
-                },
-            ) {
-                log::error!("Failed to emit stdout event: {}", e);
-            }
-        }
-    });
-
-    let window_clone = window.clone();
-    let operation_id_clone2 = operation_id.clone();
-    tokio::spawn(async move {
-        while let Ok(Some(line)) = stderr_reader.next_line().await {
-            log::error!("virustotal stderr: {}", &line);
-            if let Err(e) = window_clone.emit(
-                "operation-output",
-                powershell::StreamOutput {
-                    line,
-                    source: "stderr".to_string(),
-                    operation_id: operation_id_clone2.clone(),
-                },
-            ) {
-                log::error!("Failed to emit stderr event: {}", e);
-            }
-        }
-    });
+    let readers = [
+        spawn_scan_reader(
+            stdout,
+            "stdout",
+            window.clone(),
+            operation_id.to_string(),
+            rate_limited.clone(),
+            retry_after_secs.clone(),
+        ),
+        spawn_scan_reader(
+            stderr,
+            "stderr",
+            window.clone(),
+            operation_id.to_string(),
+            rate_limited.clone(),
+            retry_after_secs.clone(),
+        ),
+    ];
 
     // Wait for the command to finish.
     let status = child
         .wait()
         .await
         .map_err(|e| format!("Failed to wait on child process: {}", e))?;
-    let exit_code = status.code().unwrap_or(1); // Default to a generic error code.
+
+    // Make sure every line has been inspected before reporting the outcome.
+    for reader in readers {
+        let _ = reader.await;
+    }
+
+    let retry_after_secs = retry_after_secs.load(Ordering::Relaxed);
+    Ok(ScanAttempt {
+        exit_code: status.code().unwrap_or(1), // Default to a generic error code.
+        rate_limited: rate_limited.load(Ordering::Relaxed),
+        retry_after_secs: (retry_after_secs > 0).then_some(retry_after_secs),
+    })
+}
+
+/// Emits the `operation-finished` event that ends a scan.
+///
+/// Inconclusive scans finish with a warning rather than as a success, so an
+/// unchecked download is never presented as clean.
+fn emit_scan_finished(
+    window: &Window,
+    operation_id: &str,
+    package_name: &str,
+    outcome: ScanOutcome,
+) -> Result<(), AppError> {
+    let result = CommandResult {
+        success: outcome == ScanOutcome::Clean,
+        operation_id: operation_id.to_string(),
+        operation_name: generate_virustotal_operation_name(package_name),
+        error_count: (outcome == ScanOutcome::Failed).then_some(1),
+        final_status: match outcome {
+            ScanOutcome::Clean => powershell::FinalStatus::Success,
+            ScanOutcome::Failed => powershell::FinalStatus::Error,
+            ScanOutcome::Inconclusive => powershell::FinalStatus::Warning,
+        },
+        timestamp: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64,
+    };
+
+    log::info!("Scan finished: {:?}", result);
+
+    window
+        .emit("operation-finished", result)
+        .map_err(|e| AppError::Io(format!("Failed to emit scan result: {}", e)))?;
+    Ok(())
+}
+
+/// Scans a package with `scoop virustotal`, retrying while rate limited.
+async fn scan_with_virustotal(
+    window: &Window,
+    package_name: &str,
+    bucket: &str,
+    operation_id: &str,
+) -> Result<ScanOutcome, AppError> {
+    // The `bucket` parameter may be an empty string or the literal "None"
+    // if the user does not specify a bucket.
+    let command_str = if bucket.is_empty() || bucket.eq_ignore_ascii_case("none") {
+        format!("scoop virustotal {}", package_name)
+    } else {
+        format!("scoop virustotal {}/{}", bucket, package_name)
+    };
+
+    log::info!("Executing VirusTotal scan: {}", &command_str);
+
+    let mut attempt = 0;
+    let scan = loop {
+        let scan = run_scan_attempt(window, &command_str, operation_id)
+            .await
+            .map_err(AppError::Io)?;
+        if !scan.rate_limited || attempt >= MAX_RATE_LIMIT_RETRIES {
+            break scan;
+        }
+
+        let delay_secs = backoff_delay_secs(attempt, scan.retry_after_secs);
+        log::warn!(
+            "VirusTotal rate limited scan of {}, retrying in {}s ({}/{})",
+            package_name,
+            delay_secs,
+            attempt + 1,
+            MAX_RATE_LIMIT_RETRIES
+        );
+        emit_scan_line(
+            window,
+            operation_id,
+            format!("Rate limited, waiting {}s", delay_secs),
+            "system",
+        );
+        sleep(Duration::from_secs(delay_secs)).await;
+        attempt += 1;
+    };
+    let exit_code = scan.exit_code;
 
     // Interpret the exit code to determine the scan result.
     // See: https://github.com/rasa/scoop-virustotal#exit-codes
-    let result = match exit_code {
-        0 => {
-            // Success case - no threats found
-            CommandResult {
-                success: true,
-                operation_id: operation_id.clone(), // Use consistent operation_id
-                operation_name: generate_virustotal_operation_name(&package_name),
-                error_count: None,
-                final_status: powershell::FinalStatus::Success,
-                timestamp: std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .unwrap()
-                    .as_millis() as u64,
-            }
+    // 0 means no threats were found and 16 that no API key is set. Detections (2)
+    // and any other error are reported as failures for user awareness.
+    if exit_code == 0 {
+        Ok(ScanOutcome::Clean)
+    } else if exit_code == 16 {
+        Err(AppError::NotConfigured(
+            "No VirusTotal API key is configured".to_string(),
+        ))
+    } else if scan.rate_limited {
+        Err(AppError::RateLimited(format!(
+            "VirusTotal rate limit still exceeded after {} retries",
+            MAX_RATE_LIMIT_RETRIES
+        )))
+    } else {
+        Ok(ScanOutcome::Failed)
+    }
+}
+
+/// What a MetaDefender hash lookup says about a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum HashVerdict {
+    Clean,
+    Threat(String),
+    /// The hash is unknown to MetaDefender or the scan was inconclusive.
+    Unknown,
+}
+
+/// Interprets the body of a MetaDefender `/hash/{hash}` response.
+fn metadefender_verdict(body: &Value) -> HashVerdict {
+    let Some(results) = body.get("scan_results") else {
+        return HashVerdict::Unknown;
+    };
+    let description = results
+        .get("scan_all_result_a")
+        .and_then(Value::as_str)
+        .unwrap_or("Threat detected")
+        .to_string();
+    // 0 = no threat, 1 = infected, 2 = suspicious; everything else is inconclusive
+    match results.get("scan_all_result_i").and_then(Value::as_u64) {
+        Some(0) => HashVerdict::Clean,
+        Some(1) | Some(2) => HashVerdict::Threat(description),
+        _ => HashVerdict::Unknown,
+    }
+}
+
+/// Strips Scoop's algorithm prefix; bare hashes are SHA-256.
+fn strip_hash_algorithm(hash: &str) -> &str {
+    hash.split_once(':').map_or(hash, |(_, value)| value)
+}
+
+/// Looks up the manifest's download hashes on a MetaDefender server.
+///
+/// Unlike VirusTotal this needs no helper package: the hashes Scoop will
+/// verify are checked directly, so nothing is downloaded. The scan is only
+/// clean when every hash was scanned and none was flagged; hashes MetaDefender
+/// has never seen make the result inconclusive.
+async fn scan_with_metadefender(
+    window: &Window,
+    app: &AppHandle,
+    config: &ScanProviderConfig,
+    package_name: &str,
+    bucket: &str,
+    operation_id: &str,
+) -> Result<ScanOutcome, AppError> {
+    let api_key = config
+        .api_key
+        .as_deref()
+        .filter(|key| !key.is_empty())
+        .ok_or_else(|| {
+            AppError::NotConfigured("No MetaDefender API key is configured".to_string())
+        })?;
+    let base_url = config
+        .base_url
+        .as_deref()
+        .filter(|url| !url.trim().is_empty())
+        .unwrap_or(DEFAULT_METADEFENDER_URL)
+        .trim_end_matches('/');
+
+    let scoop_dir = app.state::<AppState>().scoop_path();
+    let bucket =
+        (!bucket.is_empty() && !bucket.eq_ignore_ascii_case("none")).then(|| bucket.to_string());
+    let (manifest_path, _) =
+        locate_package_manifest(&scoop_dir, package_name, bucket).map_err(AppError::NotFound)?;
+    let manifest = read_manifest_json(&manifest_path).map_err(AppError::Parse)?;
+    let hashes = manifest_arch_field(&manifest, "hash");
+    if hashes.is_empty() {
+        return Err(AppError::NotFound(format!(
+            "Manifest of {} declares no hashes to look up",
+            package_name
+        )));
+    }
+
+    log::info!(
+        "Looking up {} hashes of {} on {}",
+        hashes.len(),
+        package_name,
+        base_url
+    );
+
+    let client = reqwest::Client::builder()
+        .timeout(METADEFENDER_TIMEOUT)
+        .user_agent("Pailer")
+        .build()
+        .map_err(|e| AppError::Network(format!("Failed to create HTTP client: {}", e)))?;
+
+    let mut threats = 0;
+    let mut unknown = 0;
+    for hash in &hashes {
+        let hash = strip_hash_algorithm(hash);
+        let response = client
+            .get(format!("{}/hash/{}", base_url, hash))
+            .header("apikey", api_key)
+            .send()
+            .await
+            .map_err(|e| AppError::Network(format!("MetaDefender request failed: {}", e)))?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return Err(AppError::RateLimited(
+                "MetaDefender rate limit exceeded".to_string(),
+            ));
         }
-        2 => {
-            // Detections found - this should be marked as failure for user awareness
-            CommandResult {
-                success: false, // Changed: threats found should be marked as failed
-                operation_id: operation_id.clone(), // Use consistent operation_id
-                operation_name: generate_virustotal_operation_name(&package_name),
-                error_count: Some(1), // 1 error = detections found
-                final_status: powershell::FinalStatus::Error,
-                timestamp: std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .unwrap()
-                    .as_millis() as u64,
+        let verdict = if status == reqwest::StatusCode::NOT_FOUND {
+            HashVerdict::Unknown
+        } else if status.is_success() {
+            let body: Value = response
+                .json()
+                .await
+                .map_err(|e| AppError::Parse(format!("Invalid MetaDefender response: {}", e)))?;
+            metadefender_verdict(&body)
+        } else {
+            return Err(AppError::Network(format!(
+                "MetaDefender request failed with status {}",
+                status
+            )));
+        };
+
+        let line = match &verdict {
+            HashVerdict::Clean => format!("{}: no threat detected", hash),
+            HashVerdict::Threat(description) => {
+                threats += 1;
+                format!("{}: {}", hash, description)
             }
-        }
-        16 => {
-            // API key missing - configuration error
-            CommandResult {
-                success: false,                     // Changed: API key missing is a failure
-                operation_id: operation_id.clone(), // Use consistent operation_id
-                operation_name: generate_virustotal_operation_name(&package_name),
-                error_count: Some(1), // 1 error = API key missing
-                final_status: powershell::FinalStatus::Error,
-                timestamp: std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .unwrap()
-                    .as_millis() as u64,
+            HashVerdict::Unknown => {
+                unknown += 1;
+                format!("{}: not known to MetaDefender", hash)
             }
+        };
+        let source = if matches!(verdict, HashVerdict::Threat(_)) {
+            "stderr"
+        } else {
+            "stdout"
+        };
+        emit_scan_line(window, operation_id, line, source);
+    }
+
+    if threats > 0 {
+        Ok(ScanOutcome::Failed)
+    } else if unknown > 0 {
+        emit_scan_line(
+            window,
+            operation_id,
+            format!(
+                "{} of {} hashes could not be checked, the scan is inconclusive",
+                unknown,
+                hashes.len()
+            ),
+            "stderr",
+        );
+        Ok(ScanOutcome::Inconclusive)
+    } else {
+        Ok(ScanOutcome::Clean)
+    }
+}
+
+/// Scans a package with the configured provider and emits the results.
+///
+/// This command streams its output to the frontend and emits a `operation-finished`
+/// event with a `CommandResult` payload upon completion, including when the scan
+/// fails. Rate-limited VirusTotal requests are retried with exponential backoff
+/// before giving up with `AppError::RateLimited`.
+#[tauri::command]
+pub async fn scan_package(
+    window: Window,
+    app: AppHandle,
+    package_name: String,
+    bucket: String,
+) -> Result<(), AppError> {
+    let config = read_scan_provider(&app);
+
+    // Generate consistent operation ID at the beginning
+    let prefix = match config.provider {
+        ScanProvider::VirusTotal => "virustotal",
+        ScanProvider::MetaDefender => "metadefender",
+    };
+    let operation_id = format!(
+        "{}-{}-{}",
+        prefix,
+        package_name,
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis()
+    );
+
+    let result = match config.provider {
+        ScanProvider::VirusTotal => {
+            scan_with_virustotal(&window, &package_name, &bucket, &operation_id).await
         }
-        _ => {
-            // Other errors - command execution failed
-            CommandResult {
-                success: false,
-                operation_id: operation_id.clone(), // Use consistent operation_id
-                operation_name: generate_virustotal_operation_name(&package_name),
-                error_count: Some(1), // 1 error = unknown error
-                final_status: powershell::FinalStatus::Error,
-                timestamp: std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .unwrap()
-                    .as_millis() as u64,
-            }
+        ScanProvider::MetaDefender => {
+            scan_with_metadefender(
+                &window,
+                &app,
+                &config,
+                &package_name,
+                &bucket,
+                &operation_id,
+            )
+            .await
         }
     };
 
-    log::info!("VirusTotal scan finished: {:?}", result);
+    match result {
+        Ok(outcome) => emit_scan_finished(&window, &operation_id, &package_name, outcome),
+        Err(error) => {
+            log::error!("Scan of {} failed: {}", package_name, error);
+            emit_scan_line(&window, &operation_id, error.to_string(), "stderr");
+            emit_scan_finished(&window, &operation_id, &package_name, ScanOutcome::Failed)?;
+            Err(error)
+        }
+    }
+}
 
-    window
-        .emit("operation-finished", result)
-        .map_err(|e| format!("Failed to emit scan result: {}", e))?;
+#[cfg(test)]
+mod tests {
+    use super::{
+        backoff_delay_secs, is_rate_limit_line, metadefender_verdict, parse_retry_after_secs,
+        strip_hash_algorithm, HashVerdict,
+    };
+    use serde_json::json;
 
-    Ok(())
+    #[test]
+    fn detects_rate_limit_output() {
+        assert!(is_rate_limit_line(
+            "VirusTotal request failed: Response status code (429) Too Many Requests"
+        ));
+        assert!(!is_rate_limit_line("7zip: 0/72 engines detected"));
+    }
+
+    #[test]
+    fn retry_after_overrides_exponential_backoff() {
+        assert_eq!(parse_retry_after_secs("Retry-After: 30"), Some(30));
+        assert_eq!(parse_retry_after_secs("no header here"), None);
+        assert_eq!(backoff_delay_secs(0, None), 15);
+        assert_eq!(backoff_delay_secs(2, None), 60);
+        assert_eq!(backoff_delay_secs(5, None), 120);
+        assert_eq!(backoff_delay_secs(0, Some(45)), 45);
+    }
+
+    #[test]
+    fn interprets_metadefender_hash_lookups() {
+        let clean = json!({ "scan_results": { "scan_all_result_i": 0 } });
+        let infected = json!({
+            "scan_results": { "scan_all_result_i": 1, "scan_all_result_a": "Infected" }
+        });
+        let unknown = json!({ "abc123": "Not Found" });
+
+        assert_eq!(metadefender_verdict(&clean), HashVerdict::Clean);
+        assert_eq!(
+            metadefender_verdict(&infected),
+            HashVerdict::Threat("Infected".to_string())
+        );
+        assert_eq!(metadefender_verdict(&unknown), HashVerdict::Unknown);
+        assert_eq!(strip_hash_algorithm("sha1:abc"), "abc");
+        assert_eq!(strip_hash_algorithm("abc"), "abc");
+    }
 }
@@ -0,0 +1,130 @@
+//! Structured error type for commands whose failures the frontend branches on.
+use serde::Serialize;
+use std::fmt;
+
+/// A command error serialized as `{ kind, message }`, where `message` is
+/// human-readable and safe to display as-is.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "kind", content = "message", rename_all = "snake_case")]
+pub enum AppError {
+    NotFound(String),
+    PermissionDenied(String),
+    Network(String),
+    ScoopCommandFailed(String),
+    Io(String),
+    Parse(String),
+    /// A remote service kept rejecting requests as too frequent.
+    RateLimited(String),
+    /// A feature needs configuration, such as an API key, that is missing.
+    NotConfigured(String),
+    /// The user cancelled the operation, before or while it ran.
+    Cancelled(String),
+    /// The operation was stopped after producing no output for too long.
+    Timeout(String),
+    /// Another operation, e.g. a Scoop run from a terminal, is in the way.
+    Busy(String),
+}
+
+impl AppError {
+    /// Returns the human-readable message.
+    pub fn message(&self) -> &str {
+        match self {
+            AppError::NotFound(message)
+            | AppError::PermissionDenied(message)
+            | AppError::Network(message)
+            | AppError::ScoopCommandFailed(message)
+            | AppError::Io(message)
+            | AppError::Parse(message)
+            | AppError::RateLimited(message)
+            | AppError::NotConfigured(message)
+            | AppError::Cancelled(message)
+            | AppError::Timeout(message)
+            | AppError::Busy(message) => message,
+        }
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl std::error::Error for AppError {}
+
+/// Lets callers that return `Result<_, String>` keep using `?` on migrated commands.
+impl From<AppError> for String {
+    fn from(error: AppError) -> Self {
+        error.to_string()
+    }
+}
+
+impl From<std::io::Error> for AppError {
+    fn from(error: std::io::Error) -> Self {
+        match error.kind() {
+            std::io::ErrorKind::NotFound => AppError::NotFound(error.to_string()),
+            std::io::ErrorKind::PermissionDenied => AppError::PermissionDenied(error.to_string()),
+            _ => AppError::Io(error.to_string()),
+        }
+    }
+}
+
+impl From<serde_json::Error> for AppError {
+    fn from(error: serde_json::Error) -> Self {
+        AppError::Parse(error.to_string())
+    }
+}
+
+impl From<git2::Error> for AppError {
+    fn from(error: git2::Error) -> Self {
+        match (error.code(), error.class()) {
+            (git2::ErrorCode::NotFound, _) => AppError::NotFound(error.message().to_string()),
+            (git2::ErrorCode::Auth, _) => AppError::PermissionDenied(error.message().to_string()),
+            (_, git2::ErrorClass::Net | git2::ErrorClass::Http | git2::ErrorClass::Ssh) => {
+                AppError::Network(error.message().to_string())
+            }
+            _ => AppError::Io(error.message().to_string()),
+        }
+    }
+}
+
+impl From<reqwest::Error> for AppError {
+    fn from(error: reqwest::Error) -> Self {
+        AppError::Network(error.to_string())
+    }
+}
+
+impl From<tokio::task::JoinError> for AppError {
+    fn from(error: tokio::task::JoinError) -> Self {
+        AppError::Io(format!("Task failed: {}", error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AppError;
+
+    #[test]
+    fn serializes_as_kind_and_message() {
+        let error = AppError::NotFound("Bucket 'extras' does not exist".to_string());
+        assert_eq!(
+            serde_json::to_value(&error).unwrap(),
+            serde_json::json!({
+                "kind": "not_found",
+                "message": "Bucket 'extras' does not exist",
+            })
+        );
+    }
+
+    #[test]
+    fn maps_io_error_kinds() {
+        let denied = std::io::Error::new(std::io::ErrorKind::PermissionDenied, "denied");
+        assert!(matches!(
+            AppError::from(denied),
+            AppError::PermissionDenied(_)
+        ));
+
+        let other = std::io::Error::new(std::io::ErrorKind::Other, "disk full");
+        assert_eq!(AppError::from(other), AppError::Io("disk full".to_string()));
+    }
+}
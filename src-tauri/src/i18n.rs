@@ -41,11 +41,15 @@ pub fn default_tray_strings() -> Value {
         "hide": "Hide Pailer",
         "refreshApps": "Refresh Apps",
         "scoopApps": "Scoop Apps",
+        "search": "Search…",
         "quit": "Quit",
         "notificationTitle": "Pailer - Minimized to Tray",
         "notificationMessage": "Pailer has been minimized to the system tray and will continue running in the background.\n\nYou can:\n• Click the tray icon to restore the window\n• Right-click the tray icon to access the context menu\n• Change this behavior in Settings > Window Behavior",
         "closeAndDisable": "Close and Disable Tray",
-        "keepInTray": "Keep in Tray"
+        "keepInTray": "Keep in Tray",
+        "autoUpdateCompleteTitle": "Pailer - Background update finished",
+        "autoUpdateCompleteMessage": "Updated {{buckets}} buckets, {{packages}} packages.",
+        "updatesAvailable": "Pailer - {{count}} updates available"
     })
 }
 
@@ -1,6 +1,7 @@
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
 mod cold_start;
 mod commands;
+mod error;
 mod i18n;
 mod models;
 mod scheduler;
@@ -18,8 +19,12 @@ mod config_keys {
     pub const WINDOW_CLOSE_TO_TRAY: &str = "window.closeToTray";
     pub const WINDOW_FIRST_TRAY_NOTIFICATION_SHOWN: &str = "window.firstTrayNotificationShown";
     pub const TRAY_APPS_LIST: &str = "tray.appsList";
+    pub const LOGS_RETAIN_COUNT: &str = "logs.retainCount";
 }
 
+/// Number of log files kept on launch when `logs.retainCount` is not set.
+const DEFAULT_LOG_RETAIN_COUNT: usize = 5;
+
 fn parse_log_level(value: &str) -> Option<log::LevelFilter> {
     match value.trim().to_ascii_lowercase().as_str() {
         "off" => Some(log::LevelFilter::Off),
@@ -51,6 +56,31 @@ fn resolve_default_log_level() -> log::LevelFilter {
     }
 }
 
+/// Reads `logs.retainCount` straight from the settings file.
+///
+/// The store plugin is not registered yet when logging is set up, so the file is parsed directly.
+fn resolve_log_retain_count() -> usize {
+    let settings_path = match dirs::data_dir() {
+        Some(dir) => dir.join("com.pailer.ks").join("settings.json"),
+        None => return DEFAULT_LOG_RETAIN_COUNT,
+    };
+
+    let settings = match std::fs::read_to_string(&settings_path)
+        .ok()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+    {
+        Some(settings) => settings,
+        None => return DEFAULT_LOG_RETAIN_COUNT,
+    };
+
+    settings
+        .get(config_keys::LOGS_RETAIN_COUNT)
+        .or_else(|| settings.pointer("/settings/logs/retainCount"))
+        .and_then(|value| value.as_u64())
+        .map(|count| count as usize)
+        .unwrap_or(DEFAULT_LOG_RETAIN_COUNT)
+}
+
 fn should_trace_installed_commands() -> bool {
     env::var("PAILER_TRACE_INSTALLED")
         .map(|value| {
@@ -116,13 +146,16 @@ pub fn run() {
     // Add single instance plugin only on Windows
     #[cfg(windows)]
     {
-        builder = builder.plugin(tauri_plugin_single_instance::init(|app, _argv, _cwd| {
+        builder = builder.plugin(tauri_plugin_single_instance::init(|app, argv, _cwd| {
             // When a second instance is attempted, show and focus the existing window
             if let Some(window) = app.get_webview_window("main") {
                 let _ = window.show();
                 let _ = window.set_focus();
                 let _ = window.unminimize();
             }
+
+            // Forward a clicked `rscoop://install` link to the running instance
+            commands::package_link::handle_package_link_args(app, &argv, false);
         }));
     }
 
@@ -131,7 +164,7 @@ pub fn run() {
         .map(|dir| dir.join("com.pailer.ks").join("logs"))
         .unwrap_or_else(|| PathBuf::from("./logs"));
 
-    cleanup_old_logs(&log_dir);
+    cleanup_old_logs(&log_dir, resolve_log_retain_count());
 
     // Create log directory if it does not exist
     if let Err(e) = std::fs::create_dir_all(&log_dir) {
@@ -149,6 +182,8 @@ pub fn run() {
                 file_name: None,
             }),
         ])
+        // Keep rotated files around; old ones are pruned by `cleanup_old_logs` on launch
+        .rotation_strategy(tauri_plugin_log::RotationStrategy::KeepAll)
         .level(default_log_level)
         .level_for("lnk", log::LevelFilter::Warn)
         .level_for("reqwest", log::LevelFilter::Warn)
@@ -168,7 +203,7 @@ pub fn run() {
         .plugin({
             #[cfg(windows)]
             {
-                if !utils::is_scoop_installation() {
+                if !utils::is_scoop_managed_executable() {
                     tauri_plugin_updater::Builder::new().build()
                 } else {
                     tauri::plugin::Builder::new("empty").build()
@@ -199,7 +234,11 @@ pub fn run() {
                 }
             };
 
-            app.manage(state::AppState::new(scoop_path, configured));
+            let app_state = state::AppState::new(scoop_path, configured);
+            let global_scoop_path =
+                commands::settings::resolve_global_scoop_path(app.handle().clone());
+            tauri::async_runtime::block_on(app_state.set_global_scoop_path(global_scoop_path));
+            app.manage(app_state);
 
             #[cfg(windows)]
             {
@@ -231,8 +270,13 @@ pub fn run() {
             // Tray will be created when frontend syncs locale data
             // No need for delayed initialization here
 
+            // Pick up a package link the app was launched with
+            let argv: Vec<String> = env::args().skip(1).collect();
+            commands::package_link::handle_package_link_args(app.handle(), &argv, true);
+
             // Start background tasks
             scheduler::start_background_tasks(app.handle().clone());
+            scheduler::start_cleanup_task(app.handle().clone());
 
             Ok(())
         })
@@ -242,27 +286,67 @@ pub fn run() {
         })
         .invoke_handler(tauri::generate_handler![
             commands::search::search_scoop,
+            commands::search::search_scoop_streaming,
+            commands::search::find_by_binary,
             commands::search::get_package_buckets,
+            commands::search::find_bucket_conflicts,
+            commands::search::get_available_versions,
             commands::self_update::update_pailer_self,
             commands::self_update::can_self_update,
+            commands::self_update::check_app_update,
             commands::installed::get_installed_packages_full,
             commands::installed::refresh_installed_packages,
+            commands::installed::get_installed_packages_by_source,
+            commands::installed::get_installed_sorted_by_date,
+            commands::installed::get_installed_grouped_by_bucket,
             commands::installed::get_package_path,
+            commands::installed::open_package_folder,
             commands::installed::get_current_version_install_time,
             commands::installed::get_current_version_update_date,
+            commands::installed::find_duplicate_installs,
+            commands::installed::find_unsourced_packages,
+            commands::installed::find_ambiguous_packages,
+            commands::installed::list_env_contributions,
+            commands::installed::find_suboptimal_architecture,
+            commands::scoopfile::export_scoopfile,
+            commands::scoopfile::import_scoopfile,
+            commands::scoopfile::diff_scoopfiles,
+            commands::scoop_migration::preview_scoop_migration,
+            commands::scoop_migration::migrate_scoop_directory,
             commands::package_icon::get_installed_package_icons,
+            commands::package_icon::get_package_icon,
             commands::info::get_package_info,
             commands::info::get_package_run_entries,
             commands::info::run_package_entry,
             commands::install::install_package,
+            commands::install::install_from_manifest,
+            commands::install::get_install_command,
+            commands::install::get_install_plan,
+            commands::install::check_space_for_install,
+            commands::package_link::generate_package_link,
+            commands::package_link::take_pending_package_link,
             commands::scoop::retry_operation_elevated,
             commands::manifest::get_package_manifest,
+            commands::manifest::diff_manifest,
+            commands::manifest::validate_manifest,
+            commands::manifest::open_manifest_in_editor,
+            commands::package_hash::verify_package_hash,
+            commands::package_hash::audit_installed_integrity,
+            commands::persist::backup_persisted_data,
+            commands::persist::restore_persisted_data,
+            commands::network::is_metered_connection,
+            commands::network::get_offline_mode,
             commands::updates::check_for_updates,
+            commands::updates::check_for_updates_with_sizes,
+            commands::updates::check_package_for_update,
+            commands::updates::get_update_count,
             commands::update::update_package,
             commands::update::update_all_packages,
             commands::uninstall::uninstall_package,
+            commands::uninstall::uninstall_preview,
             commands::uninstall::clear_package_cache,
             commands::status::check_scoop_status,
+            commands::status::get_scoop_health_summary,
             commands::settings::get_config_value,
             commands::settings::set_config_value,
             commands::settings::get_scoop_path,
@@ -270,12 +354,31 @@ pub fn run() {
             commands::settings::get_scoop_path_manually_configured,
             commands::settings::get_virustotal_api_key,
             commands::settings::set_virustotal_api_key,
+            commands::settings::get_github_token,
+            commands::settings::set_github_token,
             commands::settings::get_scoop_proxy,
             commands::settings::set_scoop_proxy,
+            commands::settings::migrate_settings_store,
+            commands::settings::export_app_settings,
+            commands::settings::import_app_settings,
+            commands::settings::get_scoop_core_source,
+            commands::settings::set_scoop_core_source,
+            commands::settings::reset_scoop_core_source,
+            commands::settings::get_scoop_cache_dir,
+            commands::settings::set_scoop_cache_dir,
+            commands::scoop_alias::list_scoop_aliases,
+            commands::scoop_alias::add_scoop_alias,
+            commands::scoop_alias::remove_scoop_alias,
+            commands::settings::get_default_architecture,
+            commands::settings::set_default_architecture,
+            commands::settings::get_scoop_config_bool,
+            commands::settings::set_scoop_config_bool,
             commands::settings::auto_detect_scoop_path,
             commands::settings::path_exists,
             commands::settings::get_default_scoop_config,
             commands::settings::validate_scoop_directory,
+            commands::settings::get_global_scoop_path,
+            commands::settings::set_global_scoop_path,
             commands::settings::check_directory_exists,
             commands::settings::run_scoop_command,
             commands::settings::run_powershell_command,
@@ -285,17 +388,31 @@ pub fn run() {
             commands::settings::set_powershell_exe,
             commands::settings::get_available_powershell_executables,
             commands::powershell::request_cancel_operation,
+            commands::operation_queue::get_operation_queue,
             commands::process_control::terminate_processes,
             commands::process_control::terminate_package_processes,
+            commands::process_control::is_scoop_operation_running,
             commands::virustotal::scan_package,
+            commands::virustotal::get_scan_provider,
+            commands::virustotal::set_scan_provider,
             commands::auto_cleanup::run_auto_cleanup,
+            commands::auto_cleanup::estimate_reclaimable_space,
+            commands::auto_cleanup::list_failed_downloads,
+            commands::auto_cleanup::clear_failed_downloads,
             commands::doctor::checkup::run_scoop_checkup,
+            commands::doctor::checkup::run_single_check,
+            commands::doctor::checkup::export_checkup_markdown,
+            commands::doctor::checkup::install_missing_helper,
+            commands::doctor::checkup::get_last_checkup,
+            commands::doctor::checkup::enable_long_paths,
+            commands::doctor::checkup::set_execution_policy_remotesigned,
             commands::doctor::cleanup::cleanup_all_apps,
             commands::doctor::cleanup::cleanup_all_apps_force,
             commands::doctor::cleanup::cleanup_all_apps_smart,
             commands::doctor::cleanup::cleanup_outdated_cache,
             commands::doctor::cleanup::remove_cache_for_specific_packages,
             commands::doctor::cleanup::remove_all_cache_with_scoop,
+            commands::doctor::cleanup::run_native_scoop_cleanup,
             commands::doctor::cache::list_cache_contents,
             commands::doctor::cache::clear_cache,
             commands::doctor::versioned_apps::get_versioned_apps,
@@ -306,6 +423,11 @@ pub fn run() {
             commands::doctor::shim::remove_shim,
             commands::doctor::shim::alter_shim,
             commands::doctor::shim::add_shim,
+            commands::doctor::shim::add_shim_alias,
+            commands::doctor::shim::export_shims,
+            commands::doctor::shim::repair_broken_shims,
+            commands::doctor::shim::which_binary,
+            commands::doctor::shim::analyze_path,
             commands::doctor::shim::update_shim_args,
             commands::doctor::notify_icon_settings::preview_dedupe_notify_icon_settings,
             commands::doctor::notify_icon_settings::apply_dedupe_notify_icon_settings,
@@ -316,6 +438,9 @@ pub fn run() {
             commands::hold::list_held_packages,
             commands::hold::hold_package,
             commands::hold::unhold_package,
+            commands::hold::hold_bucket,
+            commands::hold::unhold_bucket,
+            commands::package_notes::set_package_note,
             commands::bucket::get_buckets,
             commands::bucket::get_bucket_summaries,
             commands::bucket::get_bucket_info,
@@ -324,21 +449,30 @@ pub fn run() {
             commands::bucket::get_bucket_manifests_page,
             commands::bucket::get_bucket_branches,
             commands::bucket::switch_bucket_branch,
+            commands::bucket::get_bucket_remote,
             commands::bucket_install::install_bucket,
             commands::bucket_install::validate_bucket_install,
             commands::bucket_install::update_bucket,
+            commands::bucket_install::check_bucket_has_updates,
             commands::bucket_install::update_all_buckets,
+            commands::bucket_install::reclone_all_buckets,
+            commands::bucket_install::update_buckets,
             commands::bucket_install::remove_bucket,
             commands::bucket_search::search_buckets,
             // commands::bucket_search::get_expanded_search_info,
             commands::bucket_search::get_default_buckets,
             commands::bucket_search::clear_bucket_cache,
             commands::bucket_search::check_bucket_cache_exists,
+            commands::bucket_search::get_bucket_cache_stats,
             commands::bucket_search::get_bucket_cache_info,
             commands::bucket_search::refresh_bucket_cache_if_needed,
+            commands::cache_rebuild::rebuild_all_caches,
             commands::app_info::is_scoop_installation,
+            commands::app_info::is_cwd_mismatch,
             commands::linker::get_package_versions,
             commands::linker::switch_package_version,
+            commands::linker::find_broken_junctions,
+            commands::linker::repair_junction,
             commands::linker::analyze_package_fast_switch,
             commands::linker::get_versioned_packages,
             commands::linker::debug_package_structure,
@@ -346,8 +480,11 @@ pub fn run() {
             commands::debug::get_debug_info,
             commands::debug::get_app_logs,
             commands::debug::read_app_log_file,
+            commands::debug::start_log_tail,
+            commands::debug::stop_log_tail,
             commands::debug::get_app_data_dir,
             commands::debug::get_log_dir_cmd,
+            commands::debug::export_logs_zip,
             commands::debug::get_log_retention_days,
             commands::debug::set_log_retention_days,
             commands::debug::check_factory_reset_marker,
@@ -401,8 +538,8 @@ fn set_explicit_app_user_model_id() {
     }
 }
 
-// Helper function: Clean up old log files in the specified directory
-fn cleanup_old_logs(log_dir: &PathBuf) {
+// Helper function: Remove all but the `retain_count` most recent log files
+fn cleanup_old_logs(log_dir: &PathBuf, retain_count: usize) {
     if !log_dir.exists() {
         return;
     }
@@ -411,16 +548,27 @@ fn cleanup_old_logs(log_dir: &PathBuf) {
         let mut removed_count = 0;
         let mut failed_count = 0;
 
-        for entry in entries.flatten() {
-            if let Ok(metadata) = entry.metadata() {
-                if metadata.is_file() {
-                    match std::fs::remove_file(entry.path()) {
-                        Ok(_) => removed_count += 1,
-                        Err(e) => {
-                            log::debug!("Failed to remove log file {:?}: {}", entry.path(), e);
-                            failed_count += 1;
-                        }
-                    }
+        let mut log_files: Vec<(PathBuf, std::time::SystemTime)> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let metadata = entry.metadata().ok()?;
+                if !metadata.is_file() {
+                    return None;
+                }
+                let modified = metadata.modified().unwrap_or(std::time::UNIX_EPOCH);
+                Some((entry.path(), modified))
+            })
+            .collect();
+
+        // Newest first, so everything past `retain_count` is stale
+        log_files.sort_by(|a, b| b.1.cmp(&a.1));
+
+        for (path, _) in log_files.into_iter().skip(retain_count) {
+            match std::fs::remove_file(&path) {
+                Ok(_) => removed_count += 1,
+                Err(e) => {
+                    log::debug!("Failed to remove log file {:?}: {}", path, e);
+                    failed_count += 1;
                 }
             }
         }
@@ -440,8 +588,18 @@ fn cleanup_old_logs(log_dir: &PathBuf) {
 // Windows-specific setup
 #[cfg(windows)]
 fn setup_windows_specific(app: &tauri::App) -> Result<(), Box<dyn std::error::Error>> {
+    match commands::app_info::is_cwd_mismatch() {
+        Ok(result) if result.mismatch => log::warn!(
+            "Running from '{}' instead of '{}'; Scoop may replace or lock this directory",
+            result.cwd,
+            result.expected
+        ),
+        Ok(_) => {}
+        Err(e) => log::debug!("Skipping working directory check: {}", e),
+    }
+
     // Only configure updater if not installed via Scoop
-    if !utils::is_scoop_installation() {
+    if !utils::is_scoop_managed_executable() {
         // Configure updater based on the current channel setting
         let app_handle = app.handle().clone();
         tauri::async_runtime::spawn(async move {
@@ -454,6 +612,11 @@ fn setup_windows_specific(app: &tauri::App) -> Result<(), Box<dyn std::error::Er
 
         log::info!("Updater plugin initialized successfully");
     }
+
+    if let Err(e) = commands::package_link::register_package_link_scheme() {
+        log::warn!("Failed to register package link scheme: {}", e);
+    }
+
     Ok(())
 }
 
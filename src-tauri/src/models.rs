@@ -42,6 +42,26 @@ impl Default for InstallationType {
     }
 }
 
+/// Whether a package is installed for the current user or globally (`--global`).
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PackageScope {
+    #[default]
+    User,
+    Global,
+}
+
+/// Whether an installed package's directory layout is intact.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PackageHealth {
+    #[default]
+    Ok,
+    /// The `current` link is missing; reported instead of guessing a version
+    /// directory when `scan.strictCurrent` is enabled.
+    MissingCurrent,
+}
+
 // -----------------------------------------------------------------------------
 // ScoopPackage
 // -----------------------------------------------------------------------------
@@ -72,6 +92,17 @@ pub struct ScoopPackage {
     /// The latest locally installed version string
     #[serde(default)]
     pub local_latest_version: Option<String>,
+    /// The architecture the package was installed for (e.g. `64bit`, `32bit`, `arm64`)
+    #[serde(default)]
+    pub architecture: Option<String>,
+    /// Whether the package lives in the user or the global Scoop root
+    #[serde(default)]
+    pub scope: PackageScope,
+    #[serde(default)]
+    pub health: PackageHealth,
+    /// The user's own note on the package, kept in the settings store
+    #[serde(default)]
+    pub user_note: Option<String>,
 }
 
 fn default_true() -> bool {
@@ -154,6 +185,9 @@ pub struct PackageManifest {
     pub license: Option<String>,
     #[serde(default)]
     pub notes: Option<String>,
+    /// The only architecture declared by the manifest, if it declares exactly one
+    #[serde(default)]
+    pub architecture: Option<String>,
 }
 
 fn default_version() -> String {
@@ -163,6 +197,8 @@ fn default_version() -> String {
 #[derive(Deserialize, Debug, Clone, Default)]
 pub struct InstallManifest {
     pub bucket: Option<String>,
+    #[serde(default)]
+    pub architecture: Option<String>,
 }
 
 // -----------------------------------------------------------------------------
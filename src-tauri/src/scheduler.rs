@@ -1,5 +1,6 @@
-use tauri::{AppHandle, Emitter, Manager};
+use tauri::{AppHandle, Emitter, Listener, Manager};
 
+use crate::commands::bucket_install::{BucketUpdateProgressEvent, EVENT_BUCKET_UPDATE_PROGRESS};
 use crate::commands::package_mutation::emit_installed_packages_changed;
 
 pub fn start_background_tasks(app: AppHandle) {
@@ -50,6 +51,14 @@ pub fn start_background_tasks(app: AppHandle) {
             };
 
             if elapsed >= interval_secs {
+                if should_skip_on_metered(&app).await {
+                    // Leave the timestamp untouched so the update runs once the
+                    // connection is no longer metered.
+                    log::info!("Metered connection detected, deferring auto-update");
+                    sleep(Duration::from_secs(300)).await;
+                    continue;
+                }
+
                 log::debug!(
                     "Auto-update interval elapsed ({}s), starting update check",
                     elapsed
@@ -73,6 +82,160 @@ pub fn start_background_tasks(app: AppHandle) {
     });
 }
 
+/// Runs auto cleanup on its own `cleanup.autoInterval` cadence, persisting the
+/// last run in `cleanup.lastRunTs` like the bucket scheduler does.
+///
+/// Scheduled runs only happen while auto cleanup is enabled and clean the
+/// categories selected in the cleanup settings.
+pub fn start_cleanup_task(app: AppHandle) {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+    use tokio::time::sleep;
+
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let interval_raw = crate::commands::settings::get_config_value(
+                app.clone(),
+                "cleanup.autoInterval".to_string(),
+            )
+            .ok()
+            .flatten()
+            .and_then(|v| v.as_str().map(|s| s.to_string()))
+            .unwrap_or_else(|| "off".to_string());
+
+            let Some(interval_secs) = parse_update_interval(&interval_raw) else {
+                sleep(Duration::from_secs(300)).await;
+                continue;
+            };
+
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs();
+            let last_ts = crate::commands::settings::get_config_value(
+                app.clone(),
+                "cleanup.lastRunTs".to_string(),
+            )
+            .ok()
+            .flatten()
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0);
+
+            let elapsed = if last_ts == 0 {
+                interval_secs
+            } else {
+                now.saturating_sub(last_ts)
+            };
+
+            if elapsed >= interval_secs {
+                log::debug!(
+                    "Cleanup interval elapsed ({}s), starting scheduled cleanup",
+                    elapsed
+                );
+                run_scheduled_cleanup(&app, now).await;
+                continue;
+            }
+
+            let remaining = interval_secs - elapsed;
+            sleep(Duration::from_secs(remaining.min(60))).await;
+        }
+    });
+}
+
+async fn run_scheduled_cleanup(app_handle: &tauri::AppHandle, run_started_at: u64) {
+    // Record the run up front so a failing cleanup is not retried every minute
+    let _ = crate::commands::settings::set_config_value(
+        app_handle.clone(),
+        "cleanup.lastRunTs".to_string(),
+        serde_json::json!(run_started_at),
+    );
+
+    let settings = match crate::commands::auto_cleanup::read_cleanup_settings(app_handle) {
+        Ok(settings) if settings.auto_cleanup_enabled => settings,
+        Ok(_) => {
+            log::debug!("Auto cleanup is disabled, skipping scheduled cleanup");
+            return;
+        }
+        Err(e) => {
+            log::debug!("Could not read cleanup settings: {}", e);
+            return;
+        }
+    };
+
+    log::info!("Starting scheduled cleanup task");
+    let operation_id = format!("scheduler-cleanup-{}", run_started_at);
+    let window = app_handle.get_webview_window("main");
+    let emit_line = |line: String, source: &str| {
+        if let Some(window) = &window {
+            let _ = window.emit(
+                "operation-output",
+                serde_json::json!({
+                    "line": line,
+                    "source": source,
+                    "operationId": operation_id
+                }),
+            );
+        }
+    };
+
+    if let Some(window) = &window {
+        let _ = window.emit("auto-operation-start", "Cleaning up...");
+    }
+    emit_line("Starting scheduled cleanup...".to_string(), "stdout");
+
+    // Wait behind any install/update so partial downloads in use are left alone
+    let state = app_handle.state::<crate::state::AppState>();
+    let result = match crate::commands::operation_queue::wait_for_turn(
+        app_handle,
+        &operation_id,
+        "Scheduled cleanup",
+    )
+    .await
+    {
+        Ok(_turn) => {
+            crate::commands::auto_cleanup::cleanup_with_settings(
+                app_handle.clone(),
+                state,
+                settings,
+                false,
+            )
+            .await
+        }
+        Err(e) => Err(e.to_string()),
+    };
+
+    let (success, message) = match &result {
+        Ok(report) => {
+            for category in &report.categories {
+                emit_line(
+                    format!(
+                        "✓ {}: {} item(s), {} bytes",
+                        category.category, category.items, category.bytes
+                    ),
+                    "stdout",
+                );
+            }
+            (true, "Scheduled cleanup completed".to_string())
+        }
+        Err(e) => {
+            log::warn!("Scheduled cleanup failed: {}", e);
+            emit_line(format!("Error: {}", e), "stderr");
+            (false, format!("Scheduled cleanup failed: {}", e))
+        }
+    };
+
+    if let Some(window) = &window {
+        let _ = window.emit(
+            "operation-finished",
+            serde_json::json!({
+                "success": success,
+                "message": message,
+                "operationId": operation_id,
+                "finalStatus": if success { "success" } else { "error" }
+            }),
+        );
+    }
+}
+
 async fn run_auto_update(app_handle: &tauri::AppHandle, run_started_at: u64) {
     log::info!("Starting auto bucket update task");
     let operation_id = format!("scheduler-bucket-update-{}", run_started_at);
@@ -110,13 +273,53 @@ async fn run_auto_update(app_handle: &tauri::AppHandle, run_started_at: u64) {
         }
     }
 
+    // Forward per-bucket progress to the operation output as each bucket finishes
+    let run_id = format!("scheduler-bucket-update-progress-{}", run_started_at);
+    let progress_listener = (!silent_update_enabled).then(|| {
+        let window_handle = app_handle.clone();
+        let run_id = run_id.clone();
+        let operation_id = operation_id.clone();
+        app_handle.listen(EVENT_BUCKET_UPDATE_PROGRESS, move |event| {
+            let Ok(progress) = serde_json::from_str::<BucketUpdateProgressEvent>(event.payload())
+            else {
+                return;
+            };
+            if progress.run_id != run_id {
+                return;
+            }
+
+            let result = &progress.result;
+            let line = if result.success {
+                format!("✓ Updated bucket: {}", result.bucket_name)
+            } else {
+                format!(
+                    "✗ Failed to update {}: {}",
+                    result.bucket_name, result.message
+                )
+            };
+
+            if let Some(window) = window_handle.get_webview_window("main") {
+                let _ = window.emit(
+                    "operation-output",
+                    serde_json::json!({
+                        "line": line,
+                        "source": if result.success { "stdout" } else { "stderr" },
+                        "operationId": operation_id
+                    }),
+                );
+            }
+        })
+    });
+
     // Update Buckets
-    match crate::commands::bucket_install::update_all_buckets(
-        app_handle.clone(),
-        format!("scheduler-bucket-update-progress-{}", run_started_at),
-    )
-    .await
-    {
+    let update_result =
+        crate::commands::bucket_install::update_all_buckets(app_handle.clone(), run_id).await;
+
+    if let Some(listener_id) = progress_listener {
+        app_handle.unlisten(listener_id);
+    }
+
+    match update_result {
         Ok(results) => {
             let successes = results.iter().filter(|r| r.success).count();
             log::info!(
@@ -125,29 +328,9 @@ async fn run_auto_update(app_handle: &tauri::AppHandle, run_started_at: u64) {
                 results.len()
             );
 
-            // Notify UI of bucket results only if not silent update.
+            // Per-bucket lines were already streamed; only report completion here.
             if !silent_update_enabled {
                 if let Some(window) = app_handle.get_webview_window("main") {
-                    for result in &results {
-                        let line = if result.success {
-                            format!("✓ Updated bucket: {}", result.bucket_name)
-                        } else {
-                            format!(
-                                "✗ Failed to update {}: {}",
-                                result.bucket_name, result.message
-                            )
-                        };
-
-                        let _ = window.emit(
-                            "operation-output",
-                            serde_json::json!({
-                                "line": line.clone(),
-                                "source": if result.success { "stdout" } else { "stderr" },
-                                "operationId": operation_id
-                            }),
-                        );
-                    }
-
                     let _ = window.emit("operation-finished", serde_json::json!({
                         "success": successes == results.len(),
                         "message": format!("Bucket update completed: {} of {} succeeded", successes, results.len()),
@@ -164,10 +347,33 @@ async fn run_auto_update(app_handle: &tauri::AppHandle, run_started_at: u64) {
                 serde_json::json!(run_started_at),
             );
 
-            if auto_update_packages {
-                update_packages_after_buckets(app_handle, silent_update_enabled).await;
-            } else if successes > 0 {
-                emit_installed_packages_changed(app_handle, "bucket-update", None);
+            let updated_buckets = results
+                .iter()
+                .filter(|r| r.success && r.message == "BUCKET_UPDATE_SUCCESS")
+                .count();
+            let updated_packages = if auto_update_packages {
+                update_packages_after_buckets(app_handle, silent_update_enabled).await
+            } else {
+                if successes > 0 {
+                    emit_installed_packages_changed(app_handle, "bucket-update", None);
+                }
+                0
+            };
+
+            let notify_enabled = crate::commands::settings::get_config_value(
+                app_handle.clone(),
+                "notifications.onAutoUpdate".to_string(),
+            )
+            .ok()
+            .flatten()
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+            if notify_enabled && (updated_buckets > 0 || updated_packages > 0) {
+                crate::tray::show_auto_update_notification(
+                    app_handle,
+                    updated_buckets,
+                    updated_packages,
+                );
             }
         }
         Err(e) => {
@@ -206,7 +412,11 @@ async fn run_auto_update(app_handle: &tauri::AppHandle, run_started_at: u64) {
     }
 }
 
-async fn update_packages_after_buckets(app_handle: &tauri::AppHandle, silent_update_enabled: bool) {
+/// Runs a headless `scoop update *` and returns how many packages were updated.
+async fn update_packages_after_buckets(
+    app_handle: &tauri::AppHandle,
+    silent_update_enabled: bool,
+) -> usize {
     log::info!("Starting auto package update after bucket refresh");
     let operation_id = format!(
         "scheduler-package-update-{}",
@@ -234,6 +444,11 @@ async fn update_packages_after_buckets(app_handle: &tauri::AppHandle, silent_upd
     let state = app_handle.state::<crate::state::AppState>();
     match crate::commands::update::update_all_packages_headless(app_handle.clone(), state).await {
         Ok(update_details) => {
+            let updated = update_details
+                .iter()
+                .filter(|line| line.trim_start().starts_with("Updating '"))
+                .count();
+
             // Notify UI of success only if not silent update
             if !silent_update_enabled {
                 if let Some(window) = app_handle.get_webview_window("main") {
@@ -264,6 +479,7 @@ async fn update_packages_after_buckets(app_handle: &tauri::AppHandle, silent_upd
                     );
                 }
             }
+            updated
         }
         Err(e) => {
             log::warn!("Auto package headless update failed: {}", e);
@@ -297,10 +513,26 @@ async fn update_packages_after_buckets(app_handle: &tauri::AppHandle, silent_upd
                     );
                 }
             }
+            0
         }
     }
 }
 
+/// Returns whether the scheduled update should be deferred because the user
+/// opted out of updating on metered connections and the current one is metered.
+async fn should_skip_on_metered(app_handle: &AppHandle) -> bool {
+    let skip_on_metered = crate::commands::settings::get_config_value(
+        app_handle.clone(),
+        "buckets.skipOnMetered".to_string(),
+    )
+    .ok()
+    .flatten()
+    .and_then(|v| v.as_bool())
+    .unwrap_or(false);
+
+    skip_on_metered && crate::commands::network::is_metered_connection().await
+}
+
 fn parse_update_interval(interval_raw: &str) -> Option<u64> {
     match interval_raw {
         "24h" | "1d" => Some(86400), // 24 hours
@@ -1,8 +1,8 @@
 use crate::models::ScoopPackage;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::RwLock as StdRwLock;
+use std::sync::{Mutex as StdMutex, RwLock as StdRwLock};
 use std::time::{SystemTime, UNIX_EPOCH};
 use tokio::sync::{Mutex, RwLock as AsyncRwLock};
 
@@ -25,20 +25,93 @@ pub struct LnkSourceIndexCache {
     pub index: HashMap<String, crate::commands::package_icon::ResolvedPackageIconSource>,
 }
 
+/// The outcome of the last update check, reused by lightweight callers such as the tray.
+#[derive(Clone, Debug)]
+pub struct UpdateCheckCache {
+    pub updatable_count: usize,
+    pub checked_at_ms: u64,
+}
+
+/// The results of the last doctor checkup, so the page can show them without re-running.
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct CheckupCache {
+    pub items: Vec<crate::commands::doctor::checkup::CheckupItem>,
+    /// When the checkup ran, in milliseconds since the Unix epoch.
+    pub ran_at: u64,
+}
+
+/// FIFO list of mutating operations; the entry at the front is the one allowed to run.
+#[derive(Default)]
+pub struct OperationQueue {
+    pending: StdMutex<VecDeque<String>>,
+}
+
+impl OperationQueue {
+    /// Appends an operation and returns how many operations are ahead of it.
+    pub fn enqueue(&self, operation_id: &str) -> usize {
+        let mut pending = self.pending.lock().unwrap();
+        pending.push_back(operation_id.to_string());
+        pending.len() - 1
+    }
+
+    /// Returns whether the operation is at the front of the queue.
+    pub fn is_next(&self, operation_id: &str) -> bool {
+        self.pending
+            .lock()
+            .unwrap()
+            .front()
+            .is_some_and(|id| id == operation_id)
+    }
+
+    /// Removes an operation from the queue, returning whether it was present.
+    pub fn remove(&self, operation_id: &str) -> bool {
+        let mut pending = self.pending.lock().unwrap();
+        match pending.iter().position(|id| id == operation_id) {
+            Some(index) => {
+                pending.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the queued operation ids in execution order.
+    pub fn snapshot(&self) -> Vec<String> {
+        self.pending.lock().unwrap().iter().cloned().collect()
+    }
+}
+
+/// The default global Scoop root, `%ProgramData%\scoop`.
+pub fn default_global_scoop_path() -> Option<PathBuf> {
+    std::env::var_os("ProgramData").map(|dir| PathBuf::from(dir).join("scoop"))
+}
+
 /// Shared application state managed by Tauri.
 pub struct AppState {
     /// The resolved path to the Scoop installation directory.
     scoop_path: StdRwLock<PathBuf>,
     /// Whether Scoop is properly configured
     scoop_configured: StdRwLock<bool>,
+    /// The root of global (`--global`) installs, scanned when enabled in settings.
+    global_scoop_path: StdRwLock<Option<PathBuf>>,
     /// A cache for the list of installed packages and their fingerprint.
     pub installed_packages: Mutex<Option<InstalledPackagesCache>>,
     /// A cache for package versions, invalidated when installed packages change
     pub package_versions: Mutex<Option<PackageVersionsCache>>,
     /// A cache for LNK shortcut index used for icon resolution
     pub lnk_source_index: AsyncRwLock<Option<LnkSourceIndexCache>>,
+    /// The result of the last update check, invalidated when installed packages change
+    pub update_check: Mutex<Option<UpdateCheckCache>>,
+    /// The last checkup results, invalidated when the Scoop path changes or an autofix runs
+    pub last_checkup: Mutex<Option<CheckupCache>>,
     /// Timestamp (ms) of the last installed packages refresh to prevent rapid consecutive calls
     last_refresh_time: AtomicU64,
+    /// Serializes installed package scans so concurrent callers share one scan
+    pub installed_scan_lock: Mutex<()>,
+    /// Incremented every time a full installed packages scan completes
+    installed_scan_generation: AtomicU64,
+    /// Serializes install, update, uninstall and bucket operations
+    pub operation_queue: OperationQueue,
 }
 
 impl AppState {
@@ -47,10 +120,16 @@ impl AppState {
         Self {
             scoop_path: StdRwLock::new(initial_scoop_path),
             scoop_configured: StdRwLock::new(configured),
+            global_scoop_path: StdRwLock::new(default_global_scoop_path()),
             installed_packages: Mutex::new(None),
             package_versions: Mutex::new(None),
             lnk_source_index: AsyncRwLock::new(None),
+            update_check: Mutex::new(None),
+            last_checkup: Mutex::new(None),
             last_refresh_time: AtomicU64::new(0),
+            installed_scan_lock: Mutex::new(()),
+            installed_scan_generation: AtomicU64::new(0),
+            operation_queue: OperationQueue::default(),
         }
     }
 
@@ -78,6 +157,35 @@ impl AppState {
             *self.installed_packages.lock().await = None;
             *self.package_versions.lock().await = None;
             *self.lnk_source_index.write().await = None;
+            *self.update_check.lock().await = None;
+            *self.last_checkup.lock().await = None;
+        }
+
+        changed
+    }
+
+    /// Returns the global Scoop root path, if one is known.
+    pub fn global_scoop_path(&self) -> Option<PathBuf> {
+        self.global_scoop_path.read().unwrap().clone()
+    }
+
+    /// Updates the global Scoop root path, clearing the installed packages cache
+    /// when it changes. Returns whether the path changed.
+    pub async fn set_global_scoop_path(&self, new_path: Option<PathBuf>) -> bool {
+        let changed = {
+            let mut current_path = self.global_scoop_path.write().unwrap();
+            if *current_path == new_path {
+                false
+            } else {
+                *current_path = new_path;
+                true
+            }
+        };
+
+        if changed {
+            *self.installed_packages.lock().await = None;
+            *self.package_versions.lock().await = None;
+            *self.update_check.lock().await = None;
         }
 
         changed
@@ -107,8 +215,8 @@ impl AppState {
         self.last_refresh_time.store(now, Ordering::Relaxed);
     }
 
-    /// Checks if a refresh should be debounced (less than 1 second since last refresh)
-    pub fn should_debounce_refresh(&self) -> bool {
+    /// Checks if a refresh should be debounced (less than `window_ms` since last refresh)
+    pub fn should_debounce_refresh(&self, window_ms: u64) -> bool {
         let now = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .map(|d| d.as_millis() as u64)
@@ -120,7 +228,18 @@ impl AppState {
             return false;
         }
 
-        now.saturating_sub(last_refresh) < 1000 // Debounce within 1 second
+        now.saturating_sub(last_refresh) < window_ms
+    }
+
+    /// Returns the number of installed package scans completed so far
+    pub fn installed_scan_generation(&self) -> u64 {
+        self.installed_scan_generation.load(Ordering::Acquire)
+    }
+
+    /// Records that an installed packages scan has completed
+    pub fn mark_installed_scan_completed(&self) {
+        self.installed_scan_generation
+            .fetch_add(1, Ordering::AcqRel);
     }
 
     pub fn now_ms() -> u64 {
@@ -133,10 +252,13 @@ impl AppState {
 
 #[cfg(test)]
 mod tests {
-    use super::{AppState, InstalledPackagesCache, LnkSourceIndexCache, PackageVersionsCache};
+    use super::{
+        AppState, InstalledPackagesCache, LnkSourceIndexCache, OperationQueue, PackageVersionsCache,
+    };
     use crate::models::ScoopPackage;
     use std::collections::HashMap;
     use std::path::PathBuf;
+    use std::sync::atomic::Ordering;
 
     #[tokio::test]
     async fn set_scoop_path_clears_path_dependent_caches_on_change() {
@@ -165,6 +287,45 @@ mod tests {
         assert!(state.lnk_source_index.read().await.is_some());
     }
 
+    #[test]
+    fn refresh_debounce_respects_configured_window() {
+        let state = AppState::new(PathBuf::from("C:\\scoop"), true);
+
+        // The first refresh is never debounced
+        assert!(!state.should_debounce_refresh(1000));
+
+        // A second call within the window reuses the cached data
+        state.update_refresh_time();
+        assert!(state.should_debounce_refresh(1000));
+        assert!(!state.should_debounce_refresh(0));
+
+        // Once the window has passed the next call rescans
+        state
+            .last_refresh_time
+            .store(AppState::now_ms() - 1500, Ordering::Relaxed);
+        assert!(!state.should_debounce_refresh(1000));
+        assert!(state.should_debounce_refresh(2000));
+    }
+
+    #[test]
+    fn operation_queue_runs_in_fifo_order() {
+        let queue = OperationQueue::default();
+
+        assert_eq!(queue.enqueue("first"), 0);
+        assert_eq!(queue.enqueue("second"), 1);
+        assert_eq!(queue.enqueue("third"), 2);
+        assert!(queue.is_next("first"));
+        assert!(!queue.is_next("second"));
+
+        // Cancelling a queued item must not disturb the running one
+        assert!(queue.remove("second"));
+        assert_eq!(queue.snapshot(), vec!["first", "third"]);
+
+        assert!(queue.remove("first"));
+        assert!(queue.is_next("third"));
+        assert!(!queue.remove("first"));
+    }
+
     async fn seed_path_caches(state: &AppState) {
         *state.installed_packages.lock().await = Some(InstalledPackagesCache {
             packages: vec![ScoopPackage {
@@ -6,10 +6,18 @@ use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use tauri::{
     tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent},
-    Manager,
+    Emitter, Manager,
 };
 use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
 
+/// Emitted when the tray's "Search…" item is clicked so the frontend can focus the search box.
+pub const EVENT_TRAY_QUICK_SEARCH: &str = "tray-quick-search";
+
+/// Delay before the first tray update count check, leaving room for cold start.
+const TRAY_UPDATE_COUNT_INITIAL_DELAY_SECS: u64 = 60;
+/// How often the tray tooltip's update count is refreshed.
+const TRAY_UPDATE_COUNT_INTERVAL_SECS: u64 = 5 * 60;
+
 pub fn setup_system_tray(app: &tauri::AppHandle) -> tauri::Result<()> {
     // Create a shared map to store app shortcuts for menu events
     let shortcuts_map: Arc<Mutex<HashMap<String, ScoopAppShortcut>>> =
@@ -61,6 +69,16 @@ pub fn setup_system_tray(app: &tauri::AppHandle) -> tauri::Result<()> {
                         let _ = window.hide();
                     }
                 }
+                "quickSearch" => {
+                    if let Some(window) = app.get_webview_window("main") {
+                        let _ = window.show();
+                        let _ = window.unminimize();
+                        let _ = window.set_focus();
+                    }
+                    if let Err(e) = app.emit(EVENT_TRAY_QUICK_SEARCH, ()) {
+                        log::warn!("Failed to emit {} event: {}", EVENT_TRAY_QUICK_SEARCH, e);
+                    }
+                }
                 "refreshApps" => {
                     // Refresh the tray menu
                     let app_handle = app.clone();
@@ -93,9 +111,64 @@ pub fn setup_system_tray(app: &tauri::AppHandle) -> tauri::Result<()> {
         })
         .build(app)?;
 
+    spawn_tray_update_count_refresher(app.clone());
+
     Ok(())
 }
 
+/// Periodically refreshes the update count shown in the tray tooltip.
+fn spawn_tray_update_count_refresher(app: tauri::AppHandle<tauri::Wry>) {
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(std::time::Duration::from_secs(
+            TRAY_UPDATE_COUNT_INITIAL_DELAY_SECS,
+        ))
+        .await;
+
+        loop {
+            refresh_tray_update_count(&app).await;
+            tokio::time::sleep(std::time::Duration::from_secs(
+                TRAY_UPDATE_COUNT_INTERVAL_SECS,
+            ))
+            .await;
+        }
+    });
+}
+
+/// Shows the number of available updates in the tray tooltip.
+async fn refresh_tray_update_count(app: &tauri::AppHandle<tauri::Wry>) {
+    let state = app.state::<AppState>();
+    if !state.is_scoop_configured() {
+        return;
+    }
+
+    let count = match crate::commands::updates::get_update_count(app.clone(), state).await {
+        Ok(count) => count,
+        Err(e) => {
+            log::debug!("Failed to get update count for tray: {}", e);
+            return;
+        }
+    };
+
+    let tooltip = if count == 0 {
+        "Pailer".to_string()
+    } else {
+        let language = fetch_current_language(app);
+        let strings = crate::i18n::get_tray_locale_strings(&language)
+            .unwrap_or_else(|_| crate::i18n::default_tray_strings());
+        strings
+            .get("updatesAvailable")
+            .and_then(|v| v.as_str())
+            .unwrap_or("Pailer - {{count}} updates available")
+            .replace("{{count}}", &count.to_string())
+    };
+
+    if let Some(tray) = app.tray_by_id("main") {
+        if let Err(e) = tray.set_tooltip(Some(tooltip)) {
+            log::warn!("Failed to update tray tooltip: {}", e);
+        }
+    }
+}
+
 fn fetch_current_language(app: &tauri::AppHandle<tauri::Wry>) -> String {
     // First try to get the language from settings
     if let Some(Some(lang_value)) =
@@ -187,6 +260,10 @@ fn build_tray_menu_with_language(
         .get("hide")
         .and_then(|v| v.as_str())
         .unwrap_or("Hide Pailer");
+    let search_text = menu_strings
+        .get("search")
+        .and_then(|v| v.as_str())
+        .unwrap_or("Search…");
     let refresh_apps_text = menu_strings
         .get("refreshApps")
         .and_then(|v| v.as_str())
@@ -203,12 +280,15 @@ fn build_tray_menu_with_language(
     // Basic menu items
     let show = tauri::menu::MenuItemBuilder::with_id("show", show_text).build(app)?;
     let hide = tauri::menu::MenuItemBuilder::with_id("hide", hide_text).build(app)?;
+    let quick_search =
+        tauri::menu::MenuItemBuilder::with_id("quickSearch", search_text).build(app)?;
     let refresh_apps =
         tauri::menu::MenuItemBuilder::with_id("refreshApps", refresh_apps_text).build(app)?;
 
     let mut menu_items: Vec<Box<dyn tauri::menu::IsMenuItem<tauri::Wry>>> = Vec::new();
     menu_items.push(Box::new(show));
     menu_items.push(Box::new(hide));
+    menu_items.push(Box::new(quick_search));
     let shortcuts_result = if let Some(app_state) = app.try_state::<AppState>() {
         if !app_state.is_scoop_configured() {
             Ok(Vec::new())
@@ -244,24 +324,16 @@ fn build_tray_menu_with_language(
                 .and_then(|v| v.as_array().cloned())
                 .unwrap_or_default();
 
-                // Convert configured apps to a HashSet for fast lookup
-                let configured_app_names: std::collections::HashSet<String> = configured_apps
+                // Keep the configured order; duplicates only show once
+                let mut seen_names = std::collections::HashSet::new();
+                let configured_app_names: Vec<String> = configured_apps
                     .iter()
                     .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                    .filter(|name| seen_names.insert(name.to_lowercase()))
                     .collect();
 
-                // Filter shortcuts based on configuration
                 // If no apps configured, show none (user can add them in settings)
-                let filtered_shortcuts: Vec<_> = if configured_app_names.is_empty() {
-                    Vec::new() // Show no apps by default
-                } else {
-                    shortcuts
-                        .into_iter()
-                        .filter(|shortcut| configured_app_names.contains(&shortcut.name))
-                        .collect()
-                };
-
-                if !filtered_shortcuts.is_empty() {
+                if !configured_app_names.is_empty() {
                     // Add separator before apps
                     let separator = tauri::menu::PredefinedMenuItem::separator(app)?;
                     menu_items.push(Box::new(separator));
@@ -275,14 +347,30 @@ fn build_tray_menu_with_language(
 
                     // Build new shortcuts map first, then replace atomically
                     let mut new_shortcuts_map = HashMap::new();
-                    for shortcut in filtered_shortcuts {
-                        let menu_id = format!("app_{}", shortcut.name);
-                        new_shortcuts_map.insert(menu_id.clone(), shortcut.clone());
-
-                        let menu_item =
-                            tauri::menu::MenuItemBuilder::with_id(&menu_id, &shortcut.display_name)
+                    for name in configured_app_names {
+                        let menu_id = format!("app_{}", name);
+                        match resolve_tray_app(app, &shortcuts, &name) {
+                            Some(shortcut) => {
+                                let menu_item = tauri::menu::MenuItemBuilder::with_id(
+                                    &menu_id,
+                                    &shortcut.display_name,
+                                )
                                 .build(app)?;
-                        menu_items.push(Box::new(menu_item));
+                                new_shortcuts_map.insert(menu_id, shortcut);
+                                menu_items.push(Box::new(menu_item));
+                            }
+                            None => {
+                                // No longer installed: keep its slot but gray it out
+                                log::debug!("Tray app '{}' is not installed", name);
+                                let menu_item = tauri::menu::MenuItemBuilder::with_id(
+                                    &menu_id,
+                                    name.replace('_', " "),
+                                )
+                                .enabled(false)
+                                .build(app)?;
+                                menu_items.push(Box::new(menu_item));
+                            }
+                        }
                     }
 
                     // Replace the old map atomically with error handling
@@ -320,6 +408,41 @@ fn build_tray_menu_with_language(
     menu_builder.build()
 }
 
+/// Resolves how to launch a configured tray app.
+///
+/// A Start Menu shortcut with the same name is preferred; otherwise an installed package
+/// of that name is launched through its primary shim. Returns `None` when neither exists.
+fn resolve_tray_app(
+    app: &tauri::AppHandle<tauri::Wry>,
+    shortcuts: &[ScoopAppShortcut],
+    name: &str,
+) -> Option<ScoopAppShortcut> {
+    if let Some(shortcut) = shortcuts.iter().find(|shortcut| shortcut.name == name) {
+        return Some(shortcut.clone());
+    }
+
+    let scoop_path = app.try_state::<AppState>()?.scoop_path();
+    if !scoop_path.join("apps").join(name).join("current").is_dir() {
+        return None;
+    }
+
+    let target =
+        crate::commands::doctor::shim::find_primary_shim_target(scoop_path.as_path(), name)?;
+    let working_directory = target
+        .parent()
+        .map(|dir| dir.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    Some(ScoopAppShortcut {
+        name: name.to_string(),
+        display_name: name.to_string(),
+        shortcut_path: std::path::PathBuf::new(),
+        target_path: target.to_string_lossy().to_string(),
+        working_directory,
+        icon_path: None,
+    })
+}
+
 /// Refresh the tray menu with updated Scoop apps
 pub async fn refresh_tray_menu(app: &tauri::AppHandle<tauri::Wry>) -> Result<(), String> {
     log::info!("Refreshing tray menu...");
@@ -454,6 +577,65 @@ pub fn show_system_notification_blocking(app: &tauri::AppHandle) {
     }
 }
 
+/// Returns whether Windows would currently show a notification, i.e. no
+/// focus assist, full-screen app or presentation mode is active.
+#[cfg(windows)]
+fn system_accepts_notifications() -> bool {
+    use windows_sys::Win32::UI::Shell::{SHQueryUserNotificationState, QUNS_ACCEPTS_NOTIFICATIONS};
+
+    let mut state = 0;
+    let hr = unsafe { SHQueryUserNotificationState(&mut state) };
+    hr < 0 || state == QUNS_ACCEPTS_NOTIFICATIONS
+}
+
+#[cfg(not(windows))]
+fn system_accepts_notifications() -> bool {
+    true
+}
+
+/// Shows a non-blocking notification summarizing a background update run.
+///
+/// Skipped while the main window is focused, since the operation output is
+/// already visible there, and while the system is suppressing notifications.
+pub fn show_auto_update_notification(
+    app: &tauri::AppHandle,
+    updated_buckets: usize,
+    updated_packages: usize,
+) {
+    let focused = app
+        .get_webview_window("main")
+        .and_then(|window| window.is_focused().ok())
+        .unwrap_or(false);
+    if focused {
+        log::debug!("Skipping auto-update notification: main window is focused");
+        return;
+    }
+    if !system_accepts_notifications() {
+        log::debug!("Skipping auto-update notification: system notifications are suppressed");
+        return;
+    }
+
+    let language = fetch_current_language(app);
+    let strings = crate::i18n::get_tray_locale_strings(&language)
+        .unwrap_or_else(|_| crate::i18n::default_tray_strings());
+    let title = strings
+        .get("autoUpdateCompleteTitle")
+        .and_then(|v| v.as_str())
+        .unwrap_or("Pailer - Background update finished");
+    let message = strings
+        .get("autoUpdateCompleteMessage")
+        .and_then(|v| v.as_str())
+        .unwrap_or("Updated {{buckets}} buckets, {{packages}} packages.")
+        .replace("{{buckets}}", &updated_buckets.to_string())
+        .replace("{{packages}}", &updated_packages.to_string());
+
+    app.dialog()
+        .message(message)
+        .title(title)
+        .kind(MessageDialogKind::Info)
+        .show(|_| {});
+}
+
 #[tauri::command]
 pub async fn refresh_tray_apps_menu(app: tauri::AppHandle<tauri::Wry>) -> Result<(), String> {
     refresh_tray_menu(&app).await
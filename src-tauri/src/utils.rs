@@ -3,6 +3,7 @@ use crate::commands::settings;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use serde_json::Value;
+use std::cmp::Ordering;
 use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -62,6 +63,39 @@ pub fn is_scoop_installation() -> bool {
     }
 }
 
+/// Returns the Scoop app directory (`<root>/apps/<app>`) that contains `path`, if any.
+///
+/// Only `apps` folders whose parent looks like a Scoop root (has `shims` or `buckets`)
+/// count, so custom Scoop roots are recognized without relying on the word "scoop".
+pub fn scoop_app_dir_containing(path: &Path) -> Option<PathBuf> {
+    path.ancestors()
+        .find(|dir| {
+            dir.parent().is_some_and(|apps| {
+                apps.file_name()
+                    .is_some_and(|name| name.to_string_lossy().eq_ignore_ascii_case("apps"))
+                    && apps.parent().is_some_and(|root| {
+                        root.join("shims").is_dir() || root.join("buckets").is_dir()
+                    })
+            })
+        })
+        .map(Path::to_path_buf)
+}
+
+/// Checks if the running executable is managed by Scoop and must not be replaced in place.
+///
+/// Broader than `is_scoop_installation`: it also catches executables inside the `apps`
+/// directory of a Scoop root whose path doesn't mention Scoop or Pailer.
+pub fn is_scoop_managed_executable() -> bool {
+    if is_scoop_installation() {
+        return true;
+    }
+
+    env::current_exe()
+        .ok()
+        .and_then(|exe_path| scoop_app_dir_containing(&exe_path))
+        .is_some()
+}
+
 /// Returns the Scoop root saved in Pailer settings.
 pub fn configured_scoop_root<R: Runtime>(app: AppHandle<R>) -> Result<PathBuf, String> {
     log::info!("Reading configured Scoop root directory");
@@ -377,6 +411,201 @@ pub fn get_scoop_app_shortcuts_with_path(
     Ok(shortcuts)
 }
 
+/// Sums the sizes of all files below `path` in bytes.
+///
+/// Symlinks and junctions are not followed, so persisted data linked into an app
+/// directory is not counted against it. Unreadable entries are skipped.
+pub fn directory_size(path: &Path) -> u64 {
+    let Ok(entries) = fs::read_dir(path) else {
+        return 0;
+    };
+
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            let metadata = fs::symlink_metadata(entry.path()).ok()?;
+            if metadata.file_type().is_symlink() {
+                None
+            } else if metadata.is_dir() {
+                Some(directory_size(&entry.path()))
+            } else {
+                Some(metadata.len())
+            }
+        })
+        .sum()
+}
+
+/// A dot-separated version segment: leading number plus any trailing text (`"1a"`).
+fn parse_version_segment(segment: &str) -> Option<(u64, &str)> {
+    let digits_end = segment
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(segment.len());
+    let number = segment[..digits_end].parse().ok()?;
+    Some((number, &segment[digits_end..]))
+}
+
+/// Splits a version into numeric segments and an optional pre-release tag.
+///
+/// `.`, `_` and `-` all separate release segments (`2024-01-15`); the
+/// pre-release tag starts at the first `-` separated part that doesn't begin
+/// with a digit (`1.0-preview1`, `1.0.0-rc.1`). Returns `None` when the release
+/// part isn't made of numeric segments.
+fn parse_version(version: &str) -> Option<(Vec<(u64, &str)>, Option<&str>)> {
+    let version = version.split('+').next().unwrap_or(version).trim();
+    let version = version.strip_prefix(['v', 'V']).unwrap_or(version);
+    let release_end = version
+        .match_indices('-')
+        .map(|(index, _)| index)
+        .find(|&index| !version[index + 1..].starts_with(|c: char| c.is_ascii_digit()));
+    let (release, prerelease) = match release_end {
+        Some(index) => (&version[..index], Some(&version[index + 1..])),
+        None => (version, None),
+    };
+
+    let segments = release
+        .split(['.', '_', '-'])
+        .map(parse_version_segment)
+        .collect::<Option<Vec<_>>>()?;
+    Some((segments, prerelease))
+}
+
+/// A run of digits or of other characters within a pre-release tag.
+#[derive(PartialEq, Eq)]
+enum PrereleaseToken {
+    Number(u64),
+    Text(String),
+}
+
+impl PrereleaseToken {
+    /// alpha < beta < rc < other tags
+    fn rank(text: &str) -> u8 {
+        if text.starts_with("alpha") {
+            1
+        } else if text.starts_with("beta") {
+            2
+        } else if text.starts_with("rc") {
+            3
+        } else {
+            4
+        }
+    }
+}
+
+impl Ord for PrereleaseToken {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (PrereleaseToken::Number(a), PrereleaseToken::Number(b)) => a.cmp(b),
+            (PrereleaseToken::Number(_), PrereleaseToken::Text(_)) => Ordering::Less,
+            (PrereleaseToken::Text(_), PrereleaseToken::Number(_)) => Ordering::Greater,
+            (PrereleaseToken::Text(a), PrereleaseToken::Text(b)) => {
+                Self::rank(a).cmp(&Self::rank(b)).then_with(|| a.cmp(b))
+            }
+        }
+    }
+}
+
+impl PartialOrd for PrereleaseToken {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Splits a pre-release tag into digit and text runs (`"beta2"` -> `beta`, `2`),
+/// ignoring separators.
+fn tokenize_prerelease(prerelease: &str) -> Vec<PrereleaseToken> {
+    let mut tokens = Vec::new();
+    for part in prerelease
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+    {
+        let mut rest = part;
+        while !rest.is_empty() {
+            let is_digit = rest.starts_with(|c: char| c.is_ascii_digit());
+            let run_end = rest
+                .find(|c: char| c.is_ascii_digit() != is_digit)
+                .unwrap_or(rest.len());
+            let run = &rest[..run_end];
+            tokens.push(match run.parse() {
+                Ok(number) if is_digit => PrereleaseToken::Number(number),
+                _ => PrereleaseToken::Text(run.to_string()),
+            });
+            rest = &rest[run_end..];
+        }
+    }
+    tokens
+}
+
+/// Compares pre-release tags token by token; a tag that is a prefix of the
+/// other sorts first (`beta < beta2`). Tags that only differ in separators or
+/// case are ordered lexically so they never compare equal.
+fn compare_prerelease(a: &str, b: &str) -> Ordering {
+    tokenize_prerelease(a)
+        .cmp(&tokenize_prerelease(b))
+        .then_with(|| a.cmp(b))
+}
+
+/// Compares two Scoop-style version strings.
+///
+/// Numeric segments compare as numbers (`1.10 > 1.9`, `2024-01 < 2024-02`), a
+/// pre-release suffix after `-` sorts before the release (`1.0.0-rc.1 < 1.0.0`)
+/// and compares numerically too (`preview1 < preview2`). Build metadata after
+/// `+`, a `v` prefix and trailing zero segments are ignored. Versions that
+/// don't parse, such as `nightly`, fall back to a lexical comparison.
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    let (Some((a_segments, a_pre)), Some((b_segments, b_pre))) =
+        (parse_version(a), parse_version(b))
+    else {
+        return a.cmp(b);
+    };
+
+    for i in 0..a_segments.len().max(b_segments.len()) {
+        let a_segment = a_segments.get(i).copied().unwrap_or((0, ""));
+        let b_segment = b_segments.get(i).copied().unwrap_or((0, ""));
+        match a_segment.cmp(&b_segment) {
+            Ordering::Equal => continue,
+            ordering => return ordering,
+        }
+    }
+
+    match (a_pre, b_pre) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a_pre), Some(b_pre)) => compare_prerelease(a_pre, b_pre),
+    }
+}
+
+/// Hashes a file with the given digest and returns the lowercase hex string.
+pub fn file_digest_hex<D: sha2::Digest>(path: &Path) -> Result<String, String> {
+    use std::io::Read;
+
+    let file =
+        fs::File::open(path).map_err(|e| format!("Failed to open file for hashing: {}", e))?;
+
+    let mut reader = std::io::BufReader::new(file);
+    let mut hasher = D::new();
+    let mut buffer = [0; 8192];
+
+    loop {
+        let bytes_read = reader
+            .read(&mut buffer)
+            .map_err(|e| format!("Failed to read file for hashing: {}", e))?;
+
+        if bytes_read == 0 {
+            break;
+        }
+
+        hasher.update(&buffer[..bytes_read]);
+    }
+
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect())
+}
+
 /// Check if a path is a valid Scoop candidate (has apps or buckets directory)
 pub fn is_valid_scoop_candidate(path: &PathBuf) -> bool {
     if !path.exists() || !path.is_dir() {
@@ -427,6 +656,15 @@ fn get_cached_scoop_app_shortcuts(shortcuts_dir: &Path) -> Option<Vec<ScoopAppSh
     None
 }
 
+/// Forgets the cached Scoop Apps shortcuts so the next lookup rescans them.
+/// Returns the number of shortcuts dropped.
+pub fn clear_scoop_app_shortcuts_cache() -> usize {
+    SCOOP_APP_SHORTCUTS_CACHE
+        .get()
+        .and_then(|cache| cache.lock().ok()?.take())
+        .map_or(0, |entry| entry.shortcuts.len())
+}
+
 fn update_scoop_app_shortcuts_cache(shortcuts_dir: &Path, shortcuts: &[ScoopAppShortcut]) {
     let cache = SCOOP_APP_SHORTCUTS_CACHE.get_or_init(|| Mutex::new(None));
     if let Ok(mut cache_guard) = cache.lock() {
@@ -741,8 +979,9 @@ pub async fn execute_custom_command(
         operation_name,
         powershell::EVENT_OUTPUT,
         powershell::EVENT_FINISHED,
-        powershell::EVENT_CANCEL,
         operation_id,
+        powershell::StreamOptions::default(),
     )
     .await
+    .map_err(String::from)
 }
@@ -1,4 +1,5 @@
-use super::{get_installed_package_bucket, locate_current_install_dir};
+use super::{compare_versions, get_installed_package_bucket, locate_current_install_dir};
+use std::cmp::Ordering;
 use std::fs;
 use tempfile::tempdir;
 
@@ -41,3 +42,60 @@ fn falls_back_to_latest_version_dir_when_current_is_missing() {
         Some("spc".to_string())
     );
 }
+
+#[test]
+fn compares_numeric_segments_as_numbers() {
+    assert_eq!(compare_versions("1.10", "1.9"), Ordering::Greater);
+    assert_eq!(compare_versions("1.10.0", "1.9.0"), Ordering::Greater);
+    assert_eq!(compare_versions("1.0", "1.0.0"), Ordering::Equal);
+}
+
+#[test]
+fn prerelease_sorts_before_release() {
+    assert_eq!(compare_versions("1.0.0-rc.1", "1.0.0"), Ordering::Less);
+    assert_eq!(
+        compare_versions("1.0.0-beta.2", "1.0.0-rc.1"),
+        Ordering::Less
+    );
+    assert_eq!(
+        compare_versions("1.0.0-rc.10", "1.0.0-rc.9"),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn compares_suffixed_prereleases() {
+    assert_eq!(
+        compare_versions("1.0.0-beta", "1.0.0-beta2"),
+        Ordering::Less
+    );
+    assert_eq!(
+        compare_versions("1.0-preview1", "1.0-preview2"),
+        Ordering::Less
+    );
+    assert_eq!(
+        compare_versions("1.0-preview10", "1.0-preview9"),
+        Ordering::Greater
+    );
+    assert_eq!(compare_versions("1.0-preview2", "1.0"), Ordering::Less);
+}
+
+#[test]
+fn compares_date_style_versions() {
+    assert_eq!(compare_versions("2024.01", "2024.02"), Ordering::Less);
+    assert_eq!(
+        compare_versions("2024.12.01", "2024.02.15"),
+        Ordering::Greater
+    );
+    assert_eq!(compare_versions("2024-01-15", "2024-02-01"), Ordering::Less);
+    assert_eq!(
+        compare_versions("2024-12-01", "2024-02-15"),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn falls_back_to_lexical_for_unparseable_versions() {
+    assert_eq!(compare_versions("nightly", "nightly"), Ordering::Equal);
+    assert_eq!(compare_versions("nightly", "1.0"), "nightly".cmp("1.0"));
+}
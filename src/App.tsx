@@ -24,7 +24,7 @@ import { useBuckets } from './hooks/index';
 import { useOperations } from './stores/operations';
 import { t } from './i18n';
 import { updateStore } from './stores/updateStore';
-import { localStorageUtils } from './hooks/index';
+import { localStorageUtils, requestSearch } from './hooks/index';
 
 type InstalledPackagesChangedEvent = {
   reason: string;
@@ -32,6 +32,11 @@ type InstalledPackagesChangedEvent = {
   timestamp: number;
 };
 
+type PackageLink = {
+  name: string;
+  bucket: string | null;
+};
+
 function App() {
   const { settings } = settingsStore;
   let mainContentRef: HTMLElement | undefined;
@@ -224,6 +229,13 @@ function App() {
     focusMainContent();
   };
 
+  // An rscoop://install link opens the search page on the linked package
+  const openPackageLink = (link: PackageLink) => {
+    info(`Opening package link for ${link.name} (bucket: ${link.bucket ?? 'default'})`);
+    requestSearch(link.bucket ? `${link.name}/${link.bucket}` : link.name);
+    handleNavigate('search');
+  };
+
   onMount(async () => {
     console.log('🚀 [App] App mounted - UI loaded, starting async initialization');
     info('Application UI loaded, starting background initialization');
@@ -253,6 +265,20 @@ function App() {
       logError(`Failed to register auto-operation-start listener: ${e}`);
     }
 
+    try {
+      const unlisten = await listen('tray-quick-search', () => {
+        setView('search');
+        requestAnimationFrame(() => {
+          requestAnimationFrame(() => {
+            document.querySelector<HTMLInputElement>('input[data-search-input]')?.focus();
+          });
+        });
+      });
+      onCleanup(unlisten);
+    } catch (e) {
+      logError(`Failed to register tray-quick-search listener: ${e}`);
+    }
+
     try {
       const unlisten = await listen<InstalledPackagesChangedEvent>(
         'installed-packages-changed',
@@ -270,6 +296,21 @@ function App() {
       logError(`Failed to register installed-packages-changed listener: ${e}`);
     }
 
+    try {
+      const unlisten = await listen<PackageLink>('package-link-install', (event) => {
+        openPackageLink(event.payload);
+      });
+      onCleanup(unlisten);
+
+      // A link the app was launched with arrived before this listener existed
+      const pendingLink = await invoke<PackageLink | null>('take_pending_package_link');
+      if (pendingLink) {
+        openPackageLink(pendingLink);
+      }
+    } catch (e) {
+      logError(`Failed to set up package link handling: ${e}`);
+    }
+
     // Check if installed via Scoop
     try {
       const scoopInstalled = await invoke<boolean>('is_scoop_installation');
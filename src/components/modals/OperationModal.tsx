@@ -23,6 +23,7 @@ import {
 } from '../../hooks/ui/useOperationSelectors';
 import Modal from '../common/Modal';
 import { useScrollManager } from '../common/ScrollManager';
+import { getErrorMessage } from '../../utils/appError';
 
 const OPERATION_MODAL_ANIMATION_MS = 300;
 const RUNNING_PROCESS_DETECTED_TEXT = 'running process detected';
@@ -501,6 +502,7 @@ function OperationModal(props: OperationModalProps) {
         force: currentOp.forceUpdate || undefined,
         operationId: operationId(),
         skipPreUpdateRefresh: settingsStore.settings.scoop.skipPreUpdateRefresh,
+        global: currentOp.global,
       }).catch((error) => {
         const op = operation();
         if (op && op.status === OperationStatus.InProgress) {
@@ -511,8 +513,8 @@ function OperationModal(props: OperationModalProps) {
         addOperationOutput(operationId(), {
           operationId: operationId(),
           source: 'error',
-          line: `[Pailer] Failed to start retry update: ${String(error)}`,
-          message: String(error),
+          line: `[Pailer] Failed to start retry update: ${getErrorMessage(error)}`,
+          message: getErrorMessage(error),
         });
       });
     } catch (error) {
@@ -554,6 +556,7 @@ function OperationModal(props: OperationModalProps) {
         bucketName: currentOp.isScan ? undefined : currentOp.bucketName,
         forceUpdate: !currentOp.isScan && currentOp.forceUpdate === true,
         skipPreUpdateRefresh: settingsStore.settings.scoop.skipPreUpdateRefresh,
+        global: currentOp.isScan ? undefined : currentOp.global,
       });
     } catch (error) {
       // Only update if EVENT_FINISHED hasn't already moved the operation to a terminal state.
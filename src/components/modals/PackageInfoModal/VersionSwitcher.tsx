@@ -1,6 +1,7 @@
 import { For, Show, createSignal, createEffect, onCleanup } from 'solid-js';
 import { Trash2 } from 'lucide-solid';
 import { t } from '../../../i18n';
+import { formatBytes } from '../../../utils/format';
 import { useMultiConfirmAction } from '../../../hooks/ui/useConfirmAction';
 import type { ScoopPackage, VersionedPackageInfo } from '../../../types/scoop';
 
@@ -106,6 +107,9 @@ export default function VersionSwitcher(props: VersionSwitcherProps) {
                       <div class="flex items-center justify-between">
                         <div>
                           <div class="font-semibold">{version.version}</div>
+                          <div class="text-base-content/60 text-xs">
+                            {formatBytes(version.size_bytes)}
+                          </div>
                           <Show when={version.is_current}>
                             <div class="text-primary text-sm font-medium">
                               {t('packageInfo.current')}
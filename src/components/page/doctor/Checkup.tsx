@@ -12,6 +12,11 @@ export interface CheckupItem {
   suggestion_params: any;
 }
 
+export interface LastCheckup {
+  items: CheckupItem[];
+  ran_at: number;
+}
+
 interface CheckupProps {
   checkupResult: CheckupItem[];
   isLoading: boolean;
@@ -20,6 +25,14 @@ interface CheckupProps {
   onRerun: () => void;
   onInstallHelper: (helperId: string) => void;
   installingHelper: string | null;
+  lastCheckedAt?: number | null;
+}
+
+function formatLastChecked(ranAt: number): string {
+  const minutes = Math.floor((Date.now() - ranAt) / 60000);
+  if (minutes < 1) return t('doctor.checkup.lastCheckedJustNow');
+  if (minutes < 60) return t('doctor.checkup.lastCheckedMinutesAgo', { minutes });
+  return t('doctor.checkup.lastCheckedHoursAgo', { hours: Math.floor(minutes / 60) });
 }
 
 function Checkup(props: CheckupProps) {
@@ -48,6 +61,19 @@ function Checkup(props: CheckupProps) {
         </div>
       </Show>
 
+      <Show when={!props.error && hasResults() && props.lastCheckedAt}>
+        <div class="text-base-content/60 mb-3 flex items-center gap-2 text-xs">
+          <span>{formatLastChecked(props.lastCheckedAt!)}</span>
+          <button
+            class="link link-primary"
+            onClick={props.onRerun}
+            disabled={props.isLoading || !!props.isRetrying}
+          >
+            {t('doctor.checkup.refresh')}
+          </button>
+        </div>
+      </Show>
+
       <Show when={!props.error && hasResults()}>
         <ul class="space-y-3">
           <For each={props.checkupResult}>
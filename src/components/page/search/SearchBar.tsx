@@ -42,6 +42,7 @@ function SearchBar(props: SearchBarProps) {
 
         <input
           ref={searchInputRef}
+          data-search-input
           type="text"
           placeholder={t('search.bar.placeholder')}
           class="input bg-base-100 input-bordered relative w-full pr-16 pl-10"
@@ -5,6 +5,12 @@ import SettingsToggle from '../../common/SettingsToggle';
 import Card from '../../common/Card';
 import { t } from '../../../i18n';
 
+const SCHEDULE_OPTIONS = [
+  { label: 'settings.bucketAutoUpdate.off', value: 'off' },
+  { label: 'settings.bucketAutoUpdate.every24Hours', value: '24h' },
+  { label: 'settings.bucketAutoUpdate.everyWeek', value: '7d' },
+];
+
 function AutoCleanupSettings() {
   const { settings, setCleanupSettings } = settingsStore;
   const [localVersionCount, setLocalVersionCount] = createSignal(
@@ -54,14 +60,14 @@ function AutoCleanupSettings() {
                 <input
                   type="checkbox"
                   class="toggle toggle-primary"
-                  checked={settings.cleanup.cleanupOldVersions}
+                  checked={settings.cleanup.oldVersions}
                   onChange={async (e) =>
-                    await setCleanupSettings({ cleanupOldVersions: e.currentTarget.checked })
+                    await setCleanupSettings({ oldVersions: e.currentTarget.checked })
                   }
                 />
               </div>
 
-              <Show when={settings.cleanup.cleanupOldVersions}>
+              <Show when={settings.cleanup.oldVersions}>
                 <div class="mt-4">
                   <label for="preserveVersionCount" class="mb-2 block text-xs font-semibold">
                     {t('settings.autoCleanup.versionsToKeep', { count: localVersionCount() })}
@@ -91,12 +97,53 @@ function AutoCleanupSettings() {
               <input
                 type="checkbox"
                 class="toggle toggle-primary"
-                checked={settings.cleanup.cleanupCache}
+                checked={settings.cleanup.outdatedCache}
+                onChange={async (e) =>
+                  await setCleanupSettings({ outdatedCache: e.currentTarget.checked })
+                }
+              />
+            </div>
+
+            {/* Failed Downloads Section */}
+            <div class="flex items-start justify-between">
+              <div class="flex-1">
+                <h3 class="text-sm font-medium">
+                  {t('settings.autoCleanup.cleanFailedDownloads')}
+                </h3>
+                <p class="text-base-content/60 mt-1 text-xs">
+                  {t('settings.autoCleanup.cleanFailedDownloadsDescription')}
+                </p>
+              </div>
+              <input
+                type="checkbox"
+                class="toggle toggle-primary"
+                checked={settings.cleanup.failedDownloads}
                 onChange={async (e) =>
-                  await setCleanupSettings({ cleanupCache: e.currentTarget.checked })
+                  await setCleanupSettings({ failedDownloads: e.currentTarget.checked })
                 }
               />
             </div>
+
+            {/* Scheduled Cleanup Section */}
+            <div class="flex items-start justify-between gap-4">
+              <div class="flex-1">
+                <h3 class="text-sm font-medium">{t('settings.autoCleanup.scheduleInterval')}</h3>
+                <p class="text-base-content/60 mt-1 text-xs">
+                  {t('settings.autoCleanup.scheduleIntervalDescription')}
+                </p>
+              </div>
+              <select
+                class="select select-bordered select-sm w-40"
+                value={settings.cleanup.autoInterval}
+                onChange={async (e) =>
+                  await setCleanupSettings({ autoInterval: e.currentTarget.value })
+                }
+              >
+                {SCHEDULE_OPTIONS.map((option) => (
+                  <option value={option.value}>{t(option.label)}</option>
+                ))}
+              </select>
+            </div>
           </div>
         ),
       }}
@@ -26,7 +26,8 @@ const INTERVAL_OPTIONS: { label: string; value: string; description: string }[]
 ];
 
 export default function BucketAutoUpdateSettings() {
-  const { settings, setBucketSettings } = settingsStore;
+  const { settings, setBucketSettings, setNotificationSettings, setSchedulerSettings } =
+    settingsStore;
   const [loading, setLoading] = createSignal(false);
   const [saving, setSaving] = createSignal(false);
   const [error, setError] = createSignal<string | null>(null);
@@ -138,6 +139,48 @@ export default function BucketAutoUpdateSettings() {
                 />
               </label>
             </div>
+
+            <div class="mt-4 flex items-center justify-between">
+              <div class="flex flex-col">
+                <span class="text-sm font-medium">
+                  {t('settings.bucketAutoUpdate.lowPriority')}
+                </span>
+                <span class="text-base-content/60 text-[11px]">
+                  {t('settings.bucketAutoUpdate.lowPriorityDescription')}
+                </span>
+              </div>
+              <label class="label cursor-pointer">
+                <input
+                  type="checkbox"
+                  class="toggle toggle-primary"
+                  checked={settings.scheduler.lowPriority}
+                  onChange={async (e) =>
+                    await setSchedulerSettings({ lowPriority: e.currentTarget.checked })
+                  }
+                />
+              </label>
+            </div>
+
+            <div class="mt-4 flex items-center justify-between">
+              <div class="flex flex-col">
+                <span class="text-sm font-medium">
+                  {t('settings.bucketAutoUpdate.notifyOnComplete')}
+                </span>
+                <span class="text-base-content/60 text-[11px]">
+                  {t('settings.bucketAutoUpdate.notifyOnCompleteDescription')}
+                </span>
+              </div>
+              <label class="label cursor-pointer">
+                <input
+                  type="checkbox"
+                  class="toggle toggle-primary"
+                  checked={settings.notifications.onAutoUpdate}
+                  onChange={async (e) =>
+                    await setNotificationSettings({ onAutoUpdate: e.currentTarget.checked })
+                  }
+                />
+              </label>
+            </div>
           </>
         ),
       }}
@@ -5,7 +5,7 @@ import Card from '../../common/Card';
 import { t } from '../../../i18n';
 
 function DebugSettings() {
-  const { settings, setDebugSettings } = settingsStore;
+  const { settings, setDebugSettings, setScanSettings } = settingsStore;
 
   return (
     <Card
@@ -19,7 +19,26 @@ function DebugSettings() {
           showStatusLabel={true}
         />
       }
-    />
+    >
+      <div class="flex items-center justify-between">
+        <div class="flex flex-col">
+          <span class="text-sm font-medium">{t('settings.debug.strictCurrent')}</span>
+          <span class="text-base-content/60 text-[11px]">
+            {t('settings.debug.strictCurrentDescription')}
+          </span>
+        </div>
+        <label class="label cursor-pointer">
+          <input
+            type="checkbox"
+            class="toggle toggle-primary"
+            checked={settings.scan.strictCurrent}
+            onChange={async (e) =>
+              await setScanSettings({ strictCurrent: e.currentTarget.checked })
+            }
+          />
+        </label>
+      </div>
+    </Card>
   );
 }
 
@@ -0,0 +1,60 @@
+import { createSignal, onMount } from 'solid-js';
+import { invoke } from '@tauri-apps/api/core';
+import { KeyRound, Save } from 'lucide-solid';
+import Card from '../../common/Card';
+import { t } from '../../../i18n';
+import { toast } from '../../common/ToastAlert';
+
+export default function GitHubTokenSettings() {
+  const [token, setToken] = createSignal('');
+  const [isLoading, setIsLoading] = createSignal(true);
+
+  onMount(async () => {
+    try {
+      const stored = await invoke<string | null>('get_github_token');
+      setToken(stored ?? '');
+    } catch (err) {
+      console.error('Failed to fetch GitHub token:', err);
+      toast.error(t('settings.githubToken.loadError'));
+    } finally {
+      setIsLoading(false);
+    }
+  });
+
+  const handleSave = async () => {
+    try {
+      await invoke('set_github_token', { token: token().trim() });
+      toast.success(t('settings.githubToken.saveSuccess'));
+    } catch (err) {
+      console.error('Failed to save GitHub token:', err);
+      toast.error(t('settings.githubToken.saveError'));
+    }
+  };
+
+  return (
+    <Card
+      title={t('settings.githubToken.title')}
+      icon={KeyRound}
+      description={t('settings.githubToken.description')}
+    >
+      <div class="form-control w-full">
+        <div class="join w-full">
+          <input
+            type="password"
+            placeholder={
+              isLoading() ? t('status.loading') : t('settings.githubToken.placeholder')
+            }
+            class="input input-bordered join-item bg-base-100 w-full"
+            value={token()}
+            onInput={(e) => setToken(e.currentTarget.value)}
+            disabled={isLoading()}
+          />
+          <button class="btn btn-info join-item" onClick={handleSave} disabled={isLoading()}>
+            <Save class="mr-1 h-4 w-4" />
+            {t('buttons.save')}
+          </button>
+        </div>
+      </div>
+    </Card>
+  );
+}
@@ -39,7 +39,16 @@ export default function HeldPackagesManagement(props: HeldPackagesManagementProp
               <For each={heldPackagesStore.packages}>
                 {(pkgName) => (
                   <li class="bg-base-200 hover:bg-base-300/80 flex items-center justify-between rounded-lg p-2 transition-colors">
-                    <span class="font-mono text-sm">{pkgName}</span>
+                    <span class="font-mono text-sm">
+                      {pkgName}
+                      <Show when={heldPackagesStore.bucketHolds[pkgName]}>
+                        {(bucket) => (
+                          <span class="badge badge-ghost badge-sm ml-2 font-sans">
+                            {t('settings.heldPackages.heldByBucket', { bucket: bucket() })}
+                          </span>
+                        )}
+                      </Show>
+                    </span>
                     <button
                       class="btn btn-xs btn-soft"
                       onClick={() => props.onUnhold(pkgName)}
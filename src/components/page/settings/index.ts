@@ -2,6 +2,7 @@ export { default as ScoopConfiguration } from './ScoopConfiguration';
 export { default as StartupSettings } from './StartupSettings';
 export { default as HotkeySettings } from './HotkeySettings';
 export { default as VirusTotalSettings } from './VirusTotalSettings';
+export { default as GitHubTokenSettings } from './GitHubTokenSettings';
 export { default as HeldPackagesManagement } from './HeldPackagesManagement';
 export { default as AboutSection } from './AboutSection';
 export { default as DebugSettings } from './DebugSettings';
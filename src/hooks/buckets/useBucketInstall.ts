@@ -1,5 +1,6 @@
 import { createSignal } from 'solid-js';
 import { invoke } from '@tauri-apps/api/core';
+import { getErrorMessage } from '../../utils/appError';
 
 export interface BucketInstallOptions {
   name: string;
@@ -85,7 +86,7 @@ export function useBucketInstall() {
 
       return result;
     } catch (error) {
-      const errorMsg = error instanceof Error ? error.message : 'Validation failed';
+      const errorMsg = getErrorMessage(error, 'Validation failed');
       setState((prev) => ({
         ...prev,
         isValidating: false,
@@ -123,7 +124,7 @@ export function useBucketInstall() {
 
       return result;
     } catch (error) {
-      const errorMsg = error instanceof Error ? error.message : 'Installation failed';
+      const errorMsg = getErrorMessage(error, 'Installation failed');
       console.error(`❌ Installation failed for ${bucketName}:`, errorMsg);
       setState((prev) => ({
         ...prev,
@@ -161,7 +162,7 @@ export function useBucketInstall() {
 
       return result;
     } catch (error) {
-      const errorMsg = error instanceof Error ? error.message : 'Update failed';
+      const errorMsg = getErrorMessage(error, 'Update failed');
       console.error(`❌ Update failed for ${bucketName}:`, errorMsg);
       setState((prev) => ({
         ...prev,
@@ -199,7 +200,7 @@ export function useBucketInstall() {
 
       return result;
     } catch (error) {
-      const errorMsg = error instanceof Error ? error.message : 'Removal failed';
+      const errorMsg = getErrorMessage(error, 'Removal failed');
       console.error(`❌ Removal failed for ${bucketName}:`, errorMsg);
       setState((prev) => ({
         ...prev,
@@ -15,6 +15,11 @@ export interface SearchableBucket {
   is_verified: boolean;
 }
 
+export interface DefaultBucket extends SearchableBucket {
+  installed: boolean;
+  local_path: string | null;
+}
+
 export interface BucketSearchRequest {
   query?: string;
   include_expanded: boolean;
@@ -227,7 +232,7 @@ export function useBucketSearch(): UseBucketSearchReturn {
   const [defaultBuckets] = createResource(async () => {
     try {
       await fetchCacheInfo();
-      const buckets = await invoke<SearchableBucket[]>('get_default_buckets');
+      const buckets = await invoke<DefaultBucket[]>('get_default_buckets');
       setSearchResults(buckets);
       setTotalCount(buckets.length);
       setIsExpandedSearch(false);
@@ -427,7 +432,7 @@ export function useBucketSearch(): UseBucketSearchReturn {
       if (includeExpanded()) {
         await searchBuckets(undefined, true, undefined, 'stars');
       } else {
-        const buckets = await invoke<SearchableBucket[]>('get_default_buckets');
+        const buckets = await invoke<DefaultBucket[]>('get_default_buckets');
         setSearchResults(buckets);
         setTotalCount(buckets.length);
         setIsExpandedSearch(false);
@@ -20,9 +20,11 @@ export interface BulkUpdateState {
 
 interface BulkUpdateProgressEvent {
   run_id: string;
+  index: number;
   current: number;
   total: number;
   bucket_name: string;
+  status: 'success' | 'error';
   result: BulkUpdateResult;
 }
 
@@ -28,7 +28,7 @@ export { usePackageIcons } from './packages/usePackageIcons';
 export { useVersionFetch } from './packages/useVersionFetch';
 
 // Search related
-export { useSearch } from './search/useSearch';
+export { useSearch, requestSearch } from './search/useSearch';
 export { useSearchCache, searchCacheManager, localStorageUtils } from './search/useSearchCache';
 export { parseSearchFormat } from './search/searchQuery';
 export type { ParsedSearch } from './search/searchQuery';
@@ -7,6 +7,7 @@ import { useOperations } from '../../stores/operations';
 import { t } from '../../i18n';
 import { toast } from '../../components/common/ToastAlert';
 import settingsStore from '../../stores/settings';
+import { getErrorMessage } from '../../utils/appError';
 
 interface UsePackageOperationsReturn {
   handleInstall: (pkg: ScoopPackage) => string | null;
@@ -116,7 +117,7 @@ const showErrorNotification = (message: string) => {
 };
 
 const markOperationStartFailed = (operationId: string, operationName: string, error: unknown) => {
-  const message = error instanceof Error ? error.message : String(error);
+  const message = getErrorMessage(error);
   addOperationOutput(operationId, {
     operationId,
     source: 'error',
@@ -234,6 +235,10 @@ const autoEndPackageProcesses = async (
   }
 };
 
+// Scope reported by the package list; undefined lets the backend work it out
+const isGlobalPackage = (pkg: ScoopPackage): boolean | undefined =>
+  pkg.scope === undefined ? undefined : pkg.scope === 'global';
+
 const runUpdatePackageOperation = (
   operationId: string,
   pkg: ScoopPackage,
@@ -251,6 +256,7 @@ const runUpdatePackageOperation = (
     force: force || undefined,
     operationId,
     skipPreUpdateRefresh,
+    global: isGlobalPackage(pkg),
   }).catch((err) => {
     console.error(force ? 'Force update invocation failed:' : 'Update invocation failed:', err);
     markOperationStartFailed(operationId, title, err);
@@ -298,8 +304,11 @@ const performInstall = (pkg: ScoopPackage): string => {
     invoke('install_package', {
       packageName: pkg.name,
       bucket: pkg.source,
-      operationId,
-      skipPreUpdateRefresh: settingsStore.settings.scoop.skipPreUpdateRefresh,
+      options: {
+        operationId,
+        skipPreUpdateRefresh: settingsStore.settings.scoop.skipPreUpdateRefresh,
+        checkDiskSpace: true,
+      },
     }).catch((err) => {
       console.error(`Installation invocation failed for ${pkg.name}:`, err);
       markOperationStartFailed(operationId, title, err);
@@ -334,6 +343,7 @@ const handleUninstall = (pkg: ScoopPackage): string => {
     operationType: OperationType.Uninstall,
     packageName: pkg.name,
     bucketName: pkg.source,
+    global: isGlobalPackage(pkg),
   } as Parameters<typeof addOperation>[0]);
 
   schedulePackageOperation(operationId, async () => {
@@ -350,6 +360,7 @@ const handleUninstall = (pkg: ScoopPackage): string => {
       packageName: pkg.name,
       bucket: pkg.source,
       operationId,
+      global: isGlobalPackage(pkg),
     }).catch((err) => {
       console.error(`Uninstallation invocation failed for ${pkg.name}:`, err);
       markOperationStartFailed(operationId, title, err);
@@ -375,6 +386,7 @@ const handleUpdate = async (pkg: ScoopPackage): Promise<string | null> => {
     operationType: OperationType.Update,
     packageName: pkg.name,
     bucketName: pkg.source,
+    global: isGlobalPackage(pkg),
   } as Parameters<typeof addOperation>[0]);
 
   schedulePackageOperation(operationId, async () => {
@@ -426,6 +438,7 @@ const handleForceUpdate = async (pkg: ScoopPackage): Promise<string | null> => {
     operationType: OperationType.Update,
     packageName: pkg.name,
     bucketName: pkg.source,
+    global: isGlobalPackage(pkg),
     forceUpdate: true,
   } as Parameters<typeof addOperation>[0]);
 
@@ -489,6 +502,7 @@ const handleUpdateAll = async (packages: ScoopPackage[]): Promise<string | null>
       operationType: OperationType.Update,
       packageName: pkg.name,
       bucketName: pkg.source,
+      global: isGlobalPackage(pkg),
       updateBatchId: batchId,
     } as Parameters<typeof addOperation>[0]);
   }
@@ -49,6 +49,14 @@ interface UseSearchReturn {
 let searchResultsCache: ScoopPackage[] | null = null;
 let currentSearchTermCache: string | null = null;
 
+// Term requested from outside the search page, e.g. by a package install link
+const [requestedSearchTerm, setRequestedSearchTerm] = createSignal<string | null>(null);
+
+/** Shows `term` in the search page, whether or not the page is mounted yet. */
+export function requestSearch(term: string) {
+  setRequestedSearchTerm(term);
+}
+
 export function useSearch(): UseSearchReturn {
   const { getCachedSearch, cacheSearch, clearCache } = useSearchCache();
 
@@ -78,6 +86,13 @@ export function useSearch(): UseSearchReturn {
   let currentCacheVersion: number = 0;
   let currentSearchController: AbortController | null = null;
 
+  createEffect(() => {
+    const requested = requestedSearchTerm();
+    if (requested === null) return;
+    setRequestedSearchTerm(null);
+    setSearchTerm(requested);
+  });
+
   // Sync search content to sessionStorage
   createEffect(() => {
     const term = searchTerm();
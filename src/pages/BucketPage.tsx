@@ -25,6 +25,7 @@ import { toast } from '../components/common/ToastAlert';
 import installedPackagesStore from '../stores/installedPackagesStore';
 import bucketBulkUpdateStore from '../stores/bucketBulkUpdateStore';
 import { useOperationFollowUp } from '../hooks/packages/useOperationFollowUp';
+import { getErrorMessage } from '../utils/appError';
 
 const UPDATE_RESULT_DISPLAY_DURATION = 2000;
 
@@ -481,7 +482,7 @@ function BucketPage() {
       if (!isBulkUpdateCancelling && shouldRefreshBuckets) {
         setUpdateResults((prev) => ({
           ...prev,
-          [bucketName]: `Failed to update: ${error instanceof Error ? error.message : String(error)}`,
+          [bucketName]: `Failed to update: ${getErrorMessage(error)}`,
         }));
 
         setUpdateResultStatuses((prev) => ({
@@ -509,7 +510,7 @@ function BucketPage() {
       // Return a proper BulkUpdateResult instead of throwing
       return {
         success: false,
-        message: error instanceof Error ? error.message : String(error),
+        message: getErrorMessage(error),
         bucket_name: bucketName,
       };
     } finally {
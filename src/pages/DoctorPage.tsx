@@ -1,7 +1,7 @@
-import { createSignal, createMemo, Show, onMount, onCleanup } from 'solid-js';
+import { createSignal, createMemo, createEffect, Show, onMount, onCleanup } from 'solid-js';
 import { TriangleAlert, RefreshCw } from 'lucide-solid';
 import { invoke } from '@tauri-apps/api/core';
-import Checkup, { CheckupItem } from '../components/page/doctor/Checkup';
+import Checkup, { CheckupItem, LastCheckup } from '../components/page/doctor/Checkup';
 import CacheManager from '../components/page/doctor/CacheManager';
 import VersionedAppsManager from '../components/page/doctor/VersionedAppsManager';
 import ShimManager from '../components/page/doctor/ShimManager';
@@ -17,10 +17,29 @@ function DoctorPage() {
   const [installingHelper, setInstallingHelper] = createSignal<string | null>(null);
   const [isGlobalRefreshing, setIsGlobalRefreshing] = createSignal(false);
 
+  const [lastCheckedAt, setLastCheckedAt] = createSignal<number | null>(null);
+
+  // The first load reuses the backend's last checkup; later refreshes always re-run it
+  let preferLastCheckup = true;
+
   // Use session cache for checkup data
-  const checkupCache = createSessionStorage('checkupData', () =>
-    invoke<CheckupItem[]>('run_scoop_checkup')
-  );
+  const checkupCache = createSessionStorage('checkupData', async () => {
+    if (preferLastCheckup) {
+      preferLastCheckup = false;
+      const lastCheckup = await invoke<LastCheckup | null>('get_last_checkup');
+      if (lastCheckup) {
+        return lastCheckup.items;
+      }
+    }
+    return invoke<CheckupItem[]>('run_scoop_checkup');
+  });
+
+  createEffect(() => {
+    if (!checkupCache.data()) return;
+    void invoke<LastCheckup | null>('get_last_checkup').then((lastCheckup) =>
+      setLastCheckedAt(lastCheckup?.ran_at ?? null)
+    );
+  });
 
   const forceRefreshCheckup = () => checkupCache.forceRefresh();
 
@@ -79,7 +98,7 @@ function DoctorPage() {
   const handleInstallHelper = async (helperId: string) => {
     setInstallingHelper(helperId);
     try {
-      await invoke('install_package', { packageName: helperId, bucket: '' });
+      await invoke<CheckupItem>('install_missing_helper', { name: helperId });
 
       // Manual refresh after helper installation
       await forceRefreshCheckup();
@@ -118,6 +137,7 @@ function DoctorPage() {
       onRerun={() => runCheckup(true)}
       onInstallHelper={handleInstallHelper}
       installingHelper={installingHelper()}
+      lastCheckedAt={lastCheckedAt()}
     />
   );
 
@@ -97,6 +97,7 @@ function SettingsPage(props: SettingsPageProps) {
             <Show when={activeTab() === 'security'}>
               <div class="space-y-6">
                 <SC.VirusTotalSettings />
+                <SC.GitHubTokenSettings />
               </div>
             </Show>
 
@@ -25,9 +25,11 @@ interface BucketLike {
 
 interface BulkUpdateProgressEvent {
   run_id: string;
+  index: number;
   current: number;
   total: number;
   bucket_name: string;
+  status: 'success' | 'error';
   result: BulkUpdateResult;
 }
 
@@ -1,14 +1,22 @@
 import { createRoot } from 'solid-js';
-import { createStore } from 'solid-js/store';
+import { createStore, reconcile } from 'solid-js/store';
 import { invoke } from '@tauri-apps/api/core';
 
+interface HeldPackage {
+  name: string;
+  bucket_hold: string | null;
+}
+
 function createHeldPackagesStore() {
   const [store, setStore] = createStore<{
     packages: string[];
+    // Package name -> frozen bucket that placed its hold
+    bucketHolds: Record<string, string>;
     isLoading: boolean;
     error: string | null;
   }>({
     packages: [],
+    bucketHolds: {},
     isLoading: true,
     error: null,
   });
@@ -16,8 +24,18 @@ function createHeldPackagesStore() {
   const fetchHeldPackages = async () => {
     setStore('isLoading', true);
     try {
-      const heldPackages = await invoke<string[]>('list_held_packages');
-      setStore('packages', heldPackages);
+      const heldPackages = await invoke<HeldPackage[]>('list_held_packages');
+      setStore('packages', heldPackages.map((pkg) => pkg.name));
+      setStore(
+        'bucketHolds',
+        reconcile(
+          Object.fromEntries(
+            heldPackages
+              .filter((pkg) => pkg.bucket_hold)
+              .map((pkg) => [pkg.name, pkg.bucket_hold as string])
+          )
+        )
+      );
       setStore('error', null);
     } catch (err) {
       const errorMsg = err instanceof Error ? err.message : String(err);
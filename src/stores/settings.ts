@@ -39,15 +39,19 @@ interface Settings {
   };
   cleanup: {
     autoCleanupEnabled: boolean;
-    cleanupOldVersions: boolean;
-    cleanupCache: boolean;
+    oldVersions: boolean;
+    outdatedCache: boolean;
+    failedDownloads: boolean;
     preserveVersionCount: number;
+    autoInterval: string; // "off" | "24h" | "7d", scheduled cleanup independent of operations
   };
   buckets: {
     autoUpdateInterval: string; // "off" | "1h" | "6h" | "24h"
     autoUpdatePackagesEnabled: boolean;
     silentUpdateEnabled: boolean;
     updateHistoryEnabled: boolean;
+    searchCacheTtlHours: number;
+    offline: boolean;
   };
   update: {
     channel: 'stable';
@@ -68,6 +72,8 @@ interface Settings {
     autoEndProcessMode: 'safe' | 'force';
     autoEndRunningProcesses: boolean;
     autoEndProcessPackageAllowlist: string[];
+    globalPath?: string; // unset uses %ProgramData%\scoop
+    includeGlobal: boolean;
   };
   search: {
     allowCachePrebuild: boolean;
@@ -76,6 +82,19 @@ interface Settings {
     autoTrayConfigMigration: boolean;
     preserveTrayEntriesForVersionedInstalls: boolean;
   };
+  performance: {
+    scanThreads?: number; // unset uses the global thread pool
+    refreshDebounceMs: number;
+  };
+  notifications: {
+    onAutoUpdate: boolean;
+  };
+  scan: {
+    strictCurrent: boolean;
+  };
+  scheduler: {
+    lowPriority: boolean;
+  };
 }
 
 const defaultSettings: Settings = {
@@ -95,15 +114,19 @@ const defaultSettings: Settings = {
   },
   cleanup: {
     autoCleanupEnabled: false,
-    cleanupOldVersions: true,
-    cleanupCache: true,
+    oldVersions: true,
+    outdatedCache: true,
+    failedDownloads: false,
     preserveVersionCount: 3,
+    autoInterval: 'off',
   },
   buckets: {
     autoUpdateInterval: 'off',
     autoUpdatePackagesEnabled: false,
     silentUpdateEnabled: false,
     updateHistoryEnabled: true,
+    searchCacheTtlHours: 24,
+    offline: false,
   },
   update: {
     channel: 'stable',
@@ -121,6 +144,7 @@ const defaultSettings: Settings = {
     autoEndProcessMode: 'safe',
     autoEndRunningProcesses: false,
     autoEndProcessPackageAllowlist: [],
+    includeGlobal: false,
   },
   search: {
     allowCachePrebuild: false,
@@ -129,8 +153,36 @@ const defaultSettings: Settings = {
     autoTrayConfigMigration: false,
     preserveTrayEntriesForVersionedInstalls: true,
   },
+  performance: {
+    refreshDebounceMs: 1000,
+  },
+  notifications: {
+    onAutoUpdate: false,
+  },
+  scan: {
+    strictCurrent: false,
+  },
+  scheduler: {
+    lowPriority: false,
+  },
 };
 
+/// Cleanup settings as stored before the per-category names were introduced
+type LegacyCleanupSettings = Partial<Settings['cleanup']> & {
+  cleanupOldVersions?: boolean;
+  cleanupCache?: boolean;
+};
+
+function migrateCleanupSettings(stored?: LegacyCleanupSettings): Settings['cleanup'] {
+  const { cleanupOldVersions, cleanupCache, ...rest } = stored ?? {};
+  return {
+    ...defaultSettings.cleanup,
+    ...rest,
+    oldVersions: rest.oldVersions ?? cleanupOldVersions ?? defaultSettings.cleanup.oldVersions,
+    outdatedCache: rest.outdatedCache ?? cleanupCache ?? defaultSettings.cleanup.outdatedCache,
+  };
+}
+
 function createSettingsStore() {
   // Initialize the Tauri store
   const initStore = async () => {
@@ -221,10 +273,7 @@ function createSettingsStore() {
               ...defaultSettings.debug,
               ...stored.debug,
             },
-            cleanup: {
-              ...defaultSettings.cleanup,
-              ...stored.cleanup,
-            },
+            cleanup: migrateCleanupSettings(stored.cleanup),
             buckets: {
               ...defaultSettings.buckets,
               ...stored.buckets,
@@ -258,6 +307,8 @@ function createSettingsStore() {
               autoEndProcessPackageAllowlist:
                 stored.scoop?.autoEndProcessPackageAllowlist ??
                 defaultSettings.scoop.autoEndProcessPackageAllowlist,
+              globalPath: stored.scoop?.globalPath,
+              includeGlobal: stored.scoop?.includeGlobal ?? defaultSettings.scoop.includeGlobal,
             },
             search: {
               ...defaultSettings.search,
@@ -267,6 +318,22 @@ function createSettingsStore() {
               ...defaultSettings.automation,
               ...stored.automation,
             },
+            performance: {
+              ...defaultSettings.performance,
+              ...stored.performance,
+            },
+            notifications: {
+              ...defaultSettings.notifications,
+              ...stored.notifications,
+            },
+            scan: {
+              ...defaultSettings.scan,
+              ...stored.scan,
+            },
+            scheduler: {
+              ...defaultSettings.scheduler,
+              ...stored.scheduler,
+            },
           };
         }
       } catch (error) {
@@ -434,6 +501,35 @@ function createSettingsStore() {
     });
   };
 
+  const setNotificationSettings = async (
+    newNotificationSettings: Partial<Settings['notifications']>
+  ) => {
+    await saveSettings({
+      notifications: {
+        ...settings.notifications,
+        ...newNotificationSettings,
+      },
+    });
+  };
+
+  const setScanSettings = async (newScanSettings: Partial<Settings['scan']>) => {
+    await saveSettings({
+      scan: {
+        ...settings.scan,
+        ...newScanSettings,
+      },
+    });
+  };
+
+  const setSchedulerSettings = async (newSchedulerSettings: Partial<Settings['scheduler']>) => {
+    await saveSettings({
+      scheduler: {
+        ...settings.scheduler,
+        ...newSchedulerSettings,
+      },
+    });
+  };
+
   const setCoreSettings = async (newCoreSettings: Partial<Settings>) => {
     await saveSettings(newCoreSettings);
   };
@@ -475,6 +571,9 @@ function createSettingsStore() {
     setScoopSettings,
     setSearchSettings,
     setAutomationSettings,
+    setNotificationSettings,
+    setScanSettings,
+    setSchedulerSettings,
     setCoreSettings,
   };
 }
@@ -234,6 +234,8 @@ export interface Dict {
       installing: string;
       issuesFound: string;
       items: {
+        executionPolicyAllowed: string;
+        executionPolicySuggestion: string;
         gitInstalled: string;
         gitSuggestion: string;
         helperInstalled: string;
@@ -243,11 +245,17 @@ export interface Dict {
         longPathsSuggestion: string;
         mainBucketInstalled: string;
         mainBucketSuggestion: string;
+        pathLength: string;
+        pathLengthSuggestion: string;
         scoopOnNtfs: string;
         scoopOnNtfsSuggestion: string;
         windowsDeveloperModeEnabled: string;
         windowsDeveloperModeSuggestion: string;
       };
+      lastCheckedHoursAgo: string;
+      lastCheckedJustNow: string;
+      lastCheckedMinutesAgo: string;
+      refresh: string;
       runCheckup: string;
       scrollToIssues: string;
       title: string;
@@ -649,11 +657,15 @@ export interface Dict {
       title: string;
     };
     autoCleanup: {
+      cleanFailedDownloads: string;
+      cleanFailedDownloadsDescription: string;
       cleanOldVersions: string;
       cleanOldVersionsDescription: string;
       cleanOutdatedCache: string;
       cleanOutdatedCacheDescription: string;
       description: string;
+      scheduleInterval: string;
+      scheduleIntervalDescription: string;
       title: string;
       versionsToKeep: string;
     };
@@ -690,11 +702,15 @@ export interface Dict {
       hours: string;
       hoursFormat: string;
       intervalTooShort: string;
+      lowPriority: string;
+      lowPriorityDescription: string;
       minimumInterval: string;
       minuteDisplay: string;
       minuteFormat: string;
       minutes: string;
       minutesFormat: string;
+      notifyOnComplete: string;
+      notifyOnCompleteDescription: string;
       off: string;
       offDescription: string;
       oneHourDisplay: string;
@@ -724,6 +740,8 @@ export interface Dict {
     };
     debug: {
       description: string;
+      strictCurrent: string;
+      strictCurrentDescription: string;
       title: string;
     };
     defaultLaunchPage: {
@@ -737,6 +755,7 @@ export interface Dict {
     };
     heldPackages: {
       description: string;
+      heldByBucket: string;
       noPackagesHeld: string;
       title: string;
       unhold: string;
@@ -807,6 +826,8 @@ export interface Dict {
     };
     title: string;
     tray: {
+      autoUpdateCompleteMessage: string;
+      autoUpdateCompleteTitle: string;
       closeAndDisable: string;
       hide: string;
       keepInTray: string;
@@ -815,7 +836,9 @@ export interface Dict {
       quit: string;
       refreshApps: string;
       scoopApps: string;
+      search: string;
       show: string;
+      updatesAvailable: string;
     };
     trayApps: {
       addToTray: string;
@@ -848,6 +871,14 @@ export interface Dict {
       saveSuccess: string;
       title: string;
     };
+    githubToken: {
+      description: string;
+      loadError: string;
+      placeholder: string;
+      saveError: string;
+      saveSuccess: string;
+      title: string;
+    };
     windowBehavior: {
       description: string;
       title: string;
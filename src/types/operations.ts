@@ -146,6 +146,8 @@ export interface PackageOperationState {
   bucketName?: string;
   updateBatchId?: string;
   forceUpdate?: boolean;
+  /** Whether the package is a global install; unknown when the package list had no scope. */
+  global?: boolean;
   nextStep?: OperationNextStep;
 }
 
@@ -9,11 +9,15 @@ export interface ScoopPackage {
   match_source: 'name' | 'binary' | 'none' | 'path';
   available_version?: string;
   local_latest_version?: string;
+  architecture?: string;
   installation_type: 'standard' | 'versioned' | 'custom';
   has_multiple_versions: boolean;
   homepage?: string;
   license?: string;
   notes?: string;
+  scope?: 'user' | 'global';
+  health?: 'ok' | 'missing_current';
+  user_note?: string;
 }
 
 export type VersionTypeFilter = 'all' | 'versioned' | 'held';
@@ -21,6 +25,9 @@ export type VersionTypeFilter = 'all' | 'versioned' | 'held';
 export interface ScoopInfo {
   details: [string, string][];
   notes: string | null;
+  homepage: string | null;
+  license: string | null;
+  binaries: string[];
 }
 
 export interface UpdatablePackage {
@@ -39,6 +46,7 @@ export interface PackageVersion {
   version: string;
   is_current: boolean;
   install_path: string;
+  size_bytes: number;
 }
 
 export interface VersionedPackageInfo {
@@ -47,4 +55,18 @@ export interface VersionedPackageInfo {
   available_versions: PackageVersion[];
 }
 
+export interface ManifestFieldChange {
+  field: string;
+  installed: unknown;
+  bucket: unknown;
+}
+
+export interface ManifestDiff {
+  name: string;
+  bucket: string;
+  installed_version: string | null;
+  bucket_version: string | null;
+  changes: ManifestFieldChange[];
+}
+
 export type View = 'search' | 'installed' | 'bucket' | 'settings' | 'doctor';
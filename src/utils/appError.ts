@@ -0,0 +1,41 @@
+/** Error kinds returned by commands that report structured errors */
+export type AppErrorKind =
+  | 'not_found'
+  | 'permission_denied'
+  | 'network'
+  | 'scoop_command_failed'
+  | 'io'
+  | 'parse'
+  | 'rate_limited'
+  | 'not_configured'
+  | 'cancelled'
+  | 'timeout'
+  | 'busy';
+
+export interface AppError {
+  kind: AppErrorKind;
+  message: string;
+}
+
+export function isAppError(error: unknown): error is AppError {
+  return (
+    typeof error === 'object' &&
+    error !== null &&
+    typeof (error as AppError).kind === 'string' &&
+    typeof (error as AppError).message === 'string'
+  );
+}
+
+/**
+ * Extracts a displayable message from a rejected invoke, which may be an
+ * `AppError`, a plain string or an `Error`.
+ */
+export function getErrorMessage(error: unknown, fallback?: string): string {
+  if (isAppError(error) || error instanceof Error) {
+    return error.message;
+  }
+  if (typeof error === 'string') {
+    return error;
+  }
+  return fallback ?? String(error);
+}